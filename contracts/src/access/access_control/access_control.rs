@@ -26,6 +26,7 @@ pub use crate::{
 };
 pub use access_control::Internal as _;
 
+use ink_prelude::vec::Vec;
 use openbrush::{
     modifier_definition,
     modifiers,
@@ -80,6 +81,14 @@ where
         self.data().members.has_role(role, &address)
     }
 
+    default fn has_all_roles(&self, account: AccountId, roles: Vec<RoleType>) -> bool {
+        roles.into_iter().all(|role| self.has_role(role, account))
+    }
+
+    default fn has_any_role(&self, account: AccountId, roles: Vec<RoleType>) -> bool {
+        roles.into_iter().any(|role| self.has_role(role, account))
+    }
+
     default fn get_role_admin(&self, role: RoleType) -> RoleType {
         get_role_admin(self, role)
     }
@@ -89,6 +98,7 @@ where
         if self.data().members.has_role(role, &account) {
             return Err(AccessControlError::RoleRedundant)
         }
+        self._before_role_change(role, account)?;
         self.data().members.add(role, &account);
         self._emit_role_granted(role, account, Some(T::env().caller()));
         Ok(())
@@ -97,6 +107,7 @@ where
     #[modifiers(only_role(get_role_admin(self, role)))]
     default fn revoke_role(&mut self, role: RoleType, account: AccountId) -> Result<(), AccessControlError> {
         check_role(self, role, account)?;
+        self._before_role_change(role, account)?;
         self._do_revoke_role(role, account);
         Ok(())
     }
@@ -109,6 +120,40 @@ where
         self._do_revoke_role(role, account);
         Ok(())
     }
+
+    default fn grant_role_batch(&mut self, grants: Vec<(RoleType, AccountId)>) -> Result<(), AccessControlError> {
+        let caller = T::env().caller();
+        // All grants are validated before any of them is applied
+        for (i, (role, account)) in grants.iter().enumerate() {
+            check_role(self, get_role_admin(self, *role), caller)?;
+            if self.data().members.has_role(*role, account) || grants[..i].contains(&(*role, *account)) {
+                return Err(AccessControlError::RoleRedundant)
+            }
+            self._before_role_change(*role, *account)?;
+        }
+        for (role, account) in grants {
+            self.data().members.add(role, &account);
+            self._emit_role_granted(role, account, Some(caller));
+        }
+        Ok(())
+    }
+
+    default fn revoke_role_batch(&mut self, revokes: Vec<(RoleType, AccountId)>) -> Result<(), AccessControlError> {
+        let caller = T::env().caller();
+        // All revokes are validated before any of them is applied
+        for (i, (role, account)) in revokes.iter().enumerate() {
+            check_role(self, get_role_admin(self, *role), caller)?;
+            check_role(self, *role, *account)?;
+            if revokes[..i].contains(&(*role, *account)) {
+                return Err(AccessControlError::MissingRole)
+            }
+            self._before_role_change(*role, *account)?;
+        }
+        for (role, account) in revokes {
+            self._do_revoke_role(role, account);
+        }
+        Ok(())
+    }
 }
 
 pub trait Internal {
@@ -117,6 +162,10 @@ pub trait Internal {
     fn _emit_role_granted(&mut self, _role: RoleType, _grantee: AccountId, _grantor: Option<AccountId>);
     fn _emit_role_revoked(&mut self, _role: RoleType, _account: AccountId, _sender: AccountId);
 
+    /// Hook which is called before `role` is granted to or revoked from `account`
+    /// with `grant_role` and `revoke_role`. Returns an error to reject the change.
+    fn _before_role_change(&mut self, _role: RoleType, _account: AccountId) -> Result<(), AccessControlError>;
+
     fn _default_admin() -> RoleType;
 
     fn _init_with_caller(&mut self);
@@ -140,6 +189,10 @@ where
     default fn _emit_role_granted(&mut self, _role: RoleType, _grantee: AccountId, _grantor: Option<AccountId>) {}
     default fn _emit_role_revoked(&mut self, _role: RoleType, _account: AccountId, _sender: AccountId) {}
 
+    default fn _before_role_change(&mut self, _role: RoleType, _account: AccountId) -> Result<(), AccessControlError> {
+        Ok(())
+    }
+
     default fn _default_admin() -> RoleType {
         DEFAULT_ADMIN_ROLE
     }
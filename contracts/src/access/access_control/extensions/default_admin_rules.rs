@@ -0,0 +1,193 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    access_control,
+    access_control::{
+        extensions::default_admin_rules,
+        members,
+    },
+    traits::access_control::{
+        extensions::default_admin_rules::*,
+        *,
+    },
+};
+pub use access_control::Internal as _;
+pub use default_admin_rules::Internal as _;
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        OccupiedStorage,
+        Storage,
+        Timestamp,
+        ZERO_ADDRESS,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub delay: Timestamp,
+    // The new delay and the timestamp when it takes effect
+    pub pending_delay: Option<(Timestamp, Timestamp)>,
+    // The pending admin and the timestamp after which it can accept the role
+    pub pending_admin: Option<(AccountId, Timestamp)>,
+    // The admin which began the transfer, the role is revoked from it on acceptance
+    pub transfer_initiator: Option<AccountId>,
+    pub _reserved: Option<()>,
+}
+
+impl<T, M> AccessControlDefaultAdminRules for T
+where
+    M: members::MembersManager,
+    T: Storage<Data>,
+    T: Storage<access_control::Data<M>>,
+    T: OccupiedStorage<{ access_control::STORAGE_KEY }, WithData = access_control::Data<M>>,
+{
+    default fn default_admin_delay(&self) -> Timestamp {
+        self._effective_default_admin_delay()
+    }
+
+    default fn pending_default_admin_delay(&self) -> (Timestamp, Timestamp) {
+        match self.data::<Data>().pending_delay {
+            Some((delay, schedule)) if schedule > Self::env().block_timestamp() => (delay, schedule),
+            _ => (0, 0),
+        }
+    }
+
+    default fn pending_default_admin(&self) -> (AccountId, Timestamp) {
+        self.data::<Data>().pending_admin.unwrap_or((ZERO_ADDRESS.into(), 0))
+    }
+
+    #[modifiers(access_control::only_role(Self::_default_admin()))]
+    default fn begin_default_admin_transfer(&mut self, new_admin: AccountId) -> Result<(), AccessControlError> {
+        let schedule = Self::env()
+            .block_timestamp()
+            .saturating_add(self._effective_default_admin_delay());
+        self.data::<Data>().pending_admin = Some((new_admin, schedule));
+        self.data::<Data>().transfer_initiator = Some(Self::env().caller());
+        self._emit_default_admin_transfer_scheduled(new_admin, schedule);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(Self::_default_admin()))]
+    default fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+        if self.data::<Data>().pending_admin.take().is_none() {
+            return Err(AccessControlError::NoPendingDefaultAdminTransfer)
+        }
+        self.data::<Data>().transfer_initiator = None;
+        Ok(())
+    }
+
+    default fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+        let caller = Self::env().caller();
+        let (new_admin, schedule) = self
+            .data::<Data>()
+            .pending_admin
+            .ok_or(AccessControlError::InvalidCaller)?;
+        if new_admin != caller {
+            return Err(AccessControlError::InvalidCaller)
+        }
+        if Self::env().block_timestamp() < schedule {
+            return Err(AccessControlError::DefaultAdminDelayNotPassed)
+        }
+
+        let role = Self::_default_admin();
+        if let Some(previous) = self.data::<Data>().transfer_initiator.take() {
+            if previous != new_admin && self.has_role(role, previous) {
+                self._do_revoke_role(role, previous);
+            }
+        }
+        self.data::<Data>().pending_admin = None;
+        self._setup_role(role, new_admin);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(Self::_default_admin()))]
+    default fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError> {
+        // the change which has already taken effect becomes the current delay
+        let delay = self._effective_default_admin_delay();
+        let schedule = Self::env().block_timestamp().saturating_add(delay);
+        self.data::<Data>().delay = delay;
+        self.data::<Data>().pending_delay = Some((new_delay, schedule));
+        self._emit_default_admin_delay_change_scheduled(new_delay, schedule);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(Self::_default_admin()))]
+    default fn rollback_default_admin_delay(&mut self) -> Result<(), AccessControlError> {
+        let delay = self._effective_default_admin_delay();
+        self.data::<Data>().delay = delay;
+        self.data::<Data>().pending_delay = None;
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_default_admin_transfer_scheduled(&self, _new_admin: AccountId, _accept_schedule: Timestamp);
+    fn _emit_default_admin_delay_change_scheduled(&self, _new_delay: Timestamp, _effect_schedule: Timestamp);
+
+    /// Sets the initial delay of the default admin transfer without waiting.
+    fn _init_default_admin_delay(&mut self, delay: Timestamp);
+
+    /// Returns the delay which is in effect at the current block.
+    fn _effective_default_admin_delay(&self) -> Timestamp;
+
+    /// Returns `EnforcedDefaultAdminRules` error if `role` is the default admin role.
+    /// Call it from `access_control::Internal::_before_role_change`, so the default admin role
+    /// is only transferred with `begin_default_admin_transfer` and `accept_default_admin_transfer`.
+    fn _ensure_default_admin_rules(&self, role: RoleType) -> Result<(), AccessControlError>;
+}
+
+impl<T, M> Internal for T
+where
+    M: members::MembersManager,
+    T: Storage<Data>,
+    T: Storage<access_control::Data<M>>,
+    T: OccupiedStorage<{ access_control::STORAGE_KEY }, WithData = access_control::Data<M>>,
+{
+    default fn _emit_default_admin_transfer_scheduled(&self, _new_admin: AccountId, _accept_schedule: Timestamp) {}
+    default fn _emit_default_admin_delay_change_scheduled(&self, _new_delay: Timestamp, _effect_schedule: Timestamp) {}
+
+    default fn _init_default_admin_delay(&mut self, delay: Timestamp) {
+        self.data::<Data>().delay = delay;
+        self.data::<Data>().pending_delay = None;
+    }
+
+    default fn _effective_default_admin_delay(&self) -> Timestamp {
+        match self.data::<Data>().pending_delay {
+            Some((delay, schedule)) if schedule <= Self::env().block_timestamp() => delay,
+            _ => self.data::<Data>().delay,
+        }
+    }
+
+    default fn _ensure_default_admin_rules(&self, role: RoleType) -> Result<(), AccessControlError> {
+        if role == Self::_default_admin() {
+            return Err(AccessControlError::EnforcedDefaultAdminRules)
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    access_control,
+    access_control::{
+        extensions::signature,
+        members,
+    },
+    traits::access_control::{
+        extensions::signature::*,
+        *,
+    },
+};
+pub use access_control::Internal as _;
+pub use signature::Internal as _;
+
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    OccupiedStorage,
+    Storage,
+};
+
+impl<T, M> AccessControlSignature for T
+where
+    M: members::MembersManager,
+    T: Storage<access_control::Data<M>>,
+    T: OccupiedStorage<{ access_control::STORAGE_KEY }, WithData = access_control::Data<M>>,
+{
+    default fn is_valid_signature(&self, hash: [u8; 32], signature: Vec<u8>) -> bool {
+        match self._recover_signer(&signature, &hash) {
+            Some(signer) => self.has_role(self._signer_role(), signer),
+            None => false,
+        }
+    }
+}
+
+pub trait Internal {
+    /// Returns the role which accounts must have to sign messages on behalf of the contract.
+    ///
+    /// By default it is the admin role returned by `_default_admin`.
+    fn _signer_role(&self) -> RoleType;
+
+    /// Returns the account which signed `message_hash` with ecdsa `signature`.
+    ///
+    /// Returns `None` if `signature` is not a 65 bytes recoverable signature.
+    fn _recover_signer(&self, signature: &[u8], message_hash: &[u8; 32]) -> Option<AccountId>;
+}
+
+impl<T, M> Internal for T
+where
+    M: members::MembersManager,
+    T: Storage<access_control::Data<M>>,
+    T: OccupiedStorage<{ access_control::STORAGE_KEY }, WithData = access_control::Data<M>>,
+{
+    default fn _signer_role(&self) -> RoleType {
+        Self::_default_admin()
+    }
+
+    default fn _recover_signer(&self, signature: &[u8], message_hash: &[u8; 32]) -> Option<AccountId> {
+        crate::signature::recover_ecdsa(message_hash, signature).ok()
+    }
+}
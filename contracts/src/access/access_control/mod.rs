@@ -20,7 +20,9 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub mod extensions {
+    pub mod default_admin_rules;
     pub mod enumerable;
+    pub mod signature;
 }
 
 pub mod access_control;
@@ -0,0 +1,111 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    ownable::extensions::two_step,
+    traits::ownable::{
+        extensions::two_step::*,
+        *,
+    },
+};
+pub use ownable::Internal as _;
+pub use two_step::Internal as _;
+
+use openbrush::traits::{
+    AccountId,
+    AccountIdExt,
+    Storage,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub pending_owner: Option<AccountId>,
+    // The owner who nominated the pending owner. The nomination is valid only while
+    // that account stays the owner, so renouncing or transferring the ownership cancels it.
+    pub nominated_by: AccountId,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<ownable::Data> + Storage<Data>> Ownable2Step for T {
+    default fn pending_owner(&self) -> Option<AccountId> {
+        if self.data::<Data>().nominated_by != self.data::<ownable::Data>().owner {
+            return None
+        }
+        self.data::<Data>().pending_owner.clone()
+    }
+
+    default fn accept_ownership(&mut self) -> Result<(), OwnableError> {
+        let caller = Self::env().caller();
+        if self.pending_owner() != Some(caller) {
+            return Err(OwnableError::CallerIsNotPendingOwner)
+        }
+        self.data::<Data>().pending_owner = None;
+        let old_owner = self.data::<ownable::Data>().owner.clone();
+        self.data::<ownable::Data>().owner = caller.clone();
+        self._emit_ownership_transferred_event(Some(old_owner), Some(caller));
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_ownership_transfer_started_event(&self, _previous: AccountId, _new: AccountId);
+
+    /// Nominates `new_owner` as the pending owner. Should be used in place of
+    /// `Ownable::transfer_ownership`, which must be protected by `only_owner` modifier.
+    /// The nomination is cancelled when the owner changes, for example, after `renounce_ownership`.
+    ///
+    /// On success a `OwnershipTransferStarted` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NewOwnerIsZero` error if new owner's address is zero.
+    fn _start_ownership_transfer(&mut self, new_owner: AccountId) -> Result<(), OwnableError>;
+
+    /// Removes the pending owner, so the nomination can't be accepted anymore.
+    fn _cancel_ownership_transfer(&mut self);
+}
+
+impl<T: Storage<ownable::Data> + Storage<Data>> Internal for T {
+    default fn _emit_ownership_transfer_started_event(&self, _previous: AccountId, _new: AccountId) {}
+
+    default fn _start_ownership_transfer(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+        if new_owner.is_zero() {
+            return Err(OwnableError::NewOwnerIsZero)
+        }
+        if new_owner == self.data::<ownable::Data>().owner {
+            return Err(OwnableError::NewOwnerIsOwner)
+        }
+        let owner = self.data::<ownable::Data>().owner.clone();
+        self.data::<Data>().pending_owner = Some(new_owner.clone());
+        self.data::<Data>().nominated_by = owner.clone();
+        self._emit_ownership_transfer_started_event(owner, new_owner);
+        Ok(())
+    }
+
+    default fn _cancel_ownership_transfer(&mut self) {
+        self.data::<Data>().pending_owner = None;
+    }
+}
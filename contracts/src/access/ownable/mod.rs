@@ -25,6 +25,11 @@ pub use crate::{
 };
 pub use ownable::Internal as _;
 
+pub mod extensions {
+    pub mod signature;
+    pub mod two_step;
+}
+
 use openbrush::{
     modifier_definition,
     modifiers,
@@ -59,6 +64,24 @@ where
     body(instance)
 }
 
+/// Throws if called by any account other than the owner or the contract itself.
+///
+/// The contract calls itself when the function is executed via the delegate call of the init
+/// or via the governance executor which is the contract itself.
+#[modifier_definition]
+pub fn only_owner_or_self<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<OwnableError>,
+{
+    let caller = T::env().caller();
+    if instance.data().owner != caller && T::env().account_id() != caller {
+        return Err(From::from(OwnableError::CallerIsNotOwner))
+    }
+    body(instance)
+}
+
 impl<T: Storage<Data>> Ownable for T {
     default fn owner(&self) -> AccountId {
         self.data().owner.clone()
@@ -78,6 +101,9 @@ impl<T: Storage<Data>> Ownable for T {
             return Err(OwnableError::NewOwnerIsZero)
         }
         let old_owner = self.data().owner.clone();
+        if new_owner == old_owner {
+            return Err(OwnableError::NewOwnerIsOwner)
+        }
         self.data().owner = new_owner.clone();
         self._emit_ownership_transferred_event(Some(old_owner), Some(new_owner));
         Ok(())
@@ -0,0 +1,116 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    escrow,
+    traits::escrow::*,
+};
+pub use escrow::Internal as _;
+
+pub mod extensions {
+    pub mod conditional;
+}
+
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        AccountIdExt,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub deposits: Mapping<AccountId, Balance>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> Escrow for T {
+    default fn deposits_of(&self, payee: AccountId) -> Balance {
+        self.data().deposits.get(&payee).unwrap_or(0)
+    }
+
+    default fn deposit(&mut self, payee: AccountId) -> Result<(), EscrowError> {
+        let amount = Self::env().transferred_value();
+        self._deposit(payee, amount)
+    }
+
+    default fn withdraw(&mut self, payee: AccountId) -> Result<(), EscrowError> {
+        if !self._withdrawal_allowed(&payee) {
+            return Err(EscrowError::WithdrawalNotAllowed)
+        }
+        self._withdraw(payee)
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_deposited_event(&self, _payee: AccountId, _amount: Balance);
+    fn _emit_withdrawn_event(&self, _payee: AccountId, _amount: Balance);
+
+    /// Adds `amount` to the deposit of `payee`.
+    fn _deposit(&mut self, payee: AccountId, amount: Balance) -> Result<(), EscrowError>;
+
+    /// Transfers the deposit of `payee` to `payee` without checking `_withdrawal_allowed`.
+    fn _withdraw(&mut self, payee: AccountId) -> Result<(), EscrowError>;
+
+    /// Returns `true` if the funds of `payee` can be withdrawn. The withdrawal is always allowed by default.
+    ///
+    /// Override it to put a condition on the withdrawals, for example with the `ConditionalEscrow` extension.
+    fn _withdrawal_allowed(&self, payee: &AccountId) -> bool;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _emit_deposited_event(&self, _payee: AccountId, _amount: Balance) {}
+    default fn _emit_withdrawn_event(&self, _payee: AccountId, _amount: Balance) {}
+
+    default fn _deposit(&mut self, payee: AccountId, amount: Balance) -> Result<(), EscrowError> {
+        if payee.is_zero() {
+            return Err(EscrowError::ZeroPayeeAddress)
+        }
+        let deposits = self
+            .deposits_of(payee)
+            .checked_add(amount)
+            .ok_or(EscrowError::Overflow)?;
+        self.data().deposits.insert(&payee, &deposits);
+        self._emit_deposited_event(payee, amount);
+        Ok(())
+    }
+
+    default fn _withdraw(&mut self, payee: AccountId) -> Result<(), EscrowError> {
+        let amount = self.deposits_of(payee);
+        if amount > 0 && Self::env().transfer(payee, amount).is_err() {
+            return Err(EscrowError::TransferFailed)
+        }
+        self.data().deposits.remove(&payee);
+        self._emit_withdrawn_event(payee, amount);
+        Ok(())
+    }
+
+    default fn _withdrawal_allowed(&self, _payee: &AccountId) -> bool {
+        true
+    }
+}
@@ -19,5 +19,11 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+#[cfg(feature = "escrow")]
+pub mod escrow;
 #[cfg(feature = "payment_splitter")]
 pub mod payment_splitter;
+#[cfg(feature = "pull_payment")]
+pub mod pull_payment;
+#[cfg(feature = "vesting_wallet")]
+pub mod vesting_wallet;
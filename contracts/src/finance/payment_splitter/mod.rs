@@ -25,6 +25,7 @@ pub use crate::{
 };
 pub use payment_splitter::Internal as _;
 
+use crate::traits::psp22::PSP22Ref;
 use ink_prelude::vec::Vec;
 use openbrush::{
     storage::Mapping,
@@ -47,6 +48,8 @@ pub struct Data {
     pub shares: Mapping<AccountId, Balance>,
     pub released: Mapping<AccountId, Balance>,
     pub payees: Vec<AccountId>,
+    pub token_total_released: Mapping<AccountId, Balance>,
+    pub token_released: Mapping<(AccountId, AccountId), Balance>,
     pub _reserved: Option<()>,
 }
 
@@ -87,10 +90,8 @@ impl<T: Storage<Data>> PaymentSplitter for T {
         let balance = Self::env().balance();
         let current_balance = balance.checked_sub(Self::env().minimum_balance()).unwrap_or_default();
         let total_received = current_balance + self.data().total_released;
-        let shares = self.data().shares.get(&account).unwrap().clone();
-        let total_shares = self.data().total_shares;
         let released = self.data().released.get(&account).unwrap_or_default();
-        let payment = total_received * shares / total_shares - released;
+        let payment = self._pending_payment(&account, total_received, released);
 
         if payment == 0 {
             return Err(PaymentSplitterError::AccountIsNotDuePayment)
@@ -106,6 +107,45 @@ impl<T: Storage<Data>> PaymentSplitter for T {
         self._emit_payment_released_event(account, payment);
         Ok(())
     }
+
+    default fn total_released_token(&self, token: AccountId) -> Balance {
+        self.data().token_total_released.get(&token).unwrap_or(0)
+    }
+
+    default fn released_token(&self, token: AccountId, account: AccountId) -> Balance {
+        self.data().token_released.get(&(token, account)).unwrap_or(0)
+    }
+
+    default fn releasable_token(&self, token: AccountId, account: AccountId) -> Balance {
+        let total_received = self._token_balance(&token) + self.total_released_token(token);
+        let released = self.released_token(token, account);
+        self._pending_payment(&account, total_received, released)
+    }
+
+    default fn release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError> {
+        if !self.data().shares.get(&account).is_some() {
+            return Err(PaymentSplitterError::AccountHasNoShares)
+        }
+
+        let payment = self.releasable_token(token, account);
+
+        if payment == 0 {
+            return Err(PaymentSplitterError::AccountIsNotDuePayment)
+        }
+
+        let released = self.released_token(token, account);
+        let total_released = self.total_released_token(token);
+        self.data()
+            .token_released
+            .insert(&(token, account), &(released + payment));
+        self.data()
+            .token_total_released
+            .insert(&token, &(total_released + payment));
+
+        self._transfer_token(&token, account, payment)?;
+        self._emit_token_payment_released_event(token, account, payment);
+        Ok(())
+    }
 }
 
 pub trait Internal {
@@ -113,6 +153,7 @@ pub trait Internal {
     fn _emit_payee_added_event(&self, _account: AccountId, _shares: Balance);
     fn _emit_payment_received_event(&self, _from: AccountId, _amount: Balance);
     fn _emit_payment_released_event(&self, _to: AccountId, _amount: Balance);
+    fn _emit_token_payment_released_event(&self, _token: AccountId, _to: AccountId, _amount: Balance);
 
     /// Inits an instance of `PaymentSplitter` where each account in `payees` is assigned the number of shares at
     /// the matching position in the `shares` array.
@@ -127,12 +168,30 @@ pub trait Internal {
 
     /// Calls the `release` method for each `AccountId` in the `payees` vec.
     fn _release_all(&mut self) -> Result<(), PaymentSplitterError>;
+
+    /// Returns the amount that `account` is owed from `total_received`
+    /// according to their shares, minus the amount `already_released` to them.
+    ///
+    /// The result is rounded down, so the dust stays in the contract.
+    fn _pending_payment(&self, account: &AccountId, total_received: Balance, already_released: Balance) -> Balance;
+
+    /// Returns the balance of `token` held by the contract.
+    fn _token_balance(&self, token: &AccountId) -> Balance;
+
+    /// Transfers `amount` of `token` from the contract to `to`.
+    fn _transfer_token(
+        &mut self,
+        token: &AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<(), PaymentSplitterError>;
 }
 
 impl<T: Storage<Data>> Internal for T {
     default fn _emit_payee_added_event(&self, _account: AccountId, _shares: Balance) {}
     default fn _emit_payment_received_event(&self, _from: AccountId, _amount: Balance) {}
     default fn _emit_payment_released_event(&self, _to: AccountId, _amount: Balance) {}
+    default fn _emit_token_payment_released_event(&self, _token: AccountId, _to: AccountId, _amount: Balance) {}
 
     default fn _init(&mut self, payees_and_shares: Vec<(AccountId, Balance)>) -> Result<(), PaymentSplitterError> {
         if payees_and_shares.is_empty() {
@@ -173,4 +232,31 @@ impl<T: Storage<Data>> Internal for T {
 
         Ok(())
     }
+
+    default fn _pending_payment(
+        &self,
+        account: &AccountId,
+        total_received: Balance,
+        already_released: Balance,
+    ) -> Balance {
+        let shares = self.data().shares.get(account).unwrap_or_default();
+        let total_shares = self.data().total_shares;
+        if total_shares == 0 {
+            return 0
+        }
+        (total_received * shares / total_shares).saturating_sub(already_released)
+    }
+
+    default fn _token_balance(&self, token: &AccountId) -> Balance {
+        PSP22Ref::balance_of(token, Self::env().account_id())
+    }
+
+    default fn _transfer_token(
+        &mut self,
+        token: &AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<(), PaymentSplitterError> {
+        PSP22Ref::transfer(token, to, amount, Vec::new()).map_err(|_| PaymentSplitterError::TransferFailed)
+    }
 }
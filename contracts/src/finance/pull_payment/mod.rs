@@ -0,0 +1,57 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    escrow,
+    pull_payment,
+    traits::pull_payment::*,
+};
+pub use escrow::Internal as _;
+pub use pull_payment::Internal as _;
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+impl<T: Storage<escrow::Data>> PullPayment for T {
+    default fn payments(&self, dest: AccountId) -> Balance {
+        self.data().deposits.get(&dest).unwrap_or(0)
+    }
+
+    default fn withdraw_payments(&mut self, payee: AccountId) -> Result<(), EscrowError> {
+        self._withdraw(payee)
+    }
+}
+
+pub trait Internal {
+    /// Credits `amount` of the native tokens to `dest`, it can be withdrawn later with `withdraw_payments`.
+    ///
+    /// The contract must already hold `amount`, it is not transferred by this call.
+    fn _async_transfer(&mut self, dest: AccountId, amount: Balance) -> Result<(), EscrowError>;
+}
+
+impl<T: Storage<escrow::Data>> Internal for T {
+    default fn _async_transfer(&mut self, dest: AccountId, amount: Balance) -> Result<(), EscrowError> {
+        self._deposit(dest, amount)
+    }
+}
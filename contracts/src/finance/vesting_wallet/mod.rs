@@ -0,0 +1,201 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    traits::vesting_wallet::*,
+    vesting_wallet,
+};
+pub use vesting_wallet::Internal as _;
+
+use crate::traits::psp22::PSP22Ref;
+use ink_prelude::vec::Vec;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        AccountIdExt,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub beneficiary: AccountId,
+    pub start: Timestamp,
+    pub duration: Timestamp,
+    pub released: Balance,
+    pub token_released: Mapping<AccountId, Balance>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> VestingWallet for T {
+    default fn beneficiary(&self) -> AccountId {
+        self.data().beneficiary
+    }
+
+    default fn start(&self) -> Timestamp {
+        self.data().start
+    }
+
+    default fn duration(&self) -> Timestamp {
+        self.data().duration
+    }
+
+    default fn released(&self) -> Balance {
+        self.data().released
+    }
+
+    default fn released_token(&self, token: AccountId) -> Balance {
+        self.data().token_released.get(&token).unwrap_or(0)
+    }
+
+    default fn vested_amount(&self, timestamp: Timestamp) -> Balance {
+        let balance = Self::env().balance();
+        let current_balance = balance.checked_sub(Self::env().minimum_balance()).unwrap_or_default();
+        self._vesting_schedule(current_balance + self.released(), timestamp)
+    }
+
+    default fn vested_amount_token(&self, token: AccountId, timestamp: Timestamp) -> Balance {
+        let total_allocation = self._token_balance(&token) + self.released_token(token);
+        self._vesting_schedule(total_allocation, timestamp)
+    }
+
+    default fn releasable(&self) -> Balance {
+        self.vested_amount(Self::env().block_timestamp())
+            .saturating_sub(self.released())
+    }
+
+    default fn releasable_token(&self, token: AccountId) -> Balance {
+        self.vested_amount_token(token, Self::env().block_timestamp())
+            .saturating_sub(self.released_token(token))
+    }
+
+    default fn release(&mut self) -> Result<(), VestingWalletError> {
+        let amount = self.releasable();
+        if amount == 0 {
+            return Err(VestingWalletError::NothingToRelease)
+        }
+
+        self.data().released += amount;
+
+        let beneficiary = self.beneficiary();
+        if Self::env().transfer(beneficiary, amount).is_err() {
+            return Err(VestingWalletError::TransferFailed)
+        }
+        self._emit_released_event(amount);
+        Ok(())
+    }
+
+    default fn release_token(&mut self, token: AccountId) -> Result<(), VestingWalletError> {
+        let amount = self.releasable_token(token);
+        if amount == 0 {
+            return Err(VestingWalletError::NothingToRelease)
+        }
+
+        let released = self.released_token(token);
+        self.data().token_released.insert(&token, &(released + amount));
+
+        let beneficiary = self.beneficiary();
+        self._transfer_token(&token, beneficiary, amount)?;
+        self._emit_token_released_event(token, amount);
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_released_event(&self, _amount: Balance);
+    fn _emit_token_released_event(&self, _token: AccountId, _amount: Balance);
+
+    /// Inits the vesting of the tokens for `beneficiary`, starting at `start` and lasting `duration`.
+    fn _init(
+        &mut self,
+        beneficiary: AccountId,
+        start: Timestamp,
+        duration: Timestamp,
+    ) -> Result<(), VestingWalletError>;
+
+    /// Returns the amount vested at `timestamp` out of `total_allocation`,
+    /// which is the sum of the balance held by the contract and the amount already released.
+    ///
+    /// The default schedule is linear, the result is rounded down.
+    fn _vesting_schedule(&self, total_allocation: Balance, timestamp: Timestamp) -> Balance;
+
+    /// Returns the balance of `token` held by the contract.
+    fn _token_balance(&self, token: &AccountId) -> Balance;
+
+    /// Transfers `amount` of `token` from the contract to `to`.
+    fn _transfer_token(&mut self, token: &AccountId, to: AccountId, amount: Balance) -> Result<(), VestingWalletError>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _emit_released_event(&self, _amount: Balance) {}
+    default fn _emit_token_released_event(&self, _token: AccountId, _amount: Balance) {}
+
+    default fn _init(
+        &mut self,
+        beneficiary: AccountId,
+        start: Timestamp,
+        duration: Timestamp,
+    ) -> Result<(), VestingWalletError> {
+        if beneficiary.is_zero() {
+            return Err(VestingWalletError::BeneficiaryZeroAddress)
+        }
+        self.data().beneficiary = beneficiary;
+        self.data().start = start;
+        self.data().duration = duration;
+        Ok(())
+    }
+
+    default fn _vesting_schedule(&self, total_allocation: Balance, timestamp: Timestamp) -> Balance {
+        let start = self.data().start;
+        let duration = self.data().duration;
+
+        if timestamp < start {
+            0
+        } else if timestamp - start >= duration {
+            total_allocation
+        } else {
+            let elapsed = (timestamp - start) as Balance;
+            let duration = duration as Balance;
+            // `total_allocation * elapsed / duration` without the overflow of the multiplication
+            (total_allocation / duration) * elapsed + (total_allocation % duration) * elapsed / duration
+        }
+    }
+
+    default fn _token_balance(&self, token: &AccountId) -> Balance {
+        PSP22Ref::balance_of(token, Self::env().account_id())
+    }
+
+    default fn _transfer_token(
+        &mut self,
+        token: &AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<(), VestingWalletError> {
+        PSP22Ref::transfer(token, to, amount, Vec::new()).map_err(|_| VestingWalletError::TransferFailed)
+    }
+}
@@ -0,0 +1,490 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    governor,
+    traits::governor::*,
+};
+pub use governor::Internal as _;
+
+use crate::traits::psp22::extensions::snapshot::PSP22SnapshotRef;
+use core::convert::TryFrom;
+use ink_env::{
+    call::{
+        build_call,
+        Call,
+        ExecutionInput,
+    },
+    hash::Blake2x256,
+    CallFlags,
+    DefaultEnvironment,
+};
+use ink_prelude::vec::Vec;
+use ink_storage::traits::{
+    PackedLayout,
+    SpreadLayout,
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Hash,
+        Storage,
+        String,
+        Timestamp,
+    },
+};
+use scale::Encode;
+
+#[cfg(feature = "std")]
+use ink_storage::traits::StorageLayout;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+/// The proposal with its voting schedule and the votes cast for it
+#[derive(Default, Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct ProposalCore {
+    pub proposer: AccountId,
+    pub snapshot: u64,
+    pub vote_start: Timestamp,
+    pub vote_end: Timestamp,
+    pub against_votes: Balance,
+    pub for_votes: Balance,
+    pub abstain_votes: Balance,
+    pub queued: bool,
+    pub eta: Timestamp,
+    pub executed: bool,
+}
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub token: AccountId,
+    pub voting_delay: Timestamp,
+    pub voting_period: Timestamp,
+    pub execution_delay: Timestamp,
+    pub quorum: Balance,
+    pub proposals: Mapping<ProposalId, ProposalCore>,
+    pub has_voted: Mapping<(ProposalId, AccountId), ()>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> Governor for T {
+    default fn token(&self) -> AccountId {
+        self.data().token
+    }
+
+    default fn voting_delay(&self) -> Timestamp {
+        self._voting_delay()
+    }
+
+    default fn voting_period(&self) -> Timestamp {
+        self._voting_period()
+    }
+
+    default fn execution_delay(&self) -> Timestamp {
+        self._execution_delay()
+    }
+
+    default fn quorum(&self, snapshot_id: u64) -> Balance {
+        self._quorum(snapshot_id)
+    }
+
+    default fn hash_proposal(&self, transactions: Vec<Transaction>, description_hash: Hash) -> ProposalId {
+        self._hash_proposal(&transactions, &description_hash)
+    }
+
+    default fn state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError> {
+        let proposal = self
+            .data()
+            .proposals
+            .get(&proposal_id)
+            .ok_or(GovernorError::ProposalNotFound)?;
+        Ok(self._state(&proposal))
+    }
+
+    default fn proposal_snapshot(&self, proposal_id: ProposalId) -> u64 {
+        self.data()
+            .proposals
+            .get(&proposal_id)
+            .map(|proposal| proposal.snapshot)
+            .unwrap_or_default()
+    }
+
+    default fn proposal_deadline(&self, proposal_id: ProposalId) -> Timestamp {
+        self.data()
+            .proposals
+            .get(&proposal_id)
+            .map(|proposal| proposal.vote_end)
+            .unwrap_or_default()
+    }
+
+    default fn proposal_eta(&self, proposal_id: ProposalId) -> Timestamp {
+        self.data()
+            .proposals
+            .get(&proposal_id)
+            .map(|proposal| proposal.eta)
+            .unwrap_or_default()
+    }
+
+    default fn proposal_votes(&self, proposal_id: ProposalId) -> (Balance, Balance, Balance) {
+        self.data()
+            .proposals
+            .get(&proposal_id)
+            .map(|proposal| (proposal.against_votes, proposal.for_votes, proposal.abstain_votes))
+            .unwrap_or_default()
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data().has_voted.get(&(proposal_id, account)).is_some()
+    }
+
+    default fn propose(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description: String,
+    ) -> Result<ProposalId, GovernorError> {
+        if transactions.is_empty() {
+            return Err(GovernorError::EmptyProposal)
+        }
+
+        let id = self._hash_proposal(&transactions, &self._hash_description(&description));
+        if self.data().proposals.get(&id).is_some() {
+            return Err(GovernorError::ProposalAlreadyExists)
+        }
+
+        // the votes are counted at the snapshot created with the proposal
+        let snapshot = self._create_snapshot()?;
+
+        let proposer = Self::env().caller();
+        let vote_start = Self::env().block_timestamp() + self._voting_delay();
+        let vote_end = vote_start + self._voting_period();
+        let proposal = ProposalCore {
+            proposer,
+            snapshot,
+            vote_start,
+            vote_end,
+            ..Default::default()
+        };
+        self.data().proposals.insert(&id, &proposal);
+
+        self._emit_proposal_created_event(id, proposer, transactions, vote_start, vote_end, description);
+        Ok(id)
+    }
+
+    default fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType) -> Result<Balance, GovernorError> {
+        let mut proposal = self
+            .data()
+            .proposals
+            .get(&proposal_id)
+            .ok_or(GovernorError::ProposalNotFound)?;
+        if self._state(&proposal) != ProposalState::Active {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+
+        let voter = Self::env().caller();
+        if self.has_voted(proposal_id, voter) {
+            return Err(GovernorError::AlreadyVoted)
+        }
+
+        let weight = self._get_votes(&voter, proposal.snapshot);
+        match support {
+            VoteType::Against => proposal.against_votes += weight,
+            VoteType::For => proposal.for_votes += weight,
+            VoteType::Abstain => proposal.abstain_votes += weight,
+        }
+        self.data().proposals.insert(&proposal_id, &proposal);
+        self.data().has_voted.insert(&(proposal_id, voter), &());
+
+        self._emit_vote_cast_event(voter, proposal_id, support, weight);
+        Ok(weight)
+    }
+
+    default fn queue(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError> {
+        let id = self._hash_proposal(&transactions, &description_hash);
+        let mut proposal = self.data().proposals.get(&id).ok_or(GovernorError::ProposalNotFound)?;
+        if self._state(&proposal) != ProposalState::Succeeded {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+
+        proposal.queued = true;
+        proposal.eta = Self::env().block_timestamp() + self._execution_delay();
+        self.data().proposals.insert(&id, &proposal);
+
+        self._emit_proposal_queued_event(id, proposal.eta);
+        Ok(id)
+    }
+
+    default fn execute(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError> {
+        let id = self._hash_proposal(&transactions, &description_hash);
+        let mut proposal = self.data().proposals.get(&id).ok_or(GovernorError::ProposalNotFound)?;
+        if self._state(&proposal) != ProposalState::Queued {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+        if Self::env().block_timestamp() < proposal.eta {
+            return Err(GovernorError::ProposalNotReady)
+        }
+
+        // The proposal is marked as executed before the calls, so a reentrant call can't execute it again
+        proposal.executed = true;
+        self.data().proposals.insert(&id, &proposal);
+
+        for transaction in transactions.into_iter() {
+            if let Err(err) = self._call(transaction) {
+                proposal.executed = false;
+                self.data().proposals.insert(&id, &proposal);
+                return Err(err)
+            }
+        }
+
+        self._emit_proposal_executed_event(id);
+        Ok(id)
+    }
+
+    default fn set_voting_delay(&mut self, voting_delay: Timestamp) -> Result<(), GovernorError> {
+        self._only_governance()?;
+        self.data().voting_delay = voting_delay;
+        Ok(())
+    }
+
+    default fn set_voting_period(&mut self, voting_period: Timestamp) -> Result<(), GovernorError> {
+        self._only_governance()?;
+        self.data().voting_period = voting_period;
+        Ok(())
+    }
+
+    default fn set_quorum(&mut self, quorum: Balance) -> Result<(), GovernorError> {
+        self._only_governance()?;
+        self.data().quorum = quorum;
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_proposal_created_event(
+        &self,
+        _proposal_id: ProposalId,
+        _proposer: AccountId,
+        _transactions: Vec<Transaction>,
+        _vote_start: Timestamp,
+        _vote_end: Timestamp,
+        _description: String,
+    );
+    fn _emit_vote_cast_event(&self, _voter: AccountId, _proposal_id: ProposalId, _support: VoteType, _weight: Balance);
+    fn _emit_proposal_queued_event(&self, _proposal_id: ProposalId, _eta: Timestamp);
+    fn _emit_proposal_executed_event(&self, _proposal_id: ProposalId);
+
+    /// Initializes the governor with the votes `token` and the voting parameters.
+    fn _init_governor(
+        &mut self,
+        token: AccountId,
+        voting_delay: Timestamp,
+        voting_period: Timestamp,
+        execution_delay: Timestamp,
+        quorum: Balance,
+    );
+
+    /// Returns the delay between the proposal and the start of the voting.
+    fn _voting_delay(&self) -> Timestamp;
+
+    /// Returns the duration of the voting.
+    fn _voting_period(&self) -> Timestamp;
+
+    /// Returns the delay between queuing and the execution of the proposal.
+    fn _execution_delay(&self) -> Timestamp;
+
+    /// Returns the quorum of the proposal created at the snapshot `snapshot_id`.
+    /// Override it to calculate the quorum, for example, as a fraction of the total supply at the snapshot.
+    fn _quorum(&self, snapshot_id: u64) -> Balance;
+
+    /// Creates a snapshot of the votes token and returns its id.
+    fn _create_snapshot(&mut self) -> Result<u64, GovernorError>;
+
+    /// Returns the votes of `account` at the snapshot `snapshot_id` of the votes token.
+    fn _get_votes(&self, account: &AccountId, snapshot_id: u64) -> Balance;
+
+    /// Returns the state of the `proposal` at the current block.
+    fn _state(&self, proposal: &ProposalCore) -> ProposalState;
+
+    /// Returns whether the `proposal` reached the quorum and has more `For` votes than `Against` votes.
+    fn _vote_succeeded(&self, proposal: &ProposalCore) -> bool;
+
+    fn _hash_proposal(&self, transactions: &Vec<Transaction>, description_hash: &Hash) -> ProposalId;
+
+    fn _hash_description(&self, description: &String) -> Hash;
+
+    /// Execute the transaction of the proposal.
+    fn _call(&mut self, transaction: Transaction) -> Result<(), GovernorError>;
+
+    /// Returns `CallerMustBeGovernor` error if the caller is not the governor itself.
+    fn _only_governance(&self) -> Result<(), GovernorError>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _emit_proposal_created_event(
+        &self,
+        _proposal_id: ProposalId,
+        _proposer: AccountId,
+        _transactions: Vec<Transaction>,
+        _vote_start: Timestamp,
+        _vote_end: Timestamp,
+        _description: String,
+    ) {
+    }
+    default fn _emit_vote_cast_event(
+        &self,
+        _voter: AccountId,
+        _proposal_id: ProposalId,
+        _support: VoteType,
+        _weight: Balance,
+    ) {
+    }
+    default fn _emit_proposal_queued_event(&self, _proposal_id: ProposalId, _eta: Timestamp) {}
+    default fn _emit_proposal_executed_event(&self, _proposal_id: ProposalId) {}
+
+    default fn _init_governor(
+        &mut self,
+        token: AccountId,
+        voting_delay: Timestamp,
+        voting_period: Timestamp,
+        execution_delay: Timestamp,
+        quorum: Balance,
+    ) {
+        let data = self.data();
+        data.token = token;
+        data.voting_delay = voting_delay;
+        data.voting_period = voting_period;
+        data.execution_delay = execution_delay;
+        data.quorum = quorum;
+    }
+
+    default fn _voting_delay(&self) -> Timestamp {
+        self.data().voting_delay
+    }
+
+    default fn _voting_period(&self) -> Timestamp {
+        self.data().voting_period
+    }
+
+    default fn _execution_delay(&self) -> Timestamp {
+        self.data().execution_delay
+    }
+
+    default fn _quorum(&self, _snapshot_id: u64) -> Balance {
+        self.data().quorum
+    }
+
+    default fn _create_snapshot(&mut self) -> Result<u64, GovernorError> {
+        PSP22SnapshotRef::snapshot(&self.data().token).map_err(|_| GovernorError::SnapshotNotCreated)
+    }
+
+    default fn _get_votes(&self, account: &AccountId, snapshot_id: u64) -> Balance {
+        PSP22SnapshotRef::balance_of_at(&self.data().token, account.clone(), snapshot_id)
+    }
+
+    default fn _state(&self, proposal: &ProposalCore) -> ProposalState {
+        let now = Self::env().block_timestamp();
+
+        if proposal.executed {
+            ProposalState::Executed
+        } else if now < proposal.vote_start {
+            ProposalState::Pending
+        } else if now <= proposal.vote_end {
+            ProposalState::Active
+        } else if !self._vote_succeeded(proposal) {
+            ProposalState::Defeated
+        } else if proposal.queued {
+            ProposalState::Queued
+        } else {
+            ProposalState::Succeeded
+        }
+    }
+
+    default fn _vote_succeeded(&self, proposal: &ProposalCore) -> bool {
+        let quorum_votes = proposal.for_votes.saturating_add(proposal.abstain_votes);
+        quorum_votes >= self._quorum(proposal.snapshot) && proposal.for_votes > proposal.against_votes
+    }
+
+    default fn _hash_proposal(&self, transactions: &Vec<Transaction>, description_hash: &Hash) -> ProposalId {
+        let mut hash_data: Vec<u8> = Vec::new();
+
+        hash_data.append(&mut transactions.encode());
+        hash_data.append(&mut description_hash.encode());
+
+        Hash::try_from(Self::env().hash_bytes::<Blake2x256>(&hash_data).as_ref()).unwrap()
+    }
+
+    default fn _hash_description(&self, description: &String) -> Hash {
+        Hash::try_from(Self::env().hash_bytes::<Blake2x256>(description.as_slice()).as_ref()).unwrap()
+    }
+
+    default fn _call(&mut self, transaction: Transaction) -> Result<(), GovernorError> {
+        // Flush the state into storage before the cross call.
+        // Because during cross call we can call this contract(for example for `set_quorum` method).
+        self.flush();
+        let result = build_call::<DefaultEnvironment>()
+            .call_type(
+                Call::new()
+                    .callee(transaction.callee)
+                    .gas_limit(transaction.gas_limit)
+                    .transferred_value(transaction.transferred_value),
+            )
+            .exec_input(ExecutionInput::new(transaction.selector.into()).push_arg(CallInput(&transaction.input)))
+            .returns::<()>()
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .fire()
+            .map_err(|_| GovernorError::UnderlyingTransactionReverted);
+
+        // Load the sate of the contract after the cross call.
+        self.load();
+
+        result
+    }
+
+    default fn _only_governance(&self) -> Result<(), GovernorError> {
+        if Self::env().account_id() != Self::env().caller() {
+            return Err(GovernorError::CallerMustBeGovernor)
+        }
+        Ok(())
+    }
+}
+
+/// A wrapper that allows us to encode a blob of bytes.
+///
+/// We use this to pass the set of untyped (bytes) parameters to the `CallBuilder`.
+struct CallInput<'a>(&'a [u8]);
+
+impl<'a> scale::Encode for CallInput<'a> {
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0);
+    }
+}
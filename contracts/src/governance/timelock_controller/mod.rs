@@ -92,6 +92,7 @@ where
 pub const TIMELOCK_ADMIN_ROLE: RoleType = ink_lang::selector_id!("TIMELOCK_ADMIN_ROLE");
 pub const PROPOSER_ROLE: RoleType = ink_lang::selector_id!("PROPOSER_ROLE");
 pub const EXECUTOR_ROLE: RoleType = ink_lang::selector_id!("EXECUTOR_ROLE");
+pub const CANCELLER_ROLE: RoleType = ink_lang::selector_id!("CANCELLER_ROLE");
 
 pub const DONE_TIMESTAMP: Timestamp = 1;
 
@@ -179,7 +180,7 @@ where
         Ok(())
     }
 
-    #[modifiers(access_control::only_role(Self::_proposal_role()))]
+    #[modifiers(access_control::only_role(Self::_canceller_role()))]
     default fn cancel(&mut self, id: OperationId) -> Result<(), TimelockControllerError> {
         if !self.is_operation_pending(id) {
             return Err(TimelockControllerError::OperationCannonBeCanceled)
@@ -199,6 +200,10 @@ where
     ) -> Result<(), TimelockControllerError> {
         let id = self._hash_operation(&transaction, &predecessor, &salt);
 
+        // The operation must be ready before any call is performed
+        if !self.is_operation_ready(id) {
+            return Err(TimelockControllerError::OperationIsNotReady)
+        }
         self._before_call(predecessor)?;
         self._call(id, 0, transaction)?;
         self._after_call(id)
@@ -213,6 +218,9 @@ where
     ) -> Result<(), TimelockControllerError> {
         let id = self._hash_operation_batch(&transactions, &predecessor, &salt);
 
+        if !self.is_operation_ready(id) {
+            return Err(TimelockControllerError::OperationIsNotReady)
+        }
         self._before_call(predecessor)?;
 
         for (i, transaction) in transactions.into_iter().enumerate() {
@@ -292,6 +300,8 @@ pub trait Internal {
 
     fn _executor_role() -> RoleType;
 
+    fn _canceller_role() -> RoleType;
+
     fn _done_timestamp() -> Timestamp;
 }
 
@@ -335,15 +345,17 @@ where
         self._set_role_admin(Self::_timelock_admin_role(), Self::_timelock_admin_role());
         self._set_role_admin(Self::_proposal_role(), Self::_proposal_role());
         self._set_role_admin(Self::_executor_role(), Self::_executor_role());
+        self._set_role_admin(Self::_canceller_role(), Self::_canceller_role());
 
         // admin + self administration
         self._setup_role(Self::_timelock_admin_role(), Self::env().account_id());
         self._setup_role(Self::_timelock_admin_role(), admin);
 
-        // register proposers
-        proposers
-            .into_iter()
-            .for_each(|proposer| self._setup_role(Self::_proposal_role(), proposer));
+        // register proposers, they also can cancel the operations
+        proposers.into_iter().for_each(|proposer| {
+            self._setup_role(Self::_proposal_role(), proposer);
+            self._setup_role(Self::_canceller_role(), proposer);
+        });
         // register executors
         executors
             .into_iter()
@@ -460,6 +472,10 @@ where
         EXECUTOR_ROLE
     }
 
+    default fn _canceller_role() -> RoleType {
+        CANCELLER_ROLE
+    }
+
     default fn _done_timestamp() -> Timestamp {
         DONE_TIMESTAMP
     }
@@ -28,6 +28,7 @@ mod governance;
 mod security;
 mod token;
 mod upgradeability;
+mod utils;
 
 pub mod traits;
 
@@ -36,8 +37,16 @@ pub mod traits;
 pub use access::access_control;
 #[cfg(feature = "ownable")]
 pub use access::ownable;
+#[cfg(feature = "escrow")]
+pub use finance::escrow;
 #[cfg(feature = "payment_splitter")]
 pub use finance::payment_splitter;
+#[cfg(feature = "pull_payment")]
+pub use finance::pull_payment;
+#[cfg(feature = "vesting_wallet")]
+pub use finance::vesting_wallet;
+#[cfg(feature = "governor")]
+pub use governance::governor;
 #[cfg(feature = "timelock_controller")]
 pub use governance::timelock_controller;
 #[cfg(feature = "pausable")]
@@ -56,3 +65,6 @@ pub use token::psp37;
 pub use upgradeability::diamond;
 #[cfg(feature = "proxy")]
 pub use upgradeability::proxy;
+#[cfg(feature = "multicall")]
+pub use utils::multicall;
+pub use utils::signature;
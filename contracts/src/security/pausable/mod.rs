@@ -96,6 +96,12 @@ pub trait Internal {
 
     /// Function which changes state to unpaused if paused and vice versa
     fn _switch_pause<E: From<PausableError>>(&mut self) -> Result<(), E>;
+
+    /// Returns `Paused` error if the contract is paused.
+    fn _ensure_not_paused<E: From<PausableError>>(&self) -> Result<(), E>;
+
+    /// Returns `NotPaused` error if the contract is not paused.
+    fn _ensure_paused<E: From<PausableError>>(&self) -> Result<(), E>;
 }
 
 impl<T: Storage<Data>> Internal for T {
@@ -123,4 +129,18 @@ impl<T: Storage<Data>> Internal for T {
             self._pause()
         }
     }
+
+    default fn _ensure_not_paused<E: From<PausableError>>(&self) -> Result<(), E> {
+        if self.data().paused {
+            return Err(From::from(PausableError::Paused))
+        }
+        Ok(())
+    }
+
+    default fn _ensure_paused<E: From<PausableError>>(&self) -> Result<(), E> {
+        if !self.data().paused {
+            return Err(From::from(PausableError::NotPaused))
+        }
+        Ok(())
+    }
 }
@@ -23,10 +23,11 @@ pub use crate::{
     reentrancy_guard,
     traits::errors::ReentrancyGuardError,
 };
+pub use reentrancy_guard::Internal as _;
 
-use ink_storage::traits::push_spread_root;
 use openbrush::{
     modifier_definition,
+    storage::Mapping,
     traits::Storage,
 };
 
@@ -35,12 +36,13 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 #[derive(Default, Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
-    pub status: u8,
+    /// Keys of the currently acquired locks
+    pub locks: Mapping<u8, bool>,
     pub _reserved: Option<()>,
 }
 
-const NOT_ENTERED: u8 = 0;
-const ENTERED: u8 = 1;
+/// The key of the lock used by the `non_reentrant` modifier.
+pub const DEFAULT_KEY: u8 = 0;
 
 /// Prevents a contract from calling itself, directly or indirectly.
 /// Calling a `non_reentrant` function from another `non_reentrant`
@@ -48,8 +50,11 @@ const ENTERED: u8 = 1;
 /// by making the `non_reentrant` function external, and make it call a
 /// `private` function that does the actual work.
 ///
-/// This modifier flushes the struct into storage with `ENTERED`
-/// status before calling the original method.
+/// It is the `non_reentrant_key` modifier over the `DEFAULT_KEY` lock.
+///
+/// Put it first when it is combined with other modifiers, like `#[modifiers(non_reentrant, only_owner)]`.
+/// The first modifier is the outermost one, so the lock is acquired before the other modifiers
+/// are checked and is released after them, even if they fail.
 #[modifier_definition]
 pub fn non_reentrant<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
 where
@@ -57,18 +62,65 @@ where
     F: FnOnce(&mut T) -> Result<R, E>,
     E: From<ReentrancyGuardError>,
 {
-    if instance.data().status == ENTERED {
-        return Err(From::from(ReentrancyGuardError::ReentrantCall))
-    }
-    // Any calls to nonReentrant after this point will fail
-    instance.data().status = ENTERED;
+    non_reentrant_key(instance, body, DEFAULT_KEY)
+}
 
-    // We want to flush storage before execution of inner function,
-    // because ink! doesn't do it by default and `status` will not be updated in child calls
-    push_spread_root(instance.data(), &Default::default());
+/// Prevents a contract from calling itself, directly or indirectly, while the lock `key` is acquired.
+/// The locks with different keys are independent, so the functions protecting
+/// different logical domains of the contract can call each other.
+///
+/// The lock is stored in the `Mapping`, so it is written into storage
+/// before calling the original method.
+#[modifier_definition]
+pub fn non_reentrant_key<T, F, R, E>(instance: &mut T, body: F, key: u8) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<ReentrancyGuardError>,
+{
+    // Any calls protected by the same key after this point will fail
+    instance._acquire(key)?;
 
     let result = body(instance);
-    instance.data().status = NOT_ENTERED;
+    instance._release(key);
 
     return result
 }
+
+pub trait Internal {
+    /// Returns `true` if the execution is currently inside of a `non_reentrant` function.
+    fn _entered(&self) -> bool;
+
+    /// Returns `true` if the lock `key` is acquired.
+    fn _entered_key(&self, key: u8) -> bool;
+
+    /// Acquires the lock `key`.
+    ///
+    /// Returns with `ReentrantCall` error if the lock is already acquired.
+    fn _acquire(&mut self, key: u8) -> Result<(), ReentrancyGuardError>;
+
+    /// Releases the lock `key`.
+    fn _release(&mut self, key: u8);
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _entered(&self) -> bool {
+        self._entered_key(DEFAULT_KEY)
+    }
+
+    default fn _entered_key(&self, key: u8) -> bool {
+        self.data().locks.get(&key).unwrap_or(false)
+    }
+
+    default fn _acquire(&mut self, key: u8) -> Result<(), ReentrancyGuardError> {
+        if self._entered_key(key) {
+            return Err(ReentrancyGuardError::ReentrantCall)
+        }
+        self.data().locks.insert(&key, &true);
+        Ok(())
+    }
+
+    default fn _release(&mut self, key: u8) {
+        self.data().locks.remove(&key);
+    }
+}
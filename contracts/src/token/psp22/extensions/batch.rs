@@ -0,0 +1,70 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    traits::psp22::{
+        extensions::batch::*,
+        *,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    AccountIdExt,
+    Balance,
+    Storage,
+};
+
+impl<T: Storage<psp22::Data>> PSP22Batch for T {
+    default fn batch_transfer(
+        &mut self,
+        transfers: Vec<(AccountId, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        let from = Self::env().caller();
+
+        // Check the whole batch against the running balance before the first transfer,
+        // so the batch is not applied partially
+        let mut balance = self._balance_of(&from);
+        for (to, value) in transfers.iter() {
+            if to.is_zero() {
+                return Err(PSP22Error::ZeroRecipientAddress)
+            }
+            if balance < *value {
+                return Err(PSP22Error::InsufficientBalance)
+            }
+            if to != &from {
+                balance -= value;
+            }
+        }
+
+        for (to, value) in transfers.into_iter() {
+            self._transfer_from_to(from, to, value, data.clone())?;
+        }
+        Ok(())
+    }
+}
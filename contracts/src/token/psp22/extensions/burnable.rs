@@ -41,4 +41,19 @@ impl<T: Storage<psp22::Data>> PSP22Burnable for T {
     default fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
         self._burn_from(account, amount)
     }
+
+    default fn burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        let caller = Self::env().caller();
+        let allowance = self._allowance(&account, &caller);
+
+        if allowance < amount {
+            return Err(PSP22Error::InsufficientAllowance)
+        }
+
+        self._burn_from(account, amount)?;
+        if allowance != Balance::MAX {
+            self._approve_from_to(account, caller, allowance - amount)?;
+        }
+        Ok(())
+    }
 }
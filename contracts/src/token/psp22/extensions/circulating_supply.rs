@@ -0,0 +1,91 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::circulating_supply,
+    traits::psp22::{
+        extensions::circulating_supply::*,
+        *,
+    },
+};
+pub use circulating_supply::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub excluded: Vec<AccountId>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> PSP22CirculatingSupply for T {
+    default fn circulating_supply(&self) -> Balance {
+        // the balances are read on each call, so the result follows the transfers to and from excluded accounts
+        let excluded: Balance = self
+            .data::<Data>()
+            .excluded
+            .iter()
+            .map(|account| self._balance_of(account))
+            .sum();
+        self.total_supply().saturating_sub(excluded)
+    }
+
+    default fn is_excluded_from_circulation(&self, account: AccountId) -> bool {
+        self.data::<Data>().excluded.contains(&account)
+    }
+
+    default fn excluded_from_circulation(&self) -> Vec<AccountId> {
+        self.data::<Data>().excluded.clone()
+    }
+}
+
+pub trait Internal {
+    /// Excludes the balance of `account` from the circulating supply. Excluding it twice does nothing.
+    fn _exclude_from_circulation(&mut self, account: AccountId);
+
+    /// Includes the balance of `account` into the circulating supply again.
+    fn _include_in_circulation(&mut self, account: AccountId);
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> Internal for T {
+    default fn _exclude_from_circulation(&mut self, account: AccountId) {
+        if !self.is_excluded_from_circulation(account) {
+            self.data::<Data>().excluded.push(account);
+        }
+    }
+
+    default fn _include_in_circulation(&mut self, account: AccountId) {
+        self.data::<Data>().excluded.retain(|excluded| excluded != &account);
+    }
+}
@@ -78,6 +78,11 @@ impl<T: Storage<psp22::Data>> FlashLender for T {
         }
         self._approve_from_to(receiver_account, this, current_allowance - amount - fee)?;
         self._burn_from(receiver_account, amount + fee)?;
+        // the fee is burned from the receiver of the loan and minted to the fee receiver,
+        // so the total supply doesn't change
+        if let Some(fee_receiver) = self._flash_fee_receiver().filter(|_| fee > 0) {
+            self._mint_to(fee_receiver, fee)?;
+        }
         Ok(())
     }
 }
@@ -85,6 +90,10 @@ impl<T: Storage<psp22::Data>> FlashLender for T {
 pub trait Internal {
     fn _get_fee(&self, _amount: Balance) -> Balance;
 
+    /// Returns the account which receives the fees of flashloans.
+    /// By default it is `None`, which means that fees are burned.
+    fn _flash_fee_receiver(&self) -> Option<AccountId>;
+
     fn _on_flashloan(
         &mut self,
         receiver_account: AccountId,
@@ -100,6 +109,10 @@ impl<T: Storage<psp22::Data>> Internal for T {
         0
     }
 
+    default fn _flash_fee_receiver(&self) -> Option<AccountId> {
+        None
+    }
+
     default fn _on_flashloan(
         &mut self,
         receiver_account: AccountId,
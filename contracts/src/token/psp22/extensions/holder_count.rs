@@ -0,0 +1,82 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::holder_count,
+    traits::psp22::{
+        extensions::holder_count::*,
+        *,
+    },
+};
+pub use holder_count::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub holder_count: u128,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> PSP22HolderCount for T {
+    default fn holder_count(&self) -> u128 {
+        self.data::<Data>().holder_count
+    }
+}
+
+pub trait Internal {
+    /// Updates the number of holders before the balances of `from` and `to` are changed by `amount`.
+    ///
+    /// It must be called from `_before_token_transfer`.
+    fn _update_holder_count(&mut self, from: Option<&AccountId>, to: Option<&AccountId>, amount: &Balance);
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> Internal for T {
+    default fn _update_holder_count(&mut self, from: Option<&AccountId>, to: Option<&AccountId>, amount: &Balance) {
+        // zero-amount transfers and self-transfers don't change any balance
+        if *amount == 0 || (from.is_some() && from == to) {
+            return
+        }
+
+        if let Some(to) = to {
+            if self._balance_of(to) == 0 {
+                self.data::<Data>().holder_count += 1;
+            }
+        }
+        if let Some(from) = from {
+            if self._balance_of(from) == *amount {
+                self.data::<Data>().holder_count -= 1;
+            }
+        }
+    }
+}
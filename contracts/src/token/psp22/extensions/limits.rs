@@ -0,0 +1,150 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    psp22,
+    psp22::extensions::limits,
+    traits::{
+        ownable::{
+            Ownable,
+            OwnableError,
+            OwnableRef,
+        },
+        psp22::{
+            extensions::limits::*,
+            *,
+        },
+    },
+};
+pub use limits::Internal as _;
+pub use ownable::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub max_balance: Balance,
+    pub max_tx_amount: Balance,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data> + Storage<ownable::Data>> PSP22Limits for T {
+    default fn max_balance(&self) -> Balance {
+        self.data::<Data>().max_balance
+    }
+
+    default fn max_tx_amount(&self) -> Balance {
+        self.data::<Data>().max_tx_amount
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn set_max_balance(&mut self, limit: Balance) -> Result<(), PSP22Error> {
+        self._set_max_balance(limit);
+        Ok(())
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn set_max_tx_amount(&mut self, limit: Balance) -> Result<(), PSP22Error> {
+        self._set_max_tx_amount(limit);
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// Sets the max balance which one account can receive by transfers. Zero removes the limit.
+    fn _set_max_balance(&mut self, limit: Balance);
+
+    /// Sets the max amount of one transfer. Zero removes the limit.
+    fn _set_max_tx_amount(&mut self, limit: Balance);
+
+    /// Returns `true` if transfers of `account` are not limited. By default only the owner is exempt.
+    fn _is_limit_exempt(&self, account: &AccountId) -> bool;
+
+    /// Returns `MaxTxExceeded` error if `amount` exceeds the max transaction amount
+    /// and `MaxBalanceExceeded` error if the balance of `to` would exceed the max balance.
+    ///
+    /// It must be called from `_before_token_transfer`. Minting and burning are not limited.
+    /// `transfer_from` spends the allowance before the hook is called, and a failed message
+    /// doesn't revert the storage, so the allowance stays spent if the transfer is rejected.
+    /// The transfers from or to the exempt account are not limited by the max transaction amount,
+    /// and the exempt recipient is not limited by the max balance.
+    fn _check_transfer_limits(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data> + Storage<ownable::Data>> Internal for T {
+    default fn _set_max_balance(&mut self, limit: Balance) {
+        self.data::<Data>().max_balance = limit;
+    }
+
+    default fn _set_max_tx_amount(&mut self, limit: Balance) {
+        self.data::<Data>().max_tx_amount = limit;
+    }
+
+    default fn _is_limit_exempt(&self, account: &AccountId) -> bool {
+        self.data::<ownable::Data>().owner == *account
+    }
+
+    default fn _check_transfer_limits(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+        let to_is_exempt = self._is_limit_exempt(to);
+
+        let max_tx_amount = self.data::<Data>().max_tx_amount;
+        if max_tx_amount != 0 && *amount > max_tx_amount && !self._is_limit_exempt(from) && !to_is_exempt {
+            return Err(PSP22Error::MaxTxExceeded)
+        }
+
+        // the transfer to itself doesn't change the balance
+        let max_balance = self.data::<Data>().max_balance;
+        if max_balance != 0 && from != to && !to_is_exempt && self._balance_of(to).saturating_add(*amount) > max_balance
+        {
+            return Err(PSP22Error::MaxBalanceExceeded)
+        }
+        Ok(())
+    }
+}
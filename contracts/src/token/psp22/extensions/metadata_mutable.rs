@@ -0,0 +1,89 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::{
+        metadata,
+        metadata_mutable,
+    },
+    traits::psp22::{
+        extensions::metadata::*,
+        *,
+    },
+};
+pub use metadata_mutable::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::traits::{
+    Storage,
+    String,
+};
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_metadata_updated_event(&self, _name: Option<String>, _symbol: Option<String>, _decimals: u8);
+
+    /// Changes the name of the token.
+    ///
+    /// On success a `MetadataUpdated` event is emitted.
+    fn _set_token_name(&mut self, name: Option<String>);
+
+    /// Changes the symbol of the token.
+    ///
+    /// On success a `MetadataUpdated` event is emitted.
+    fn _set_token_symbol(&mut self, symbol: Option<String>);
+
+    /// Changes the decimals of the token.
+    ///
+    /// Balances are stored without decimals, so the change affects how all existing balances
+    /// are displayed by wallets and can be misleading for holders. Use it with care.
+    ///
+    /// On success a `MetadataUpdated` event is emitted.
+    fn _set_token_decimals(&mut self, decimals: u8);
+}
+
+impl<T: Storage<metadata::Data>> Internal for T {
+    default fn _emit_metadata_updated_event(&self, _name: Option<String>, _symbol: Option<String>, _decimals: u8) {}
+
+    default fn _set_token_name(&mut self, name: Option<String>) {
+        self.data().name = name;
+        emit_metadata_updated(self);
+    }
+
+    default fn _set_token_symbol(&mut self, symbol: Option<String>) {
+        self.data().symbol = symbol;
+        emit_metadata_updated(self);
+    }
+
+    default fn _set_token_decimals(&mut self, decimals: u8) {
+        self.data().decimals = decimals;
+        emit_metadata_updated(self);
+    }
+}
+
+fn emit_metadata_updated<T: Storage<metadata::Data>>(instance: &T) {
+    let data = instance.data();
+    instance._emit_metadata_updated_event(data.name.clone(), data.symbol.clone(), data.decimals);
+}
@@ -0,0 +1,142 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::mint_rate_limit,
+    traits::psp22::{
+        extensions::mint_rate_limit::*,
+        *,
+    },
+};
+pub use mint_rate_limit::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::traits::{
+    Balance,
+    Storage,
+    String,
+    Timestamp,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub period: Timestamp,
+    pub cap_per_period: Balance,
+    // Start of the first window, the next windows follow it every `period`
+    pub first_window_start: Timestamp,
+    // Start of the window in which `minted_in_window` was minted
+    pub window_start: Timestamp,
+    pub minted_in_window: Balance,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP22MintRateLimit for T {
+    default fn mint_period(&self) -> Timestamp {
+        self.data().period
+    }
+
+    default fn mint_cap_per_period(&self) -> Balance {
+        self.data().cap_per_period
+    }
+
+    default fn mint_allowance_remaining(&self) -> Balance {
+        if self.data().period == 0 {
+            return Balance::MAX
+        }
+        self.data()
+            .cap_per_period
+            .saturating_sub(self._minted_in_current_window())
+    }
+}
+
+pub trait Internal {
+    /// Limits the mints to `cap_per_period` tokens per `period` milliseconds.
+    /// The first window starts at the current block.
+    ///
+    /// Returns `Custom` error if `period` is zero.
+    fn _init_mint_rate_limit(&mut self, period: Timestamp, cap_per_period: Balance) -> Result<(), PSP22Error>;
+
+    /// Returns the start of the window which contains the current block.
+    fn _current_mint_window_start(&self) -> Timestamp;
+
+    /// Returns the amount of tokens minted within the current window.
+    /// The tokens minted in the previous windows are not counted.
+    fn _minted_in_current_window(&self) -> Balance;
+
+    /// Counts `amount` against the current window.
+    /// Returns `MintRateLimitExceeded` error if it exceeds the cap of the window.
+    ///
+    /// Should be called in `_before_token_transfer` when tokens are minted.
+    fn _consume_mint_allowance(&mut self, amount: &Balance) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _init_mint_rate_limit(&mut self, period: Timestamp, cap_per_period: Balance) -> Result<(), PSP22Error> {
+        if period == 0 {
+            return Err(PSP22Error::Custom(String::from("Period must be above 0")))
+        }
+        let now = Self::env().block_timestamp();
+        let data = self.data();
+        data.period = period;
+        data.cap_per_period = cap_per_period;
+        data.first_window_start = now;
+        data.window_start = now;
+        data.minted_in_window = 0;
+        Ok(())
+    }
+
+    default fn _current_mint_window_start(&self) -> Timestamp {
+        let data = self.data();
+        let now = Self::env().block_timestamp();
+        if data.period == 0 || now < data.first_window_start {
+            return data.first_window_start
+        }
+        now - (now - data.first_window_start) % data.period
+    }
+
+    default fn _minted_in_current_window(&self) -> Balance {
+        if self.data().window_start != self._current_mint_window_start() {
+            return 0
+        }
+        self.data().minted_in_window
+    }
+
+    default fn _consume_mint_allowance(&mut self, amount: &Balance) -> Result<(), PSP22Error> {
+        if self.data().period == 0 {
+            return Ok(())
+        }
+        if *amount > self.mint_allowance_remaining() {
+            return Err(PSP22Error::MintRateLimitExceeded)
+        }
+        // the counter is reset when the window rolls over
+        let minted = self._minted_in_current_window() + amount;
+        self.data().window_start = self._current_mint_window_start();
+        self.data().minted_in_window = minted;
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::partial_freeze,
+    traits::psp22::{
+        extensions::partial_freeze::*,
+        *,
+    },
+};
+pub use partial_freeze::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub frozen: Mapping<AccountId, Balance>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> PSP22PartialFreeze for T {
+    default fn frozen_balance(&self, account: AccountId) -> Balance {
+        self._frozen_balance(&account)
+    }
+}
+
+pub trait Internal {
+    fn _frozen_balance(&self, account: &AccountId) -> Balance;
+
+    /// Freezes `amount` more tokens of `account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` error if the frozen balance would exceed the balance of `account`.
+    fn _freeze(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+
+    /// Unfreezes `amount` tokens of `account`. Unfreezing more than the frozen balance unfreezes all of it.
+    fn _unfreeze(&mut self, account: AccountId, amount: Balance);
+
+    /// Returns `InsufficientUnfrozenBalance` error if `from` would transfer more than its unfrozen balance.
+    ///
+    /// It must be called from `_before_token_transfer`, so burning the frozen tokens is restricted too.
+    fn _ensure_unfrozen_balance(&self, from: Option<&AccountId>, amount: &Balance) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> Internal for T {
+    default fn _frozen_balance(&self, account: &AccountId) -> Balance {
+        self.data::<Data>().frozen.get(account).unwrap_or(0)
+    }
+
+    default fn _freeze(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        let frozen = self
+            ._frozen_balance(&account)
+            .checked_add(amount)
+            .ok_or(PSP22Error::InsufficientBalance)?;
+        if frozen > self._balance_of(&account) {
+            return Err(PSP22Error::InsufficientBalance)
+        }
+        self.data::<Data>().frozen.insert(&account, &frozen);
+        Ok(())
+    }
+
+    default fn _unfreeze(&mut self, account: AccountId, amount: Balance) {
+        let frozen = self._frozen_balance(&account).saturating_sub(amount);
+        if frozen == 0 {
+            self.data::<Data>().frozen.remove(&account);
+        } else {
+            self.data::<Data>().frozen.insert(&account, &frozen);
+        }
+    }
+
+    default fn _ensure_unfrozen_balance(&self, from: Option<&AccountId>, amount: &Balance) -> Result<(), PSP22Error> {
+        if let Some(from) = from {
+            let unfrozen = self._balance_of(from).saturating_sub(self._frozen_balance(from));
+            if *amount > unfrozen {
+                return Err(PSP22Error::InsufficientUnfrozenBalance)
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::permit,
+    traits::psp22::{
+        extensions::permit::*,
+        *,
+    },
+};
+use ink_env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+pub use permit::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+const PERMIT_DOMAIN: &[u8] = b"PSP22Permit";
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub nonces: Mapping<AccountId, u64>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> PSP22Permit for T {
+    default fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: [u8; 65],
+    ) -> Result<(), PSP22Error> {
+        if Self::env().block_timestamp() > deadline {
+            return Err(PSP22Error::PermitExpired)
+        }
+
+        let nonce = self.nonces(owner);
+        let message_hash = self._permit_message_hash(&owner, &spender, &value, nonce, &deadline);
+        if self._recover_signer(&signature, &message_hash) != Some(owner) {
+            return Err(PSP22Error::PermitInvalidSignature)
+        }
+
+        self.data::<Data>().nonces.insert(&owner, &(nonce + 1));
+        self._approve_from_to(owner, spender, value)
+    }
+
+    default fn nonces(&self, owner: AccountId) -> u64 {
+        self.data::<Data>().nonces.get(&owner).unwrap_or(0)
+    }
+
+    default fn domain_separator(&self) -> [u8; 32] {
+        self._domain_separator()
+    }
+}
+
+pub trait Internal {
+    /// Returns the hash of the permit message which should be signed by `owner`
+    fn _permit_message_hash(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        nonce: u64,
+        deadline: &Timestamp,
+    ) -> [u8; 32];
+
+    fn _domain_separator(&self) -> [u8; 32];
+
+    /// Returns the account which signed `message_hash` with ecdsa `signature`
+    fn _recover_signer(&self, signature: &[u8; 65], message_hash: &[u8; 32]) -> Option<AccountId>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _permit_message_hash(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        nonce: u64,
+        deadline: &Timestamp,
+    ) -> [u8; 32] {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_encoded::<Blake2x256, _>(
+            &(self._domain_separator(), owner, spender, value, nonce, deadline),
+            &mut output,
+        );
+        output
+    }
+
+    default fn _domain_separator(&self) -> [u8; 32] {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_encoded::<Blake2x256, _>(&(PERMIT_DOMAIN, Self::env().account_id()), &mut output);
+        output
+    }
+
+    default fn _recover_signer(&self, signature: &[u8; 65], message_hash: &[u8; 32]) -> Option<AccountId> {
+        crate::signature::recover_ecdsa(message_hash, signature).ok()
+    }
+}
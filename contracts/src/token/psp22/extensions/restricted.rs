@@ -0,0 +1,112 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::restricted,
+    traits::psp22::{
+        extensions::restricted::*,
+        *,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+pub use restricted::Internal as _;
+
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub blocked: Mapping<AccountId, bool>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP22Restricted for T {
+    default fn is_blocked(&self, account: AccountId) -> bool {
+        self._is_blocked(&account)
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_account_blocked_event(&self, _account: AccountId);
+    fn _emit_account_unblocked_event(&self, _account: AccountId);
+
+    fn _is_blocked(&self, account: &AccountId) -> bool;
+
+    /// Blocks `account`, so it can't send or receive tokens. Blocking the blocked account does nothing.
+    fn _block_account(&mut self, account: AccountId);
+
+    /// Unblocks `account`. Unblocking the account which is not blocked does nothing.
+    fn _unblock_account(&mut self, account: AccountId);
+
+    /// Returns `AccountBlocked` error if `from` or `to` is blocked.
+    ///
+    /// It must be called from `_before_token_transfer`, so minting to and burning from
+    /// the blocked account are restricted too.
+    fn _ensure_not_blocked(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _emit_account_blocked_event(&self, _account: AccountId) {}
+
+    default fn _emit_account_unblocked_event(&self, _account: AccountId) {}
+
+    default fn _is_blocked(&self, account: &AccountId) -> bool {
+        self.data().blocked.get(account).unwrap_or(false)
+    }
+
+    default fn _block_account(&mut self, account: AccountId) {
+        if self._is_blocked(&account) {
+            return
+        }
+        self.data().blocked.insert(&account, &true);
+        self._emit_account_blocked_event(account);
+    }
+
+    default fn _unblock_account(&mut self, account: AccountId) {
+        if !self._is_blocked(&account) {
+            return
+        }
+        self.data().blocked.remove(&account);
+        self._emit_account_unblocked_event(account);
+    }
+
+    default fn _ensure_not_blocked(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+        for account in from.into_iter().chain(to) {
+            if self._is_blocked(account) {
+                return Err(PSP22Error::AccountBlocked(*account))
+            }
+        }
+        Ok(())
+    }
+}
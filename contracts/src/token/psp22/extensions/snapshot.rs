@@ -0,0 +1,194 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    psp22,
+    psp22::extensions::snapshot,
+    traits::{
+        ownable::{
+            Ownable,
+            OwnableError,
+            OwnableRef,
+        },
+        psp22::{
+            extensions::snapshot::*,
+            *,
+        },
+    },
+};
+pub use ownable::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+pub use snapshot::Internal as _;
+
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub current_snapshot_id: u64,
+    // Checkpoints of balances by their index. Each checkpoint `(id, balance)` stores the balance
+    // at the moment of the snapshot `id`, if it was changed after that snapshot.
+    pub account_snapshots: Mapping<(AccountId, u32), (u64, Balance)>,
+    pub account_snapshots_count: Mapping<AccountId, u32>,
+    // Checkpoints of the total supply in the same format
+    pub total_supply_snapshots: Mapping<u32, (u64, Balance)>,
+    pub total_supply_snapshots_count: u32,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data> + Storage<ownable::Data>> PSP22Snapshot for T {
+    default fn current_snapshot_id(&self) -> u64 {
+        self.data::<Data>().current_snapshot_id
+    }
+
+    default fn balance_of_at(&self, account: AccountId, snapshot_id: u64) -> Balance {
+        let data = self.data::<Data>();
+        if !snapshot_exists(data, snapshot_id) {
+            return 0
+        }
+        let count = data.account_snapshots_count.get(&account).unwrap_or(0);
+        value_at(count, snapshot_id, |index| {
+            data.account_snapshots
+                .get(&(account.clone(), index))
+                .unwrap_or_default()
+        })
+        .unwrap_or(self._balance_of(&account))
+    }
+
+    default fn total_supply_at(&self, snapshot_id: u64) -> Balance {
+        let data = self.data::<Data>();
+        if !snapshot_exists(data, snapshot_id) {
+            return 0
+        }
+        value_at(data.total_supply_snapshots_count, snapshot_id, |index| {
+            data.total_supply_snapshots.get(&index).unwrap_or_default()
+        })
+        .unwrap_or(self.total_supply())
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn snapshot(&mut self) -> Result<u64, PSP22Error> {
+        Ok(self._snapshot())
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_snapshot_event(&self, _id: u64);
+
+    /// Creates a new snapshot and returns its id.
+    fn _snapshot(&mut self) -> u64;
+
+    /// Records the checkpoints of `from`, `to` and the total supply before their values are changed.
+    ///
+    /// It must be called from `_before_token_transfer`.
+    fn _update_snapshots(&mut self, from: Option<&AccountId>, to: Option<&AccountId>);
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> Internal for T {
+    default fn _emit_snapshot_event(&self, _id: u64) {}
+
+    default fn _snapshot(&mut self) -> u64 {
+        let id = self.data::<Data>().current_snapshot_id + 1;
+        self.data::<Data>().current_snapshot_id = id;
+        self._emit_snapshot_event(id);
+        id
+    }
+
+    default fn _update_snapshots(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) {
+        let current_id = self.data::<Data>().current_snapshot_id;
+        if current_id == 0 {
+            return
+        }
+
+        for account in [from, to].into_iter().flatten() {
+            let count = self.data::<Data>().account_snapshots_count.get(account).unwrap_or(0);
+            let last = count
+                .checked_sub(1)
+                .and_then(|index| self.data::<Data>().account_snapshots.get(&(account.clone(), index)));
+            if !is_recorded(last, current_id) {
+                let balance = self._balance_of(account);
+                let data = self.data::<Data>();
+                data.account_snapshots
+                    .insert(&(account.clone(), count), &(current_id, balance));
+                data.account_snapshots_count.insert(account, &(count + 1));
+            }
+        }
+
+        // `is_none` means that it is minting or burning
+        if from.is_none() || to.is_none() {
+            let count = self.data::<Data>().total_supply_snapshots_count;
+            let last = count
+                .checked_sub(1)
+                .and_then(|index| self.data::<Data>().total_supply_snapshots.get(&index));
+            if !is_recorded(last, current_id) {
+                let total_supply = self.total_supply();
+                let data = self.data::<Data>();
+                data.total_supply_snapshots.insert(&count, &(current_id, total_supply));
+                data.total_supply_snapshots_count = count + 1;
+            }
+        }
+    }
+}
+
+/// Returns `true` if the snapshot `id` was created, so the values at it can't change anymore.
+fn snapshot_exists(data: &Data, id: u64) -> bool {
+    id != 0 && id <= data.current_snapshot_id
+}
+
+/// Returns `true` if the `last` checkpoint already records the value at the snapshot `id`.
+fn is_recorded(last: Option<(u64, Balance)>, id: u64) -> bool {
+    matches!(last, Some((last_id, _)) if last_id >= id)
+}
+
+/// Returns the value at the snapshot `id` from `count` checkpoints sorted by the snapshot id,
+/// or `None` if the value wasn't changed after that snapshot.
+fn value_at(count: u32, id: u64, checkpoint: impl Fn(u32) -> (u64, Balance)) -> Option<Balance> {
+    // the first checkpoint created at or after the snapshot `id` holds the value at that snapshot
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if checkpoint(middle).0 < id {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    if low < count {
+        Some(checkpoint(low).1)
+    } else {
+        None
+    }
+}
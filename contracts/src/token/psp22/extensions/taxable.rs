@@ -0,0 +1,177 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::taxable,
+    traits::psp22::{
+        extensions::taxable::*,
+        *,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+pub use taxable::Internal as _;
+
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    AccountIdExt,
+    Balance,
+    Storage,
+    String,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+/// The maximal fee, it is the whole amount of the transfer
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub fee_bps: u16,
+    pub fee_recipient: Option<AccountId>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> PSP22Taxable for T {
+    default fn fee_bps(&self) -> u16 {
+        self.data::<Data>().fee_bps
+    }
+
+    default fn fee_recipient(&self) -> Option<AccountId> {
+        self.data::<Data>().fee_recipient.clone()
+    }
+}
+
+pub trait Internal {
+    /// Sets the fee charged on each transfer in basis points.
+    ///
+    /// Returns `Custom` error if `fee_bps` is above `MAX_FEE_BPS`.
+    fn _set_fee_bps(&mut self, fee_bps: u16) -> Result<(), PSP22Error>;
+
+    /// Sets the account which receives the fees.
+    ///
+    /// Returns `ZeroRecipientAddress` error if `account` is zero.
+    fn _set_fee_recipient(&mut self, account: AccountId) -> Result<(), PSP22Error>;
+
+    /// Returns the fee charged on the transfer of `amount`.
+    fn _fee_of(&self, amount: Balance) -> Balance;
+
+    /// Transfers `amount` from `from` to `to`, the fee part of `amount` is transferred to the fee recipient,
+    /// and the rest is delivered to `to`. On success one `Transfer` event is emitted per part.
+    ///
+    /// Transfers from or to the fee recipient are not charged. Minting and burning are not charged as well,
+    /// because they don't use `_transfer_from_to`.
+    ///
+    /// It must be called from `_transfer_from_to` instead of the default implementation.
+    fn _transfer_with_fee(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> Internal for T {
+    default fn _set_fee_bps(&mut self, fee_bps: u16) -> Result<(), PSP22Error> {
+        if fee_bps > MAX_FEE_BPS {
+            return Err(PSP22Error::Custom(String::from("Fee is above the maximum")))
+        }
+        self.data::<Data>().fee_bps = fee_bps;
+        Ok(())
+    }
+
+    default fn _set_fee_recipient(&mut self, account: AccountId) -> Result<(), PSP22Error> {
+        if account.is_zero() {
+            return Err(PSP22Error::ZeroRecipientAddress)
+        }
+        self.data::<Data>().fee_recipient = Some(account);
+        Ok(())
+    }
+
+    default fn _fee_of(&self, amount: Balance) -> Balance {
+        let fee_bps = Balance::from(self.data::<Data>().fee_bps);
+        let max_fee_bps = Balance::from(MAX_FEE_BPS);
+        // `amount * fee_bps / max_fee_bps` without the overflow
+        amount / max_fee_bps * fee_bps + amount % max_fee_bps * fee_bps / max_fee_bps
+    }
+
+    default fn _transfer_with_fee(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        if from.is_zero() {
+            return Err(PSP22Error::ZeroSenderAddress)
+        }
+        if to.is_zero() {
+            return Err(PSP22Error::ZeroRecipientAddress)
+        }
+        if self._balance_of(&from) < amount {
+            return Err(PSP22Error::InsufficientBalance)
+        }
+
+        let recipient = self.data::<Data>().fee_recipient.clone();
+        let fee = match recipient {
+            Some(recipient) if recipient != from && recipient != to => self._fee_of(amount),
+            _ => 0,
+        };
+        if fee > 0 {
+            move_fee(self, from, recipient.unwrap(), fee)?;
+        }
+
+        let net = amount - fee;
+        self._before_token_transfer(Some(&from), Some(&to), &net)?;
+        let from_balance = self._balance_of(&from);
+        self.data::<psp22::Data>().balances.insert(&from, &(from_balance - net));
+        self._do_safe_transfer_check(&from, &to, &net, &data)?;
+        let to_balance = self._balance_of(&to).checked_add(net).ok_or(PSP22Error::Overflow)?;
+        self.data::<psp22::Data>().balances.insert(&to, &to_balance);
+        self._after_token_transfer(Some(&from), Some(&to), &net)?;
+        self._emit_transfer_event(Some(from), Some(to), net);
+        Ok(())
+    }
+}
+
+/// Moves the fee from `from` to `recipient` calling the transfer hooks.
+fn move_fee<T>(instance: &mut T, from: AccountId, recipient: AccountId, fee: Balance) -> Result<(), PSP22Error>
+where
+    T: Storage<psp22::Data>,
+{
+    instance._before_token_transfer(Some(&from), Some(&recipient), &fee)?;
+    let from_balance = instance._balance_of(&from);
+    instance.data().balances.insert(&from, &(from_balance - fee));
+    let recipient_balance = instance
+        ._balance_of(&recipient)
+        .checked_add(fee)
+        .ok_or(PSP22Error::Overflow)?;
+    instance.data().balances.insert(&recipient, &recipient_balance);
+    instance._after_token_transfer(Some(&from), Some(&recipient), &fee)?;
+    instance._emit_transfer_event(Some(from), Some(recipient), fee);
+    Ok(())
+}
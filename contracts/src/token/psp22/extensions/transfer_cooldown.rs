@@ -0,0 +1,124 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    psp22,
+    psp22::extensions::transfer_cooldown,
+    traits::{
+        ownable::{
+            Ownable,
+            OwnableError,
+            OwnableRef,
+        },
+        psp22::{
+            extensions::transfer_cooldown::*,
+            *,
+        },
+    },
+};
+pub use ownable::Internal as _;
+pub use psp22::{
+    Internal as _,
+    Transfer as _,
+};
+pub use transfer_cooldown::Internal as _;
+
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub cooldown: Timestamp,
+    pub last_transfer: Mapping<AccountId, Timestamp>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> PSP22TransferCooldown for T {
+    default fn transfer_cooldown(&self) -> Timestamp {
+        self.data::<Data>().cooldown
+    }
+
+    default fn last_transfer(&self, account: AccountId) -> Option<Timestamp> {
+        self.data::<Data>().last_transfer.get(&account)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn set_transfer_cooldown(&mut self, cooldown: Timestamp) -> Result<(), PSP22Error> {
+        self._set_transfer_cooldown(cooldown);
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// Sets the minimum time in milliseconds between two transfers of one sender. Zero disables the cooldown.
+    fn _set_transfer_cooldown(&mut self, cooldown: Timestamp);
+
+    /// Returns `true` if transfers of `account` are not limited by the cooldown.
+    /// By default only the owner is exempt.
+    fn _is_cooldown_exempt(&self, account: &AccountId) -> bool;
+
+    /// Returns `CooldownNotElapsed` error if `from` transferred less than `cooldown` milliseconds ago,
+    /// otherwise records the current block as the time of its last transfer.
+    ///
+    /// It must be called from `_before_token_transfer`. Minting is exempt, because `from` is `None`.
+    fn _consume_transfer_cooldown(&mut self, from: Option<&AccountId>) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Internal for T {
+    default fn _set_transfer_cooldown(&mut self, cooldown: Timestamp) {
+        self.data::<Data>().cooldown = cooldown;
+    }
+
+    default fn _is_cooldown_exempt(&self, account: &AccountId) -> bool {
+        self.data::<ownable::Data>().owner == *account
+    }
+
+    default fn _consume_transfer_cooldown(&mut self, from: Option<&AccountId>) -> Result<(), PSP22Error> {
+        let from = match from {
+            Some(from) => from,
+            None => return Ok(()),
+        };
+        let cooldown = self.data::<Data>().cooldown;
+        if cooldown == 0 || self._is_cooldown_exempt(from) {
+            return Ok(())
+        }
+
+        let now = Self::env().block_timestamp();
+        if let Some(last) = self.data::<Data>().last_transfer.get(from) {
+            if now < last.saturating_add(cooldown) {
+                return Err(PSP22Error::CooldownNotElapsed)
+            }
+        }
+        self.data::<Data>().last_transfer.insert(from, &now);
+        Ok(())
+    }
+}
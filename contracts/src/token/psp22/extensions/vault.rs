@@ -0,0 +1,360 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::vault,
+    traits::psp22::{
+        extensions::vault::*,
+        *,
+    },
+};
+pub use psp22::Internal as _;
+pub use vault::Internal as _;
+
+use ink_env::CallFlags;
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub asset: AccountId,
+    pub _reserved: Option<()>,
+}
+
+/// Direction of the rounding during the conversion between assets and shares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> PSP22Vault for T {
+    default fn asset(&self) -> AccountId {
+        self.data::<Data>().asset
+    }
+
+    default fn total_assets(&self) -> Balance {
+        self._total_assets()
+    }
+
+    default fn convert_to_shares(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_shares(assets, Rounding::Down)
+    }
+
+    default fn convert_to_assets(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_assets(shares, Rounding::Down)
+    }
+
+    default fn preview_deposit(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_shares(assets, Rounding::Down)
+    }
+
+    default fn preview_mint(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_assets(shares, Rounding::Up)
+    }
+
+    default fn preview_withdraw(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_shares(assets, Rounding::Up)
+    }
+
+    default fn preview_redeem(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_assets(shares, Rounding::Down)
+    }
+
+    default fn deposit(&mut self, assets: Balance, receiver: AccountId) -> Result<Balance, PSP22Error> {
+        let shares = self.preview_deposit(assets)?;
+        self._deposit_assets(Self::env().caller(), receiver, assets, shares)?;
+        Ok(shares)
+    }
+
+    default fn mint(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance, PSP22Error> {
+        let assets = self.preview_mint(shares)?;
+        self._deposit_assets(Self::env().caller(), receiver, assets, shares)?;
+        Ok(assets)
+    }
+
+    default fn withdraw(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+    ) -> Result<Balance, PSP22Error> {
+        let shares = self.preview_withdraw(assets)?;
+        self._withdraw_assets(Self::env().caller(), receiver, owner, assets, shares)?;
+        Ok(shares)
+    }
+
+    default fn redeem(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+    ) -> Result<Balance, PSP22Error> {
+        let assets = self.preview_redeem(shares)?;
+        self._withdraw_assets(Self::env().caller(), receiver, owner, assets, shares)?;
+        Ok(assets)
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_deposit_event(&self, _sender: AccountId, _owner: AccountId, _assets: Balance, _shares: Balance);
+    fn _emit_withdraw_event(
+        &self,
+        _sender: AccountId,
+        _receiver: AccountId,
+        _owner: AccountId,
+        _assets: Balance,
+        _shares: Balance,
+    );
+
+    /// Initalize the vault with defining the underlying PSP22 asset
+    fn _init_vault(&mut self, asset: AccountId);
+
+    /// The vault acts as if it had `10 ^ offset` more shares and one more asset than it really has.
+    /// It makes the inflation of the share price by the first depositor unprofitable.
+    /// A bigger offset makes such attack more expensive.
+    fn _decimals_offset(&self) -> u8;
+
+    /// Converts `assets` to shares with the defined rounding.
+    fn _convert_to_shares(&self, assets: Balance, rounding: Rounding) -> Result<Balance, PSP22Error>;
+
+    /// Converts `shares` to assets with the defined rounding.
+    fn _convert_to_assets(&self, shares: Balance, rounding: Rounding) -> Result<Balance, PSP22Error>;
+
+    /// Takes `assets` from `caller` and mints `shares` to `receiver`.
+    fn _deposit_assets(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `shares` of `owner` and sends `assets` to `receiver`.
+    /// The allowance of `caller` is spent if it is not `owner`.
+    fn _withdraw_assets(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        owner: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// helper function to get balance of underlying assets in the contract
+    fn _total_assets(&self) -> Balance;
+
+    /// helper function to transfer the underlying assets from `from` to the contract
+    fn _transfer_assets_in(&mut self, from: AccountId, assets: Balance) -> Result<(), PSP22Error>;
+
+    /// helper function to transfer the underlying assets from the contract to `to`
+    fn _transfer_assets_out(&mut self, to: AccountId, assets: Balance) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<psp22::Data> + Storage<Data>> Internal for T {
+    default fn _emit_deposit_event(&self, _sender: AccountId, _owner: AccountId, _assets: Balance, _shares: Balance) {}
+
+    default fn _emit_withdraw_event(
+        &self,
+        _sender: AccountId,
+        _receiver: AccountId,
+        _owner: AccountId,
+        _assets: Balance,
+        _shares: Balance,
+    ) {
+    }
+
+    default fn _init_vault(&mut self, asset: AccountId) {
+        self.data::<Data>().asset = asset;
+    }
+
+    default fn _decimals_offset(&self) -> u8 {
+        0
+    }
+
+    default fn _convert_to_shares(&self, assets: Balance, rounding: Rounding) -> Result<Balance, PSP22Error> {
+        let virtual_shares = virtual_shares(self._decimals_offset())?;
+        mul_div(
+            assets,
+            self.total_supply()
+                .checked_add(virtual_shares)
+                .ok_or(PSP22Error::Overflow)?,
+            self._total_assets().checked_add(1).ok_or(PSP22Error::Overflow)?,
+            rounding,
+        )
+    }
+
+    default fn _convert_to_assets(&self, shares: Balance, rounding: Rounding) -> Result<Balance, PSP22Error> {
+        let virtual_shares = virtual_shares(self._decimals_offset())?;
+        mul_div(
+            shares,
+            self._total_assets().checked_add(1).ok_or(PSP22Error::Overflow)?,
+            self.total_supply()
+                .checked_add(virtual_shares)
+                .ok_or(PSP22Error::Overflow)?,
+            rounding,
+        )
+    }
+
+    default fn _deposit_assets(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._transfer_assets_in(caller, assets)?;
+        self._mint_to(receiver, shares)?;
+        self._emit_deposit_event(caller, receiver, assets, shares);
+        Ok(())
+    }
+
+    default fn _withdraw_assets(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        owner: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error> {
+        if self._balance_of(&owner) < shares {
+            return Err(PSP22Error::InsufficientBalance)
+        }
+
+        if caller != owner {
+            let allowance = self._allowance(&owner, &caller);
+
+            if allowance < shares {
+                return Err(PSP22Error::InsufficientAllowance)
+            }
+
+            if allowance != Balance::MAX {
+                self._approve_from_to(owner, caller, allowance - shares)?;
+            }
+        }
+
+        self._burn_from(owner, shares)?;
+        self._transfer_assets_out(receiver, assets)?;
+        self._emit_withdraw_event(caller, receiver, owner, assets, shares);
+        Ok(())
+    }
+
+    default fn _total_assets(&self) -> Balance {
+        PSP22Ref::balance_of(&self.data::<Data>().asset, Self::env().account_id())
+    }
+
+    default fn _transfer_assets_in(&mut self, from: AccountId, assets: Balance) -> Result<(), PSP22Error> {
+        PSP22Ref::transfer_from_builder(
+            &self.data::<Data>().asset,
+            from,
+            Self::env().account_id(),
+            assets,
+            Vec::<u8>::new(),
+        )
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .fire()
+        .unwrap()
+    }
+
+    default fn _transfer_assets_out(&mut self, to: AccountId, assets: Balance) -> Result<(), PSP22Error> {
+        PSP22Ref::transfer_builder(&self.data::<Data>().asset, to, assets, Vec::<u8>::new())
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .fire()
+            .unwrap()
+    }
+}
+
+fn virtual_shares(decimals_offset: u8) -> Result<Balance, PSP22Error> {
+    (10 as Balance)
+        .checked_pow(decimals_offset as u32)
+        .ok_or(PSP22Error::Overflow)
+}
+
+/// Returns `value * numerator / denominator` with the defined rounding.
+///
+/// The product is calculated in 256 bits, so only the result must fit `Balance`.
+fn mul_div(
+    value: Balance,
+    numerator: Balance,
+    denominator: Balance,
+    rounding: Rounding,
+) -> Result<Balance, PSP22Error> {
+    let (high, low) = full_mul(value, numerator);
+    let (result, remainder) = full_div(high, low, denominator).ok_or(PSP22Error::Overflow)?;
+
+    if rounding == Rounding::Up && remainder != 0 {
+        return result.checked_add(1).ok_or(PSP22Error::Overflow)
+    }
+    Ok(result)
+}
+
+const HALF_BITS: u32 = Balance::BITS / 2;
+const HALF_MASK: Balance = Balance::MAX >> HALF_BITS;
+
+/// Returns the high and the low halves of the 256 bits product `a * b`.
+fn full_mul(a: Balance, b: Balance) -> (Balance, Balance) {
+    let (a_high, a_low) = (a >> HALF_BITS, a & HALF_MASK);
+    let (b_high, b_low) = (b >> HALF_BITS, b & HALF_MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    // the sum of three halves can't overflow a full `Balance`
+    let middle = (low_low >> HALF_BITS) + (low_high & HALF_MASK) + (high_low & HALF_MASK);
+    let low = (low_low & HALF_MASK) | (middle << HALF_BITS);
+    let high = high_high + (low_high >> HALF_BITS) + (high_low >> HALF_BITS) + (middle >> HALF_BITS);
+    (high, low)
+}
+
+/// Divides the 256 bits value `high * 2^128 + low` by `denominator` and returns the quotient
+/// and the remainder, or `None` if the quotient doesn't fit `Balance`.
+fn full_div(high: Balance, low: Balance, denominator: Balance) -> Option<(Balance, Balance)> {
+    if high >= denominator {
+        return None
+    }
+    if high == 0 {
+        return Some((low / denominator, low % denominator))
+    }
+
+    // long division by one bit, the remainder is always below the denominator
+    let mut quotient: Balance = 0;
+    let mut remainder = high;
+    for bit in (0..Balance::BITS).rev() {
+        let carry = remainder >> (Balance::BITS - 1);
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1 << bit;
+        }
+    }
+    Some((quotient, remainder))
+}
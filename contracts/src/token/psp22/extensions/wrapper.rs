@@ -84,7 +84,7 @@ pub trait Internal {
 
 impl<T: Storage<psp22::Data> + Storage<Data>> Internal for T {
     default fn _recover(&mut self, account: AccountId) -> Result<Balance, PSP22Error> {
-        let value = self._underlying_balance() - self.total_supply();
+        let value = self._underlying_balance().saturating_sub(self.total_supply());
         self._mint_to(account, value)?;
         Ok(value)
     }
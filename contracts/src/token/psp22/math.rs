@@ -0,0 +1,49 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::Balance;
+
+/// Converts `amount` of the token with `from_decimals` precision into the precision of `to_decimals`,
+/// for example, the assets of the vault into its shares when they have different decimals.
+///
+/// Downscaling truncates the digits which can't be represented by `to_decimals`,
+/// so `scale_to(1999, 3, 0)` returns `1`. It is a pure function, no storage is read.
+///
+/// # Errors
+///
+/// Returns `Overflow` error if the upscaled amount exceeds `Balance::MAX`.
+pub fn scale_to(amount: Balance, from_decimals: u8, to_decimals: u8) -> Result<Balance, PSP22Error> {
+    if to_decimals >= from_decimals {
+        let factor = pow10(to_decimals - from_decimals).ok_or(PSP22Error::Overflow)?;
+        amount.checked_mul(factor).ok_or(PSP22Error::Overflow)
+    } else {
+        // any balance is below the factor which doesn't fit `Balance`, so all digits are truncated
+        match pow10(from_decimals - to_decimals) {
+            Some(factor) => Ok(amount / factor),
+            None => Ok(0),
+        }
+    }
+}
+
+fn pow10(exponent: u8) -> Option<Balance> {
+    (10 as Balance).checked_pow(exponent as u32)
+}
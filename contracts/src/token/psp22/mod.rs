@@ -19,19 +19,40 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+pub mod math;
 pub mod psp22;
 
 pub use psp22::*;
 
 pub mod extensions {
+    pub mod batch;
     pub mod burnable;
     pub mod capped;
+    pub mod circulating_supply;
     pub mod flashmint;
+    pub mod holder_count;
+    #[cfg(feature = "ownable")]
+    pub mod limits;
     pub mod metadata;
+    pub mod metadata_mutable;
+    pub mod mint_rate_limit;
     pub mod mintable;
+    pub mod partial_freeze;
+    pub mod permit;
+    pub mod restricted;
+    #[cfg(feature = "ownable")]
+    pub mod snapshot;
+    pub mod taxable;
+    #[cfg(feature = "ownable")]
+    pub mod transfer_cooldown;
+    pub mod vault;
     pub mod wrapper;
 }
 
 pub mod utils {
+    pub mod crowdsale;
+    pub mod merkle_claim;
+    #[cfg(feature = "ownable")]
+    pub mod recoverable;
     pub mod token_timelock;
 }
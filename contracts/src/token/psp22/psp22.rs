@@ -98,7 +98,9 @@ impl<T: Storage<Data>> PSP22 for T {
             return Err(PSP22Error::InsufficientAllowance)
         }
 
-        self._approve_from_to(from, caller, allowance - value)?;
+        if allowance != Balance::MAX {
+            self._approve_from_to(from, caller, allowance - value)?;
+        }
         self._transfer_from_to(from, to, value, data)?;
         Ok(())
     }
@@ -130,6 +132,15 @@ pub trait Internal {
     /// User must override those methods in their contract.
     fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _amount: Balance);
     fn _emit_approval_event(&self, _owner: AccountId, _spender: AccountId, _amount: Balance);
+    /// Emitted by `_mint_to` right after the `Transfer` event with `None` as `from`.
+    ///
+    /// The `Transfer` event is still emitted as the PSP22 standard requires, so the indexers which only
+    /// track `Transfer` keep working. This event lets the consumers tell the mints apart without decoding `None`.
+    fn _emit_mint_event(&self, _to: AccountId, _amount: Balance);
+    /// Emitted by `_burn_from` right after the `Transfer` event with `None` as `to`.
+    ///
+    /// Same as `_emit_mint_event`, it is emitted in addition to `Transfer`, not instead of it.
+    fn _emit_burn_event(&self, _from: AccountId, _amount: Balance);
 
     fn _balance_of(&self, owner: &AccountId) -> Balance;
     fn _allowance(&self, owner: &AccountId, spender: &AccountId) -> Balance;
@@ -142,6 +153,20 @@ pub trait Internal {
         data: &Vec<u8>,
     ) -> Result<(), PSP22Error>;
 
+    /// Moves `amount` tokens from `from` to `to`, without checking the caller and the allowance.
+    ///
+    /// `from` and `to` may be the same account, and `to` may be the address of the contract itself.
+    /// Such transfers only move the tokens between balances, the total supply is never changed.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `ZeroSenderAddress` error if `from` is zero account.
+    ///
+    /// Returns with `ZeroRecipientAddress` error if `to` is zero account.
+    ///
+    /// Returns with `InsufficientBalance` error if `from` doesn't have enough balance.
     fn _transfer_from_to(
         &mut self,
         from: AccountId,
@@ -150,16 +175,50 @@ pub trait Internal {
         data: Vec<u8>,
     ) -> Result<(), PSP22Error>;
 
+    /// Sets `amount` as the allowance of `spender` over the tokens of `owner`, without checking the caller.
+    ///
+    /// It is the building block of `approve` and of the extensions which set allowances
+    /// on behalf of `owner`, like permit.
+    ///
+    /// On success a `Approval` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `ZeroSenderAddress` error if `owner` is zero account.
+    ///
+    /// Returns with `ZeroRecipientAddress` error if `spender` is zero account.
     fn _approve_from_to(&mut self, owner: AccountId, spender: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 
+    /// Creates `amount` tokens and assigns them to `account`, increasing the total supply.
+    ///
+    /// Minting doesn't need a sentinel account to take the tokens from: the transfer hooks and
+    /// the `Transfer` event get `None` as `from`. A `Mint` event is emitted after the `Transfer` one.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `ZeroRecipientAddress` error if `account` is zero account.
+    ///
+    /// Returns with `Overflow` error if the total supply or the balance of `account` would exceed `Balance::MAX`.
     fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 
+    /// Destroys `amount` tokens of `account`, decreasing the total supply.
+    ///
+    /// Burning doesn't need a sentinel account to send the tokens to: the transfer hooks and
+    /// the `Transfer` event get `None` as `to`. A `Burn` event is emitted after the `Transfer` one.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `ZeroRecipientAddress` error if `account` is zero account.
+    ///
+    /// Returns with `InsufficientBalance` error if `account` doesn't have enough balance.
     fn _burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 }
 
 impl<T: Storage<Data>> Internal for T {
     default fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _amount: Balance) {}
     default fn _emit_approval_event(&self, _owner: AccountId, _spender: AccountId, _amount: Balance) {}
+    default fn _emit_mint_event(&self, _to: AccountId, _amount: Balance) {}
+    default fn _emit_burn_event(&self, _from: AccountId, _amount: Balance) {}
 
     default fn _balance_of(&self, owner: &AccountId) -> Balance {
         self.data().balances.get(owner).unwrap_or(0)
@@ -239,8 +298,8 @@ impl<T: Storage<Data>> Internal for T {
 
         self._do_safe_transfer_check(&from, &to, &amount, &data)?;
 
-        let to_balance = self._balance_of(&to);
-        self.data().balances.insert(&to, &(to_balance + amount));
+        let to_balance = self._balance_of(&to).checked_add(amount).ok_or(PSP22Error::Overflow)?;
+        self.data().balances.insert(&to, &to_balance);
 
         self._after_token_transfer(Some(&from), Some(&to), &amount)?;
         self._emit_transfer_event(Some(from), Some(to), amount);
@@ -271,13 +330,18 @@ impl<T: Storage<Data>> Internal for T {
             return Err(PSP22Error::ZeroRecipientAddress)
         }
 
+        // the balance of the account can't exceed the total supply, but both are checked to not rely on it
+        let new_supply = self.data().supply.checked_add(amount).ok_or(PSP22Error::Overflow)?;
         self._before_token_transfer(None, Some(&account), &amount)?;
-        let mut new_balance = self._balance_of(&account);
-        new_balance += amount;
+        let new_balance = self
+            ._balance_of(&account)
+            .checked_add(amount)
+            .ok_or(PSP22Error::Overflow)?;
         self.data().balances.insert(&account, &new_balance);
-        self.data().supply += amount;
+        self.data().supply = new_supply;
         self._after_token_transfer(None, Some(&account), &amount)?;
         self._emit_transfer_event(None, Some(account), amount);
+        self._emit_mint_event(account, amount);
 
         Ok(())
     }
@@ -300,6 +364,8 @@ impl<T: Storage<Data>> Internal for T {
         self.data().supply -= amount;
         self._after_token_transfer(Some(&account), None, &amount)?;
         self._emit_transfer_event(Some(account), None, amount);
+        self._emit_burn_event(account, amount);
+        self._after_burn(&account, &amount)?;
 
         Ok(())
     }
@@ -319,6 +385,10 @@ pub trait Transfer {
         _to: Option<&AccountId>,
         _amount: &Balance,
     ) -> Result<(), PSP22Error>;
+
+    /// Called by `_burn_from` after the balance and the supply are decreased.
+    /// Override it to react on burns, for example to adjust a reward index.
+    fn _after_burn(&mut self, _from: &AccountId, _amount: &Balance) -> Result<(), PSP22Error>;
 }
 
 impl<T: Storage<Data>> Transfer for T {
@@ -339,4 +409,8 @@ impl<T: Storage<Data>> Transfer for T {
     ) -> Result<(), PSP22Error> {
         Ok(())
     }
+
+    default fn _after_burn(&mut self, _from: &AccountId, _amount: &Balance) -> Result<(), PSP22Error> {
+        Ok(())
+    }
 }
@@ -0,0 +1,214 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Sale of the [`PSP22`] tokens for the native currency, minted to the buyers
+pub use crate::{
+    psp22::utils::crowdsale,
+    traits::psp22::{
+        extensions::mintable::*,
+        utils::crowdsale::*,
+    },
+};
+pub use crowdsale::Internal as _;
+
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        AccountIdExt,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub token: AccountId,
+    pub wallet: AccountId,
+    pub rate: Balance,
+    pub cap: Balance,
+    pub wallet_limit: Balance,
+    pub funds_raised: Balance,
+    pub contributions: Mapping<AccountId, Balance>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP22Crowdsale for T {
+    default fn token(&self) -> AccountId {
+        self.data().token
+    }
+
+    default fn wallet(&self) -> AccountId {
+        self.data().wallet
+    }
+
+    default fn rate(&self) -> Balance {
+        self.data().rate
+    }
+
+    default fn funds_raised(&self) -> Balance {
+        self.data().funds_raised
+    }
+
+    default fn cap(&self) -> Balance {
+        self.data().cap
+    }
+
+    default fn wallet_limit(&self) -> Balance {
+        self.data().wallet_limit
+    }
+
+    default fn contribution_of(&self, beneficiary: AccountId) -> Balance {
+        self.data().contributions.get(&beneficiary).unwrap_or(0)
+    }
+
+    default fn buy_tokens(&mut self, beneficiary: AccountId) -> Result<(), PSP22CrowdsaleError> {
+        let purchaser = Self::env().caller();
+        let value = Self::env().transferred_value();
+
+        if beneficiary.is_zero() {
+            return Err(PSP22CrowdsaleError::ZeroBeneficiaryAddress)
+        }
+        if value == 0 {
+            return Err(PSP22CrowdsaleError::ZeroPurchase)
+        }
+
+        let funds_raised = self.data().funds_raised;
+        let remaining = self.data().cap.saturating_sub(funds_raised);
+        if remaining == 0 {
+            return Err(PSP22CrowdsaleError::CapReached)
+        }
+        // the last purchase is cut to the cap, the excess is refunded
+        let accepted = value.min(remaining);
+        let refund = value - accepted;
+
+        let contribution = self
+            .contribution_of(beneficiary)
+            .checked_add(accepted)
+            .ok_or(PSP22CrowdsaleError::WalletLimitExceeded)?;
+        if contribution > self.data().wallet_limit {
+            return Err(PSP22CrowdsaleError::WalletLimitExceeded)
+        }
+        let amount = self._token_amount(accepted)?;
+
+        self._deliver_tokens(beneficiary, amount)?;
+
+        self.data().funds_raised = funds_raised + accepted;
+        self.data().contributions.insert(&beneficiary, &contribution);
+        self._emit_tokens_purchased_event(purchaser, beneficiary, accepted, amount);
+
+        self._forward_funds(accepted)?;
+        if refund > 0 {
+            Self::env()
+                .transfer(purchaser, refund)
+                .map_err(|_| PSP22CrowdsaleError::TransferFailed)?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_tokens_purchased_event(
+        &self,
+        _purchaser: AccountId,
+        _beneficiary: AccountId,
+        _value: Balance,
+        _amount: Balance,
+    );
+
+    /// Initializes the sale of `token` at `rate` tokens per unit of the native currency.
+    ///
+    /// The raised funds are forwarded to `wallet`, the sale raises at most `cap`
+    /// and accepts at most `wallet_limit` per beneficiary.
+    fn _init(
+        &mut self,
+        token: AccountId,
+        wallet: AccountId,
+        rate: Balance,
+        cap: Balance,
+        wallet_limit: Balance,
+    ) -> Result<(), PSP22CrowdsaleError>;
+
+    /// Returns the amount of tokens bought for `value` of the native currency
+    fn _token_amount(&self, value: Balance) -> Result<Balance, PSP22CrowdsaleError>;
+
+    /// Delivers `amount` of tokens to `beneficiary`.
+    ///
+    /// By default it mints the tokens via [`PSP22Mintable::mint`], so the sale must be allowed
+    /// to mint the tokens. If the sale is a part of the token contract itself,
+    /// override it to call `psp22::Internal::_mint_to`.
+    fn _deliver_tokens(&mut self, beneficiary: AccountId, amount: Balance) -> Result<(), PSP22CrowdsaleError>;
+
+    /// Forwards `value` of the raised funds to the wallet
+    fn _forward_funds(&mut self, value: Balance) -> Result<(), PSP22CrowdsaleError>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _emit_tokens_purchased_event(
+        &self,
+        _purchaser: AccountId,
+        _beneficiary: AccountId,
+        _value: Balance,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn _init(
+        &mut self,
+        token: AccountId,
+        wallet: AccountId,
+        rate: Balance,
+        cap: Balance,
+        wallet_limit: Balance,
+    ) -> Result<(), PSP22CrowdsaleError> {
+        if wallet.is_zero() {
+            return Err(PSP22CrowdsaleError::PSP22Error(PSP22Error::ZeroRecipientAddress))
+        }
+        self.data().token = token;
+        self.data().wallet = wallet;
+        self.data().rate = rate;
+        self.data().cap = cap;
+        self.data().wallet_limit = wallet_limit;
+        Ok(())
+    }
+
+    default fn _token_amount(&self, value: Balance) -> Result<Balance, PSP22CrowdsaleError> {
+        value
+            .checked_mul(self.data().rate)
+            .ok_or(PSP22CrowdsaleError::PSP22Error(PSP22Error::Overflow))
+    }
+
+    default fn _deliver_tokens(&mut self, beneficiary: AccountId, amount: Balance) -> Result<(), PSP22CrowdsaleError> {
+        let token = self.data().token;
+        PSP22MintableRef::mint(&token, beneficiary, amount)?;
+        Ok(())
+    }
+
+    default fn _forward_funds(&mut self, value: Balance) -> Result<(), PSP22CrowdsaleError> {
+        Self::env()
+            .transfer(self.data().wallet, value)
+            .map_err(|_| PSP22CrowdsaleError::TransferFailed)
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Distribution of the [`PSP22`] tokens to the recipients proven with the merkle proof
+pub use crate::{
+    psp22::utils::merkle_claim,
+    traits::psp22::{
+        utils::merkle_claim::*,
+        *,
+    },
+};
+pub use merkle_claim::Internal as _;
+
+use ink_env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use ink_prelude::vec::Vec;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub token: AccountId,
+    pub merkle_root: [u8; 32],
+    pub claimed: Mapping<u32, bool>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP22MerkleClaim for T {
+    default fn token(&self) -> AccountId {
+        self.data().token
+    }
+
+    default fn merkle_root(&self) -> [u8; 32] {
+        self.data().merkle_root
+    }
+
+    default fn is_claimed(&self, index: u32) -> bool {
+        self.data().claimed.get(&index).unwrap_or(false)
+    }
+
+    default fn claim(
+        &mut self,
+        index: u32,
+        account: AccountId,
+        amount: Balance,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), PSP22MerkleClaimError> {
+        if self.is_claimed(index) {
+            return Err(PSP22MerkleClaimError::AlreadyClaimed)
+        }
+        let leaf = self._leaf(index, &account, amount);
+        let root = self.data().merkle_root;
+        if !self._verify(&proof, &root, &leaf) {
+            return Err(PSP22MerkleClaimError::InvalidProof)
+        }
+
+        // the claim is marked before the delivery, so the delivery can't claim it again
+        self.data().claimed.insert(&index, &true);
+        if let Err(error) = self._deliver_tokens(account, amount) {
+            // the failed message doesn't revert the storage
+            self.data().claimed.remove(&index);
+            return Err(error)
+        }
+        self._emit_claimed_event(index, account, amount);
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_claimed_event(&self, _index: u32, _account: AccountId, _amount: Balance);
+
+    /// Initializes the distribution of `token` to the recipients committed with `merkle_root`
+    fn _init(&mut self, token: AccountId, merkle_root: [u8; 32]);
+
+    /// Returns the leaf of the merkle tree, the `Blake2x256` hash of the SCALE-encoded `(index, account, amount)`
+    fn _leaf(&self, index: u32, account: &AccountId, amount: Balance) -> [u8; 32];
+
+    /// Returns `true` if `proof` proves that `leaf` belongs to the tree with `root`.
+    ///
+    /// Each pair of nodes is hashed in the sorted order, so the proof doesn't need to specify
+    /// the position of the nodes.
+    fn _verify(&self, proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool;
+
+    /// Delivers `amount` of tokens to `account`.
+    ///
+    /// By default it transfers the tokens held by the contract. Override it to mint the tokens instead.
+    fn _deliver_tokens(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22MerkleClaimError>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _emit_claimed_event(&self, _index: u32, _account: AccountId, _amount: Balance) {}
+
+    default fn _init(&mut self, token: AccountId, merkle_root: [u8; 32]) {
+        self.data().token = token;
+        self.data().merkle_root = merkle_root;
+    }
+
+    default fn _leaf(&self, index: u32, account: &AccountId, amount: Balance) -> [u8; 32] {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_encoded::<Blake2x256, _>(&(index, account, amount), &mut output);
+        output
+    }
+
+    default fn _verify(&self, proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+        let computed = proof.iter().fold(*leaf, |node, sibling| hash_pair(&node, sibling));
+        &computed == root
+    }
+
+    default fn _deliver_tokens(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22MerkleClaimError> {
+        let token = self.data().token;
+        PSP22Ref::transfer(&token, account, amount, Vec::<u8>::new())?;
+        Ok(())
+    }
+}
+
+/// Returns the `Blake2x256` hash of the concatenation of the sorted `a` and `b`
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut input = [0u8; 64];
+    input[..32].copy_from_slice(first);
+    input[32..].copy_from_slice(second);
+
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(&input, &mut output);
+    output
+}
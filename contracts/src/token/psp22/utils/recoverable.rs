@@ -0,0 +1,84 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    psp22::utils::recoverable,
+    traits::{
+        ownable::{
+            Ownable,
+            OwnableError,
+            OwnableRef,
+        },
+        psp22::{
+            utils::recoverable::*,
+            *,
+        },
+    },
+};
+pub use ownable::Internal as _;
+pub use recoverable::Internal as _;
+
+use ink_prelude::vec::Vec;
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        AccountIdExt,
+        Balance,
+        Storage,
+        String,
+    },
+};
+
+impl<T: Storage<ownable::Data>> PSP22Recoverable for T {
+    #[modifiers(ownable::only_owner)]
+    default fn recover_token(&mut self, token: AccountId, amount: Balance, to: AccountId) -> Result<(), PSP22Error> {
+        if self._is_protected_token(&token) {
+            return Err(PSP22Error::Custom(String::from("Token can't be recovered")))
+        }
+        if to.is_zero() {
+            return Err(PSP22Error::ZeroRecipientAddress)
+        }
+        self._recover_token(token, amount, to)
+    }
+}
+
+pub trait Internal {
+    /// Returns `true` if `token` can't be recovered from the contract.
+    ///
+    /// By default only the token of the contract itself is protected, so the reserves
+    /// of the token can't be drained. Override it to protect other tokens held by the contract.
+    fn _is_protected_token(&self, token: &AccountId) -> bool;
+
+    /// Transfers `amount` of `token` held by the contract to `to`
+    fn _recover_token(&mut self, token: AccountId, amount: Balance, to: AccountId) -> Result<(), PSP22Error>;
+}
+
+impl<T: Storage<ownable::Data>> Internal for T {
+    default fn _is_protected_token(&self, token: &AccountId) -> bool {
+        token == &Self::env().account_id()
+    }
+
+    default fn _recover_token(&mut self, token: AccountId, amount: Balance, to: AccountId) -> Result<(), PSP22Error> {
+        PSP22Ref::transfer(&token, to, amount, Vec::<u8>::new())
+    }
+}
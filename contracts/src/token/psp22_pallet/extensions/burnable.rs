@@ -39,4 +39,24 @@ impl<T: Storage<psp22_pallet::Data>> PSP22Burnable for T {
     default fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
         self._burn_from(account, amount)
     }
+
+    default fn burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        if self.allowance(account, self._sender()) < amount {
+            return Err(PSP22Error::InsufficientAllowance)
+        }
+
+        // The pallet decreases the allowance during the approved transfer,
+        // so the tokens are moved to the contract and burned there.
+        let contract = Self::env().account_id();
+        let self_ = self.data();
+        self_.pallet_assets.transfer_approved(
+            self_.origin,
+            self_.asset_id,
+            account.clone(),
+            contract.clone(),
+            amount,
+        )?;
+        self._emit_transfer_event(Some(account), Some(contract), amount);
+        self._burn_from(contract, amount)
+    }
 }
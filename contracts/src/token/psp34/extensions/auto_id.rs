@@ -0,0 +1,97 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::{
+        balances,
+        extensions::auto_id,
+    },
+    traits::psp34::{
+        extensions::auto_id::*,
+        *,
+    },
+};
+pub use auto_id::Internal as _;
+pub use psp34::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::traits::{
+    AccountId,
+    OccupiedStorage,
+    Storage,
+    String,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    // The id of the next token, it only grows
+    pub next_id: u64,
+    pub _reserved: Option<()>,
+}
+
+impl<B, T> PSP34AutoId for T
+where
+    B: balances::BalancesManager,
+    T: Storage<psp34::Data<B>>,
+    T: Storage<Data>,
+    T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
+{
+    default fn mint_next(&mut self, account: AccountId) -> Result<Id, PSP34Error> {
+        self._mint_next(account)
+    }
+}
+
+pub trait Internal {
+    /// Mints a new token to `account` with the next free id and returns that id.
+    fn _mint_next(&mut self, account: AccountId) -> Result<Id, PSP34Error>;
+}
+
+impl<B, T> Internal for T
+where
+    B: balances::BalancesManager,
+    T: Storage<psp34::Data<B>>,
+    T: Storage<Data>,
+    T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
+{
+    default fn _mint_next(&mut self, account: AccountId) -> Result<Id, PSP34Error> {
+        let mut next_id = self.data::<Data>().next_id;
+        // skip the ids which were minted explicitly
+        while self._owner_of(&Id::U64(next_id)).is_some() {
+            next_id = increment(next_id)?;
+        }
+
+        let id = Id::U64(next_id);
+        self._mint_to(account, id.clone())?;
+        self.data::<Data>().next_id = increment(next_id)?;
+        Ok(id)
+    }
+}
+
+fn increment(id: u64) -> Result<u64, PSP34Error> {
+    id.checked_add(1)
+        .ok_or(PSP34Error::Custom(String::from("Ids are exhausted")))
+}
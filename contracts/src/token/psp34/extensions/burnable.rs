@@ -21,17 +21,22 @@
 
 pub use crate::{
     psp34,
-    psp34::balances,
+    psp34::{
+        balances,
+        extensions::burnable,
+    },
     traits::psp34::{
         extensions::burnable::*,
         *,
     },
 };
+pub use burnable::Internal as _;
 pub use psp34::{
     Internal as _,
     Transfer as _,
 };
 
+use ink_prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     OccupiedStorage,
@@ -45,6 +50,59 @@ where
     T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
 {
     default fn burn(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
+        let owner = self._check_token_exists(&id)?;
+        let caller = Self::env().caller();
+
+        if owner != account {
+            return Err(PSP34Error::NotApproved)
+        }
+        if owner != caller && !self._allowance(&owner, &caller, &Some(&id)) {
+            return Err(PSP34Error::NotApproved)
+        }
+
         self._burn_from(account, id)
     }
+
+    default fn burn_batch(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error> {
+        let caller = Self::env().caller();
+        if account != caller {
+            for id in ids.iter() {
+                if !self._allowance(&account, &caller, &Some(id)) {
+                    return Err(PSP34Error::NotApproved)
+                }
+            }
+        }
+
+        self._burn_batch_from(account, ids)
+    }
+}
+
+pub trait Internal {
+    /// Burns tokens with `ids` from `account` after checking that all of `ids` exist,
+    /// are owned by `account` and are not repeated. The approvals of the burned tokens are removed.
+    fn _burn_batch_from(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error>;
+}
+
+impl<B, T> Internal for T
+where
+    B: balances::BalancesManager,
+    T: Storage<psp34::Data<B>>,
+    T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
+{
+    default fn _burn_batch_from(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error> {
+        for (i, id) in ids.iter().enumerate() {
+            // the repeated id doesn't exist anymore when it is burned the second time
+            if ids[..i].contains(id) {
+                return Err(PSP34Error::TokenNotExists)
+            }
+            if self._check_token_exists(id)? != account {
+                return Err(PSP34Error::NotApproved)
+            }
+        }
+
+        for id in ids.into_iter() {
+            self._burn_from(account, id)?;
+        }
+        Ok(())
+    }
 }
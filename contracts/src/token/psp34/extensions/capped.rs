@@ -0,0 +1,114 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::{
+        balances,
+        extensions::capped,
+    },
+    traits::psp34::{
+        extensions::capped::*,
+        *,
+    },
+};
+pub use capped::Internal as _;
+pub use psp34::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::traits::{
+    Balance,
+    OccupiedStorage,
+    Storage,
+    String,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub cap: Balance,
+    // The number of tokens ever minted, burns don't decrease it
+    pub minted: Balance,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP34Capped for T {
+    default fn cap(&self) -> Balance {
+        self.data().cap
+    }
+}
+
+pub trait Internal {
+    /// Initializes the cap of the collection. Zero removes the cap.
+    ///
+    /// Returns `Custom` error if `cap` is below the current supply.
+    fn _init_cap(&mut self, cap: Balance) -> Result<(), PSP34Error>;
+
+    /// Returns `CollectionIsFull` error if minting one more token exceeds the cap.
+    /// Burned tokens free up room for the new ones.
+    ///
+    /// Should be called in `_before_token_transfer` when tokens are minted.
+    fn _ensure_cap_not_exceeded(&self) -> Result<(), PSP34Error>;
+
+    /// Returns `CollectionIsFull` error if minting one more token exceeds the cap
+    /// and counts the minted token. Unlike `_ensure_cap_not_exceeded`, burned tokens
+    /// don't free up room, so at most `cap` tokens are minted ever.
+    ///
+    /// Should be called in `_before_token_transfer` when tokens are minted.
+    fn _ensure_cap_not_exceeded_strict(&mut self) -> Result<(), PSP34Error>;
+}
+
+impl<B, T> Internal for T
+where
+    B: balances::BalancesManager,
+    T: Storage<psp34::Data<B>> + Storage<Data>,
+    T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
+{
+    default fn _init_cap(&mut self, cap: Balance) -> Result<(), PSP34Error> {
+        if cap != 0 && cap < self.total_supply() {
+            return Err(PSP34Error::Custom(String::from("Cap is below the current supply")))
+        }
+        self.data::<Data>().cap = cap;
+        Ok(())
+    }
+
+    default fn _ensure_cap_not_exceeded(&self) -> Result<(), PSP34Error> {
+        let cap = self.data::<Data>().cap;
+        if cap != 0 && self.total_supply() >= cap {
+            return Err(PSP34Error::CollectionIsFull)
+        }
+        Ok(())
+    }
+
+    default fn _ensure_cap_not_exceeded_strict(&mut self) -> Result<(), PSP34Error> {
+        let cap = self.data::<Data>().cap;
+        let minted = self.data::<Data>().minted;
+        if cap != 0 && minted >= cap {
+            return Err(PSP34Error::CollectionIsFull)
+        }
+        self.data::<Data>().minted = minted + 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    psp34,
+    psp34::{
+        balances,
+        extensions::lazy_mint,
+    },
+    traits::{
+        ownable::{
+            Ownable,
+            OwnableError,
+            OwnableRef,
+        },
+        psp34::{
+            extensions::lazy_mint::*,
+            *,
+        },
+    },
+};
+pub use lazy_mint::Internal as _;
+pub use ownable::Internal as _;
+pub use psp34::{
+    Internal as _,
+    Transfer as _,
+};
+
+use ink_env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        OccupiedStorage,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+const VOUCHER_DOMAIN: &[u8] = b"PSP34LazyMint";
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    // Ids of the redeemed vouchers, so the voucher can't be redeemed again after the token is burnt
+    pub redeemed: Mapping<Id, bool>,
+    pub _reserved: Option<()>,
+}
+
+impl<B, T> PSP34LazyMint for T
+where
+    B: balances::BalancesManager,
+    T: Storage<psp34::Data<B>> + Storage<ownable::Data> + Storage<Data>,
+    T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
+{
+    default fn redeem(&mut self, voucher: Voucher) -> Result<(), PSP34Error> {
+        let buyer = Self::env().caller();
+        let value = Self::env().transferred_value();
+        let creator = self.data::<ownable::Data>().owner;
+
+        let message_hash = self._voucher_message_hash(&voucher.id, &voucher.price);
+        if self._recover_signer(&voucher.signature, &message_hash) != Some(creator) {
+            return Err(PSP34Error::VoucherInvalidSignature)
+        }
+        if self.is_redeemed(voucher.id.clone()) {
+            return Err(PSP34Error::VoucherAlreadyRedeemed)
+        }
+        if value < voucher.price {
+            return Err(PSP34Error::VoucherInsufficientValue)
+        }
+
+        self._mint_to(buyer, voucher.id.clone())?;
+        self.data::<Data>().redeemed.insert(&voucher.id, &true);
+
+        if voucher.price > 0 {
+            Self::env()
+                .transfer(creator, voucher.price)
+                .map_err(|_| PSP34Error::VoucherPaymentFailed)?;
+        }
+        let refund = value - voucher.price;
+        if refund > 0 {
+            Self::env()
+                .transfer(buyer, refund)
+                .map_err(|_| PSP34Error::VoucherPaymentFailed)?;
+        }
+        Ok(())
+    }
+
+    default fn is_redeemed(&self, id: Id) -> bool {
+        self.data::<Data>().redeemed.get(&id).unwrap_or(false)
+    }
+}
+
+pub trait Internal {
+    /// Returns the hash of the voucher message which should be signed by the owner of the collection.
+    ///
+    /// The hash includes the address of the collection, so the voucher can't be redeemed in another one.
+    fn _voucher_message_hash(&self, id: &Id, price: &Balance) -> [u8; 32];
+
+    /// Returns the account which signed `message_hash` with ecdsa `signature`
+    fn _recover_signer(&self, signature: &[u8; 65], message_hash: &[u8; 32]) -> Option<AccountId>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _voucher_message_hash(&self, id: &Id, price: &Balance) -> [u8; 32] {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_encoded::<Blake2x256, _>(&(VOUCHER_DOMAIN, Self::env().account_id(), id, price), &mut output);
+        output
+    }
+
+    default fn _recover_signer(&self, signature: &[u8; 65], message_hash: &[u8; 32]) -> Option<AccountId> {
+        crate::signature::recover_ecdsa(message_hash, signature).ok()
+    }
+}
@@ -40,6 +40,7 @@ use ink_prelude::vec::Vec;
 use openbrush::{
     storage::{
         Mapping,
+        MultiMapping,
         TypeGuard,
     },
     traits::Storage,
@@ -47,10 +48,19 @@ use openbrush::{
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
+/// Attribute key of the collection id under which the name of the collection is stored.
+pub const COLLECTION_NAME_KEY: &[u8] = b"name";
+
+/// Attribute key of the collection id under which the symbol of the collection is stored.
+pub const COLLECTION_SYMBOL_KEY: &[u8] = b"symbol";
+
 #[derive(Default, Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
     pub attributes: Mapping<(Id, Vec<u8>), Vec<u8>, AttributesKey>,
+    pub attribute_names: MultiMapping<Id, Vec<u8>>,
+    pub base_uri: Vec<u8>,
+    pub uri_suffix: Vec<u8>,
     pub _reserved: Option<()>,
 }
 
@@ -64,6 +74,36 @@ impl<T: Storage<Data>> PSP34Metadata for T {
     default fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
         self.data().attributes.get(&(&id, &key))
     }
+
+    default fn get_attribute_count(&self, id: Id) -> u32 {
+        self.data().attribute_names.count(&id) as u32
+    }
+
+    default fn get_attribute_name(&self, id: Id, index: u32) -> Option<Vec<u8>> {
+        self.data().attribute_names.get_value(&id, &(index as u128))
+    }
+
+    default fn token_uri(&self, id: Id) -> Vec<u8> {
+        let base_uri = &self.data().base_uri;
+        if base_uri.is_empty() {
+            return Vec::new()
+        }
+
+        let mut uri = base_uri.clone();
+        uri.extend_from_slice(&encode_id(&id));
+        uri.extend_from_slice(&self.data().uri_suffix);
+        uri
+    }
+
+    default fn collection_name(&self) -> Vec<u8> {
+        self.get_attribute(self._collection_id(), Vec::from(COLLECTION_NAME_KEY))
+            .unwrap_or_default()
+    }
+
+    default fn collection_symbol(&self) -> Vec<u8> {
+        self.get_attribute(self._collection_id(), Vec::from(COLLECTION_SYMBOL_KEY))
+            .unwrap_or_default()
+    }
 }
 
 pub trait Internal {
@@ -71,6 +111,29 @@ pub trait Internal {
     fn _emit_attribute_set_event(&self, _id: Id, _key: Vec<u8>, _data: Vec<u8>);
 
     fn _set_attribute(&mut self, id: Id, key: Vec<u8>, value: Vec<u8>);
+
+    /// Removes the attribute of `id` for the given `key`.
+    fn _remove_attribute(&mut self, id: &Id, key: &Vec<u8>);
+
+    /// Removes all attributes of `id`. Should be called when the token is burned.
+    fn _remove_attributes(&mut self, id: &Id);
+
+    /// Sets the base URI of the tokens used by `token_uri`.
+    fn _set_base_uri(&mut self, base_uri: Vec<u8>);
+
+    /// Sets the suffix appended to the URI of the tokens by `token_uri`, for example `.json`.
+    fn _set_uri_suffix(&mut self, uri_suffix: Vec<u8>);
+
+    /// Returns the id under which the attributes of the whole collection are stored.
+    ///
+    /// It must return the same id as `PSP34::collection_id`, so override both of them together.
+    fn _collection_id(&self) -> Id;
+
+    /// Sets the name of the collection.
+    fn _set_collection_name(&mut self, name: Vec<u8>);
+
+    /// Sets the symbol of the collection.
+    fn _set_collection_symbol(&mut self, symbol: Vec<u8>);
 }
 
 impl<T> Internal for T
@@ -80,7 +143,76 @@ where
     default fn _emit_attribute_set_event(&self, _id: Id, _key: Vec<u8>, _data: Vec<u8>) {}
 
     default fn _set_attribute(&mut self, id: Id, key: Vec<u8>, value: Vec<u8>) {
+        if !self.data().attribute_names.contains_value(&id, &key) {
+            self.data().attribute_names.insert(&id, &key);
+        }
         self.data().attributes.insert(&(&id, &key), &value);
         self._emit_attribute_set_event(id, key, value);
     }
+
+    default fn _remove_attribute(&mut self, id: &Id, key: &Vec<u8>) {
+        self.data().attributes.remove(&(id, key));
+        self.data().attribute_names.remove_value(id, key);
+    }
+
+    default fn _remove_attributes(&mut self, id: &Id) {
+        while let Some(key) = self.data().attribute_names.get_value(id, &0) {
+            self._remove_attribute(id, &key);
+        }
+    }
+
+    default fn _set_base_uri(&mut self, base_uri: Vec<u8>) {
+        self.data().base_uri = base_uri;
+    }
+
+    default fn _set_uri_suffix(&mut self, uri_suffix: Vec<u8>) {
+        self.data().uri_suffix = uri_suffix;
+    }
+
+    default fn _collection_id(&self) -> Id {
+        let account_id = Self::env().account_id();
+        Id::Bytes(<_ as AsRef<[u8; 32]>>::as_ref(&account_id).to_vec())
+    }
+
+    default fn _set_collection_name(&mut self, name: Vec<u8>) {
+        self._set_attribute(self._collection_id(), Vec::from(COLLECTION_NAME_KEY), name);
+    }
+
+    default fn _set_collection_symbol(&mut self, symbol: Vec<u8>) {
+        self._set_attribute(self._collection_id(), Vec::from(COLLECTION_SYMBOL_KEY), symbol);
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes integer `id` as a decimal number and `Id::Bytes` as lowercase hex.
+fn encode_id(id: &Id) -> Vec<u8> {
+    match id {
+        Id::U8(value) => encode_decimal(*value as u128),
+        Id::U16(value) => encode_decimal(*value as u128),
+        Id::U32(value) => encode_decimal(*value as u128),
+        Id::U64(value) => encode_decimal(*value as u128),
+        Id::U128(value) => encode_decimal(*value),
+        Id::Bytes(value) => {
+            let mut encoded = Vec::with_capacity(value.len() * 2);
+            for byte in value.iter() {
+                encoded.push(HEX_DIGITS[(byte >> 4) as usize]);
+                encoded.push(HEX_DIGITS[(byte & 0x0f) as usize]);
+            }
+            encoded
+        }
+    }
+}
+
+fn encode_decimal(mut value: u128) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    loop {
+        encoded.push(b'0' + (value % 10) as u8);
+        value /= 10;
+        if value == 0 {
+            break
+        }
+    }
+    encoded.reverse();
+    encoded
 }
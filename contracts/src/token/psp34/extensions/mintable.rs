@@ -21,17 +21,22 @@
 
 pub use crate::{
     psp34,
-    psp34::balances,
+    psp34::{
+        balances,
+        extensions::mintable,
+    },
     traits::psp34::{
         extensions::mintable::*,
         *,
     },
 };
+pub use mintable::Internal as _;
 pub use psp34::{
     Internal as _,
     Transfer as _,
 };
 
+use ink_prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     OccupiedStorage,
@@ -47,4 +52,33 @@ where
     default fn mint(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
         self._mint_to(account, id)
     }
+
+    default fn mint_batch(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error> {
+        self._mint_batch_to(account, ids)
+    }
+}
+
+pub trait Internal {
+    /// Mints tokens with `ids` to `account` after checking that none of `ids` exists or is repeated.
+    fn _mint_batch_to(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error>;
+}
+
+impl<B, T> Internal for T
+where
+    B: balances::BalancesManager,
+    T: Storage<psp34::Data<B>>,
+    T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
+{
+    default fn _mint_batch_to(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error> {
+        for (i, id) in ids.iter().enumerate() {
+            if ids[..i].contains(id) || self._check_token_exists(id).is_ok() {
+                return Err(PSP34Error::TokenExists)
+            }
+        }
+
+        for id in ids.into_iter() {
+            self._mint_to(account, id)?;
+        }
+        Ok(())
+    }
 }
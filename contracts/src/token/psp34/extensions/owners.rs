@@ -0,0 +1,133 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::{
+        balances,
+        extensions::owners,
+    },
+    traits::psp34::{
+        extensions::owners::*,
+        *,
+    },
+};
+pub use owners::Internal as _;
+pub use psp34::{
+    Internal as _,
+    Transfer as _,
+};
+
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        OccupiedStorage,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub owners: Mapping<u128, AccountId>,
+    pub owner_indexes: Mapping<AccountId, u128>,
+    pub owner_count: u128,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP34Owners for T {
+    default fn owner_by_index(&self, index: u128) -> Option<AccountId> {
+        self.data().owners.get(&index)
+    }
+
+    default fn owner_count(&self) -> u128 {
+        self.data().owner_count
+    }
+}
+
+pub trait Internal {
+    /// Adds `to` to the owners if it received its first token
+    /// and removes `from` from the owners if it lost its last token.
+    ///
+    /// It must be called from `_after_token_transfer`.
+    fn _update_owners(&mut self, from: Option<&AccountId>, to: Option<&AccountId>);
+
+    /// Appends `owner` to the end of the owners list if it isn't in the list.
+    fn _add_owner(&mut self, owner: &AccountId);
+
+    /// Removes `owner` from the owners list if it is in the list.
+    /// The last owner is moved to the freed index, so the indexes stay dense.
+    fn _remove_owner(&mut self, owner: &AccountId);
+}
+
+impl<B, T> Internal for T
+where
+    B: balances::BalancesManager,
+    T: Storage<psp34::Data<B>> + Storage<Data>,
+    T: OccupiedStorage<{ psp34::STORAGE_KEY }, WithData = psp34::Data<B>>,
+{
+    default fn _update_owners(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) {
+        if let Some(from) = from {
+            if self.balance_of(from.clone()) == 0 {
+                self._remove_owner(from);
+            }
+        }
+        if let Some(to) = to {
+            if self.balance_of(to.clone()) > 0 {
+                self._add_owner(to);
+            }
+        }
+    }
+
+    default fn _add_owner(&mut self, owner: &AccountId) {
+        let data = self.data::<Data>();
+        if data.owner_indexes.get(owner).is_some() {
+            return
+        }
+
+        let index = data.owner_count;
+        data.owners.insert(&index, owner);
+        data.owner_indexes.insert(owner, &index);
+        data.owner_count = index + 1;
+    }
+
+    default fn _remove_owner(&mut self, owner: &AccountId) {
+        let data = self.data::<Data>();
+        let index = match data.owner_indexes.get(owner) {
+            Some(index) => index,
+            None => return,
+        };
+
+        // swap the removed owner with the last one and pop the last index
+        let last_index = data.owner_count - 1;
+        if index != last_index {
+            let last_owner = data.owners.get(&last_index).unwrap();
+            data.owners.insert(&index, &last_owner);
+            data.owner_indexes.insert(&last_owner, &index);
+        }
+        data.owners.remove(&last_index);
+        data.owner_indexes.remove(owner);
+        data.owner_count = last_index;
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::royalty,
+    traits::psp34::{
+        extensions::royalty::*,
+        *,
+    },
+};
+pub use psp34::{
+    Internal as _,
+    Transfer as _,
+};
+pub use royalty::Internal as _;
+
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+        ZERO_ADDRESS,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+/// The denominator of royalty fees, fees are in basis points.
+pub const FEE_DENOMINATOR: u16 = 10000;
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    // Receiver and fee of the royalty which is used if the token doesn't have own royalty
+    pub default_royalty: Option<(AccountId, u16)>,
+    pub token_royalty: Mapping<Id, (AccountId, u16)>,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP34Royalty for T {
+    default fn royalty_info(&self, id: Id, sale_price: Balance) -> (AccountId, Balance) {
+        let royalty = self.data().token_royalty.get(&id).or(self.data().default_royalty);
+
+        match royalty {
+            Some((receiver, fee)) => {
+                let fee = fee as Balance;
+                let denominator = FEE_DENOMINATOR as Balance;
+                // split the price to avoid overflow of multiplication
+                let amount = sale_price / denominator * fee + sale_price % denominator * fee / denominator;
+                (receiver, amount)
+            }
+            None => (ZERO_ADDRESS.into(), 0),
+        }
+    }
+}
+
+pub trait Internal {
+    /// Sets the royalty which is used for all tokens without own royalty.
+    ///
+    /// Returns `Custom` error if `fee` is more than 10000 basis points.
+    fn _set_default_royalty(&mut self, receiver: AccountId, fee: u16) -> Result<(), PSP34Error>;
+
+    fn _delete_default_royalty(&mut self);
+
+    /// Sets the royalty of the token `id`, which overrides the default royalty.
+    ///
+    /// Returns `Custom` error if `fee` is more than 10000 basis points.
+    fn _set_token_royalty(&mut self, id: Id, receiver: AccountId, fee: u16) -> Result<(), PSP34Error>;
+
+    /// Resets the royalty of the token `id` to the default royalty.
+    ///
+    /// It should be called when the token is burned.
+    fn _reset_token_royalty(&mut self, id: &Id);
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _set_default_royalty(&mut self, receiver: AccountId, fee: u16) -> Result<(), PSP34Error> {
+        if fee > FEE_DENOMINATOR {
+            return Err(PSP34Error::Custom(String::from("Royalty fee exceeds sale price")))
+        }
+        self.data().default_royalty = Some((receiver, fee));
+        Ok(())
+    }
+
+    default fn _delete_default_royalty(&mut self) {
+        self.data().default_royalty = None;
+    }
+
+    default fn _set_token_royalty(&mut self, id: Id, receiver: AccountId, fee: u16) -> Result<(), PSP34Error> {
+        if fee > FEE_DENOMINATOR {
+            return Err(PSP34Error::Custom(String::from("Royalty fee exceeds sale price")))
+        }
+        self.data().token_royalty.insert(&id, &(receiver, fee));
+        Ok(())
+    }
+
+    default fn _reset_token_royalty(&mut self, id: &Id) {
+        self.data().token_royalty.remove(id);
+    }
+}
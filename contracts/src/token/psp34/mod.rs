@@ -26,10 +26,20 @@ pub mod psp34;
 
 pub use psp34::*;
 pub mod extensions {
+    pub mod auto_id;
     pub mod burnable;
+    pub mod capped;
     pub mod enumerable;
+    #[cfg(feature = "ownable")]
+    pub mod lazy_mint;
     pub mod metadata;
     pub mod mintable;
+    pub mod owners;
+    pub mod royalty;
+}
+
+pub mod utils {
+    pub mod dutch_auction;
 }
 
 pub type Owner = AccountId;
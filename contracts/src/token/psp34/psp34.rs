@@ -41,6 +41,7 @@ use ink_prelude::vec::Vec;
 use openbrush::{
     storage::{
         Mapping,
+        MultiMapping,
         TypeGuard,
     },
     traits::{
@@ -63,6 +64,8 @@ where
     pub token_owner: Mapping<Id, Owner>,
     pub operator_approvals: Mapping<(Owner, Operator, Option<Id>), (), ApprovalsKey /* optimization */>,
     pub balances: B,
+    // Operators approved for each token, so the approvals can be cleared when the token is burned
+    pub token_operators: MultiMapping<Id, Operator>,
     pub _reserved: Option<()>,
 }
 
@@ -114,6 +117,10 @@ pub trait Internal {
     fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _id: Id);
     fn _emit_approval_event(&self, _from: AccountId, _to: AccountId, _id: Option<Id>, _approved: bool);
 
+    /// Emitted instead of `_emit_approval_event` when `operator` is approved (or disapproved) for all tokens of `owner`.
+    /// By default it falls back to `_emit_approval_event` with `None` id.
+    fn _emit_approval_for_all_event(&self, owner: AccountId, operator: AccountId, approved: bool);
+
     /// Approve the passed AccountId to transfer the specified token on behalf of the message's sender.
     fn _approve_for(&mut self, to: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error>;
 
@@ -135,8 +142,13 @@ pub trait Internal {
 
     fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error>;
 
+    /// Burns the token `id` of `from` and removes all approvals of `from` for it,
+    /// so they don't come back if the token is minted again.
     fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), PSP34Error>;
 
+    /// Removes the approvals of all operators which `owner` approved for the token `id`.
+    fn _remove_token_approvals(&mut self, owner: &AccountId, id: &Id);
+
     fn _allowance(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> bool;
 
     fn _check_token_exists(&self, id: &Id) -> Result<AccountId, PSP34Error>;
@@ -151,6 +163,10 @@ where
     default fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _id: Id) {}
     default fn _emit_approval_event(&self, _from: AccountId, _to: AccountId, _id: Option<Id>, _approved: bool) {}
 
+    default fn _emit_approval_for_all_event(&self, owner: AccountId, operator: AccountId, approved: bool) {
+        self._emit_approval_event(owner, operator, None, approved)
+    }
+
     default fn _approve_for(&mut self, to: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
         let mut caller = Self::env().caller();
 
@@ -174,7 +190,19 @@ where
         } else {
             self.data().operator_approvals.remove(&(&caller, &to, &id.as_ref()));
         }
-        self._emit_approval_event(caller, to, id, approved);
+        if let Some(id) = &id {
+            let is_tracked = self.data().token_operators.contains_value(id, &to);
+            if approved && !is_tracked {
+                self.data().token_operators.insert(id, &to);
+            } else if !approved && is_tracked {
+                self.data().token_operators.remove_value(id, &to);
+            }
+        }
+        if id.is_some() {
+            self._emit_approval_event(caller, to, id, approved);
+        } else {
+            self._emit_approval_for_all_event(caller, to, approved);
+        }
 
         Ok(())
     }
@@ -268,11 +296,19 @@ where
 
         self.data().token_owner.remove(&id);
         self.data().balances.decrease_balance(&from, &id, true);
+        self._remove_token_approvals(&from, &id);
         self._after_token_transfer(Some(&from), None, &id)?;
         self._emit_transfer_event(Some(from), None, id);
         Ok(())
     }
 
+    default fn _remove_token_approvals(&mut self, owner: &AccountId, id: &Id) {
+        while let Some(operator) = self.data().token_operators.get_value(id, &0) {
+            self.data().operator_approvals.remove(&(owner, &operator, &Some(id)));
+            self.data().token_operators.remove_value(id, &operator);
+        }
+    }
+
     default fn _allowance(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> bool {
         self.data().operator_approvals.get(&(owner, operator, &None)).is_some()
             || id != &None && self.data().operator_approvals.get(&(owner, operator, id)).is_some()
@@ -0,0 +1,194 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34::utils::dutch_auction,
+    traits::psp34::{
+        utils::dutch_auction::*,
+        *,
+    },
+};
+pub use dutch_auction::Internal as _;
+
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+    Timestamp,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub token: AccountId,
+    pub seller: AccountId,
+    pub start_price: Balance,
+    pub end_price: Balance,
+    pub start_time: Timestamp,
+    pub duration: Timestamp,
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> PSP34DutchAuction for T {
+    default fn token(&self) -> AccountId {
+        self.data().token
+    }
+
+    default fn seller(&self) -> AccountId {
+        self.data().seller
+    }
+
+    default fn start_price(&self) -> Balance {
+        self.data().start_price
+    }
+
+    default fn end_price(&self) -> Balance {
+        self.data().end_price
+    }
+
+    default fn start_time(&self) -> Timestamp {
+        self.data().start_time
+    }
+
+    default fn duration(&self) -> Timestamp {
+        self.data().duration
+    }
+
+    default fn current_price(&self) -> Balance {
+        self._price_at(Self::env().block_timestamp())
+    }
+
+    default fn buy(&mut self, id: Id) -> Result<(), PSP34DutchAuctionError> {
+        let buyer = Self::env().caller();
+        let value = Self::env().transferred_value();
+
+        if Self::env().block_timestamp() < self.data().start_time {
+            return Err(PSP34DutchAuctionError::AuctionNotStarted)
+        }
+        let price = self.current_price();
+        if value < price {
+            return Err(PSP34DutchAuctionError::InsufficientValue)
+        }
+        let refund = value - price;
+
+        self._deliver_token(buyer, id.clone())?;
+        self._emit_token_purchased_event(buyer, id, price);
+
+        self._forward_funds(price)?;
+        if refund > 0 {
+            Self::env()
+                .transfer(buyer, refund)
+                .map_err(|_| PSP34DutchAuctionError::TransferFailed)?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_token_purchased_event(&self, _buyer: AccountId, _id: Id, _price: Balance);
+
+    /// Initializes the auction of the tokens of `token` collection.
+    ///
+    /// The price decreases linearly from `start_price` at `start_time` to `end_price`
+    /// `duration` milliseconds later. The funds of the sales are forwarded to `seller`.
+    ///
+    /// Returns `InvalidPriceRange` error if `end_price` is above `start_price`.
+    fn _init(
+        &mut self,
+        token: AccountId,
+        seller: AccountId,
+        start_price: Balance,
+        end_price: Balance,
+        start_time: Timestamp,
+        duration: Timestamp,
+    ) -> Result<(), PSP34DutchAuctionError>;
+
+    /// Returns the price of the token at `timestamp`
+    fn _price_at(&self, timestamp: Timestamp) -> Balance;
+
+    /// Transfers the token `id` owned by the auction to `buyer`.
+    ///
+    /// By default it calls [`PSP34::transfer`] of the collection, so the auction must own the token.
+    /// If the auction is a part of the collection itself, override it to move the token directly.
+    fn _deliver_token(&mut self, buyer: AccountId, id: Id) -> Result<(), PSP34DutchAuctionError>;
+
+    /// Forwards `value` of the sale to the seller
+    fn _forward_funds(&mut self, value: Balance) -> Result<(), PSP34DutchAuctionError>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _emit_token_purchased_event(&self, _buyer: AccountId, _id: Id, _price: Balance) {}
+
+    default fn _init(
+        &mut self,
+        token: AccountId,
+        seller: AccountId,
+        start_price: Balance,
+        end_price: Balance,
+        start_time: Timestamp,
+        duration: Timestamp,
+    ) -> Result<(), PSP34DutchAuctionError> {
+        if end_price > start_price {
+            return Err(PSP34DutchAuctionError::InvalidPriceRange)
+        }
+        let data = self.data();
+        data.token = token;
+        data.seller = seller;
+        data.start_price = start_price;
+        data.end_price = end_price;
+        data.start_time = start_time;
+        data.duration = duration;
+        Ok(())
+    }
+
+    default fn _price_at(&self, timestamp: Timestamp) -> Balance {
+        let data = self.data();
+        if timestamp <= data.start_time {
+            return data.start_price
+        }
+        let elapsed = timestamp - data.start_time;
+        if elapsed >= data.duration {
+            return data.end_price
+        }
+
+        let range = data.start_price - data.end_price;
+        let (elapsed, duration) = (elapsed as Balance, data.duration as Balance);
+        // split the range to avoid the overflow of `range * elapsed`
+        let decrease = range / duration * elapsed + range % duration * elapsed / duration;
+        data.start_price - decrease
+    }
+
+    default fn _deliver_token(&mut self, buyer: AccountId, id: Id) -> Result<(), PSP34DutchAuctionError> {
+        let token = self.data().token;
+        PSP34Ref::transfer(&token, buyer, id, Vec::<u8>::new())?;
+        Ok(())
+    }
+
+    default fn _forward_funds(&mut self, value: Balance) -> Result<(), PSP34DutchAuctionError> {
+        Self::env()
+            .transfer(self.data().seller, value)
+            .map_err(|_| PSP34DutchAuctionError::TransferFailed)
+    }
+}
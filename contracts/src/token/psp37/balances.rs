@@ -23,6 +23,7 @@ use crate::psp37::{
     Id,
     PSP37Error,
 };
+use ink_prelude::vec::Vec;
 use ink_storage::traits::{
     SpreadAllocate,
     SpreadLayout,
@@ -45,6 +46,12 @@ pub trait BalancesManager: SpreadLayout + SpreadAllocate {
     fn total_supply(&self, id: &Option<&Id>) -> Balance;
     fn increase_balance(&mut self, owner: &AccountId, id: &Id, amount: &Balance, mint: bool) -> Result<(), PSP37Error>;
     fn decrease_balance(&mut self, owner: &AccountId, id: &Id, amount: &Balance, burn: bool) -> Result<(), PSP37Error>;
+
+    /// Returns an error if `ids_amounts` can't be minted. It is called before any balance is increased,
+    /// so the minting of the batch is rejected as a whole.
+    fn ensure_can_mint(&self, _ids_amounts: &Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+        Ok(())
+    }
 }
 
 #[derive(Default, Debug)]
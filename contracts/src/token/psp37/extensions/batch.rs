@@ -106,9 +106,12 @@ where
             }
         }
 
+        // the whole batch is validated first, so it is never applied partially
+        self._ensure_enough_balance(&from, &ids_amounts)?;
+
         self._before_token_transfer(Some(&from), Some(&to), &ids_amounts)?;
 
-        for (id, value) in &ids_amounts {
+        for (id, value) in ids_amounts.iter().filter(|(_, value)| *value > 0) {
             self._decrease_allowance(&from, &operator, id, value.clone())?;
 
             self.data().balances.decrease_balance(&from, id, value, false)?;
@@ -116,7 +119,7 @@ where
 
         self._do_safe_transfer_check(&operator, &from, &to, &ids_amounts, &data)?;
 
-        for (id, value) in &ids_amounts {
+        for (id, value) in ids_amounts.iter().filter(|(_, value)| *value > 0) {
             self.data().balances.increase_balance(&to, id, value, false)?;
         }
 
@@ -0,0 +1,147 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp37,
+    psp37::{
+        balances,
+        extensions::capped,
+    },
+    traits::psp37::{
+        extensions::capped::*,
+        *,
+    },
+};
+pub use balances::BalancesManager as _;
+pub use capped::Internal as _;
+pub use psp37::{
+    Internal as _,
+    Transfer as _,
+};
+
+use ink_prelude::vec::Vec;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Balances);
+
+/// Balances of the token which reject the minting above the cap of the token.
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Balances {
+    pub balances: balances::Balances,
+    pub caps: Mapping<Id, Balance>,
+    pub _reserved: Option<()>,
+}
+
+impl Balances {
+    /// Returns `true` if minting `amount` of token with `id` exceeds its cap.
+    pub fn is_cap_exceeded(&self, id: &Id, amount: &Balance) -> bool {
+        let cap = self.caps.get(id).unwrap_or_default();
+        if cap == 0 {
+            return false
+        }
+        match self.total_supply(&Some(id)).checked_add(*amount) {
+            Some(supply) => supply > cap,
+            None => true,
+        }
+    }
+}
+
+impl balances::BalancesManager for Balances {
+    #[inline(always)]
+    fn balance_of(&self, owner: &AccountId, id: &Option<&Id>) -> Balance {
+        self.balances.balance_of(owner, id)
+    }
+
+    #[inline(always)]
+    fn total_supply(&self, id: &Option<&Id>) -> Balance {
+        self.balances.total_supply(id)
+    }
+
+    fn increase_balance(&mut self, owner: &AccountId, id: &Id, amount: &Balance, mint: bool) -> Result<(), PSP37Error> {
+        self.balances.increase_balance(owner, id, amount, mint)
+    }
+
+    fn decrease_balance(&mut self, owner: &AccountId, id: &Id, amount: &Balance, burn: bool) -> Result<(), PSP37Error> {
+        self.balances.decrease_balance(owner, id, amount, burn)
+    }
+
+    fn ensure_can_mint(&self, ids_amounts: &Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+        for (id, _) in ids_amounts.iter() {
+            // The same id can be minted several times within one batch
+            let total: Balance = ids_amounts
+                .iter()
+                .filter(|(other, _)| other == id)
+                .fold(0, |total, (_, amount)| total.saturating_add(*amount));
+            if self.is_cap_exceeded(id, &total) {
+                return Err(PSP37Error::CapExceeded)
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> PSP37Capped for T
+where
+    T: Storage<psp37::Data<Balances>>,
+    T: OccupiedStorage<{ psp37::STORAGE_KEY }, WithData = psp37::Data<Balances>>,
+{
+    default fn cap(&self, id: Id) -> Balance {
+        self.data().balances.caps.get(&id).unwrap_or_default()
+    }
+}
+
+pub trait Internal {
+    /// Sets the cap of token with `id`. Zero removes the cap.
+    ///
+    /// Returns `Custom` error if `cap` is below the current supply of the token.
+    fn _set_cap(&mut self, id: Id, cap: Balance) -> Result<(), PSP37Error>;
+
+    /// Returns `true` if minting `amount` of token with `id` exceeds its cap.
+    fn _is_cap_exceeded(&self, id: &Id, amount: &Balance) -> bool;
+}
+
+impl<T> Internal for T
+where
+    T: Storage<psp37::Data<Balances>>,
+    T: OccupiedStorage<{ psp37::STORAGE_KEY }, WithData = psp37::Data<Balances>>,
+{
+    default fn _set_cap(&mut self, id: Id, cap: Balance) -> Result<(), PSP37Error> {
+        if cap != 0 && cap < self.data().balances.total_supply(&Some(&id)) {
+            return Err(PSP37Error::Custom(String::from("Cap is below the current supply")))
+        }
+        self.data().balances.caps.insert(&id, &cap);
+        Ok(())
+    }
+
+    default fn _is_cap_exceeded(&self, id: &Id, amount: &Balance) -> bool {
+        self.data().balances.is_cap_exceeded(id, amount)
+    }
+}
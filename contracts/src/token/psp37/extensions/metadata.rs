@@ -51,6 +51,7 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
     pub attributes: Mapping<(Id, Vec<u8>), Vec<u8>, AttributesKey>,
+    pub uri: Vec<u8>,
     pub _reserved: Option<()>,
 }
 
@@ -64,6 +65,24 @@ impl<T: Storage<Data>> PSP37Metadata for T {
     default fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
         self.data().attributes.get(&(&id, &key))
     }
+
+    default fn uri(&self, id: Id) -> Vec<u8> {
+        let base = &self.data().uri;
+        let encoded_id = encode_id(&id);
+
+        let mut uri = Vec::with_capacity(base.len());
+        let mut i = 0;
+        while i < base.len() {
+            if base[i..].starts_with(ID_PLACEHOLDER) {
+                uri.extend_from_slice(&encoded_id);
+                i += ID_PLACEHOLDER.len();
+            } else {
+                uri.push(base[i]);
+                i += 1;
+            }
+        }
+        uri
+    }
 }
 
 pub trait Internal {
@@ -72,6 +91,12 @@ pub trait Internal {
     fn _set_attribute(&mut self, id: &Id, key: &Vec<u8>, data: &Vec<u8>) -> Result<(), PSP37Error>;
 
     fn _get_attribute(&self, id: &Id, key: &Vec<u8>) -> Option<Vec<u8>>;
+
+    /// User must override this method in their contract.
+    fn _emit_uri_event(&self, _uri: &Vec<u8>);
+
+    /// Sets the base URI of the tokens, it may contain the `{id}` placeholder.
+    fn _set_uri(&mut self, uri: &Vec<u8>);
 }
 
 impl<T: Storage<Data>> Internal for T {
@@ -86,4 +111,40 @@ impl<T: Storage<Data>> Internal for T {
     default fn _get_attribute(&self, id: &Id, key: &Vec<u8>) -> Option<Vec<u8>> {
         self.data().attributes.get(&(&id, &key))
     }
+
+    default fn _emit_uri_event(&self, _uri: &Vec<u8>) {}
+
+    default fn _set_uri(&mut self, uri: &Vec<u8>) {
+        self.data().uri = uri.clone();
+        self._emit_uri_event(uri);
+    }
+}
+
+const ID_PLACEHOLDER: &[u8] = b"{id}";
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes the `id` to lowercase hex, integers are left-padded with zeros to 32 bytes.
+fn encode_id(id: &Id) -> Vec<u8> {
+    let bytes = match id {
+        Id::U8(value) => padded(&value.to_be_bytes()),
+        Id::U16(value) => padded(&value.to_be_bytes()),
+        Id::U32(value) => padded(&value.to_be_bytes()),
+        Id::U64(value) => padded(&value.to_be_bytes()),
+        Id::U128(value) => padded(&value.to_be_bytes()),
+        Id::Bytes(value) => value.clone(),
+    };
+
+    let mut encoded = Vec::with_capacity(bytes.len() * 2);
+    for byte in bytes.iter() {
+        encoded.push(HEX_DIGITS[(byte >> 4) as usize]);
+        encoded.push(HEX_DIGITS[(byte & 0x0f) as usize]);
+    }
+    encoded
+}
+
+fn padded(bytes: &[u8]) -> Vec<u8> {
+    let mut result = ink_prelude::vec![0u8; 32 - bytes.len()];
+    result.extend_from_slice(bytes);
+    result
 }
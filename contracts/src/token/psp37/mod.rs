@@ -28,7 +28,9 @@ pub mod balances;
 pub mod extensions {
     pub mod batch;
     pub mod burnable;
+    pub mod capped;
     pub mod enumerable;
     pub mod metadata;
     pub mod mintable;
+    pub mod supply;
 }
@@ -82,6 +82,13 @@ where
         self.data().balances.balance_of(&owner, &id.as_ref())
     }
 
+    default fn balance_of_batch(&self, accounts_and_ids: Vec<(AccountId, Id)>) -> Vec<Balance> {
+        accounts_and_ids
+            .iter()
+            .map(|(owner, id)| self.data().balances.balance_of(owner, &Some(id)))
+            .collect()
+    }
+
     default fn total_supply(&self, id: Option<Id>) -> Balance {
         self.data().balances.total_supply(&id.as_ref())
     }
@@ -123,6 +130,9 @@ pub trait Internal {
         _ids_amounts: Vec<(Id, Balance)>,
     );
     fn _emit_approval_event(&self, _owner: AccountId, _operator: AccountId, _id: Option<Id>, value: Balance);
+    /// Emitted after the `Approval` event when the approval of `operator` for all tokens of `owner` changes.
+    /// Override it to emit an `ApprovalForAll` event, so indexers don't need to decode `None` id of `Approval`.
+    fn _emit_approval_for_all_event(&self, _owner: AccountId, _operator: AccountId, _approved: bool);
 
     /// Creates `amount` tokens of token type `id` to `to`.
     ///
@@ -153,10 +163,28 @@ pub trait Internal {
         data: Vec<u8>,
     ) -> Result<(), PSP37Error>;
 
+    /// Returns the allowance of `operator` for `id` token of `account`.
+    ///
+    /// The approval for all tokens takes precedence over the allowance for `id`: while it exists,
+    /// `Balance::MAX` is returned for any `id`. The allowance for `id` is kept as is
+    /// and is returned again after the approval for all tokens is revoked.
     fn _get_allowance(&self, account: &AccountId, operator: &AccountId, id: &Option<&Id>) -> Balance;
 
+    /// Returns `true` if `operator` is approved for all tokens of `account`.
+    fn _is_approved_for_all(&self, account: &AccountId, operator: &AccountId) -> bool;
+
+    /// Sets the allowance of `operator` for `id` token of the caller to `value`.
+    /// If `id` is `None`, approves (or disapproves if `value` is zero) `operator` for all tokens of the caller.
+    /// In that case the `Approval` event has the resulting allowance, `Balance::MAX` or zero,
+    /// and is followed by the approval for all event.
     fn _approve_for(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), PSP37Error>;
 
+    /// Consumes `value` of the allowance of `operator` for `id` token of `owner`.
+    /// Unlimited allowances are not decreased.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `NotAllowed` error if the allowance is lower than `value`.
     fn _decrease_allowance(
         &mut self,
         owner: &AccountId,
@@ -165,6 +193,13 @@ pub trait Internal {
         value: Balance,
     ) -> Result<(), PSP37Error>;
 
+    /// Returns `InsufficientTokenBalance` error with the id and its total amount
+    /// if `from` doesn't hold the total amount of any id in `ids_amounts`.
+    /// The amounts of the repeated ids are summed up, zero amounts are skipped.
+    ///
+    /// It doesn't modify the storage, so it is called before the transfer applies any entry.
+    fn _ensure_enough_balance(&self, from: &AccountId, ids_amounts: &Vec<(Id, Balance)>) -> Result<(), PSP37Error>;
+
     fn _transfer_token(
         &mut self,
         from: &AccountId,
@@ -206,6 +241,7 @@ where
     ) {
     }
     default fn _emit_approval_event(&self, _owner: AccountId, _operator: AccountId, _id: Option<Id>, _value: Balance) {}
+    default fn _emit_approval_for_all_event(&self, _owner: AccountId, _operator: AccountId, _approved: bool) {}
 
     default fn _mint_to(&mut self, to: AccountId, mut ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
         if to.is_zero() {
@@ -215,6 +251,7 @@ where
             return Ok(())
         }
 
+        self.data().balances.ensure_can_mint(&ids_amounts)?;
         self._before_token_transfer(None, Some(&to), &ids_amounts)?;
 
         for (id, amount) in &ids_amounts {
@@ -275,6 +312,8 @@ where
             return Err(PSP37Error::NotAllowed)
         }
 
+        self._ensure_enough_balance(&from, &ids_amounts)?;
+
         self._before_token_transfer(Some(&from), Some(&to), &ids_amounts)?;
         self._decrease_allowance(&from, &operator, &id, value)?;
         self._transfer_token(&from, &to, id.clone(), value, &data)?;
@@ -284,13 +323,20 @@ where
     }
 
     default fn _get_allowance(&self, owner: &AccountId, operator: &AccountId, id: &Option<&Id>) -> Balance {
-        return match self.data().operator_approvals.get(&(owner, operator, &None)) {
-            None => self.data().operator_approvals.get(&(owner, operator, id)).unwrap_or(0),
-            _ => Balance::MAX,
+        if self._is_approved_for_all(owner, operator) {
+            return Balance::MAX
+        }
+        match id {
+            Some(_) => self.data().operator_approvals.get(&(owner, operator, id)).unwrap_or(0),
+            None => 0,
         }
     }
 
-    fn _approve_for(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), PSP37Error> {
+    default fn _is_approved_for_all(&self, owner: &AccountId, operator: &AccountId) -> bool {
+        self.data().operator_approvals.get(&(owner, operator, &None)).is_some()
+    }
+
+    default fn _approve_for(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), PSP37Error> {
         let caller = Self::env().caller();
 
         if caller == operator {
@@ -305,22 +351,26 @@ where
                     .operator_approvals
                     .insert(&(&caller, &operator, &Some(id)), &value);
             }
+            self._emit_approval_event(caller, operator, Some(id.clone()), value);
         } else {
-            if value == 0 {
-                self.data().operator_approvals.remove(&(&caller, &operator, &None));
-            } else {
+            let approved = value != 0;
+            if approved {
                 self.data()
                     .operator_approvals
                     .insert(&(&caller, &operator, &None), &Balance::MAX);
+            } else {
+                self.data().operator_approvals.remove(&(&caller, &operator, &None));
             }
+            // the approval for all tokens is unlimited, so the event has the resulting allowance
+            let allowance = if approved { Balance::MAX } else { 0 };
+            self._emit_approval_event(caller, operator, None, allowance);
+            self._emit_approval_for_all_event(caller, operator, approved);
         }
 
-        self._emit_approval_event(caller, operator, id, value);
-
         Ok(())
     }
 
-    fn _decrease_allowance(
+    default fn _decrease_allowance(
         &mut self,
         owner: &AccountId,
         operator: &AccountId,
@@ -338,7 +388,7 @@ where
         }
 
         if initial_allowance < value {
-            return Err(PSP37Error::InsufficientBalance)
+            return Err(PSP37Error::NotAllowed)
         }
 
         self.data()
@@ -348,7 +398,35 @@ where
         Ok(())
     }
 
-    fn _transfer_token(
+    default fn _ensure_enough_balance(
+        &self,
+        from: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        let mut totals: Vec<(&Id, Balance)> = Vec::new();
+        for (id, amount) in ids_amounts.iter().filter(|(_, amount)| *amount > 0) {
+            let total = match totals.iter_mut().find(|(total_id, _)| *total_id == id) {
+                Some(entry) => {
+                    // the total which overflows can't be held by any account
+                    entry.1 = entry
+                        .1
+                        .checked_add(*amount)
+                        .ok_or_else(|| PSP37Error::InsufficientTokenBalance(id.clone(), Balance::MAX))?;
+                    entry.1
+                }
+                None => {
+                    totals.push((id, *amount));
+                    *amount
+                }
+            };
+            if self.data().balances.balance_of(from, &Some(id)) < total {
+                return Err(PSP37Error::InsufficientTokenBalance(id.clone(), total))
+            }
+        }
+        Ok(())
+    }
+
+    default fn _transfer_token(
         &mut self,
         from: &AccountId,
         to: &AccountId,
@@ -407,6 +485,8 @@ where
     }
 }
 
+/// Hooks which are called once per mint, burn or transfer with the whole list of ids and amounts,
+/// so batch operations don't pay for a hook call per id.
 pub trait Transfer {
     fn _before_token_transfer(
         &mut self,
@@ -20,6 +20,7 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub use crate::traits::errors::AccessControlError;
+use ink_prelude::vec::Vec;
 use openbrush::traits::AccountId;
 
 pub type RoleType = u32;
@@ -40,6 +41,16 @@ pub trait AccessControl {
     #[ink(message)]
     fn has_role(&self, role: RoleType, address: AccountId) -> bool;
 
+    /// Returns `true` if `account` has been granted all of `roles`.
+    /// Returns `true` if `roles` is empty.
+    #[ink(message)]
+    fn has_all_roles(&self, account: AccountId, roles: Vec<RoleType>) -> bool;
+
+    /// Returns `true` if `account` has been granted at least one of `roles`.
+    /// Returns `false` if `roles` is empty.
+    #[ink(message)]
+    fn has_any_role(&self, account: AccountId, roles: Vec<RoleType>) -> bool;
+
     /// Returns the admin role that controls `role`. See `grant_role` and `revoke_role`.
     #[ink(message)]
     fn get_role_admin(&self, role: RoleType) -> RoleType;
@@ -78,4 +89,28 @@ pub trait AccessControl {
     /// Returns with `MissingRole` error if `account` doesn't have `role`.
     #[ink(message)]
     fn renounce_role(&mut self, role: RoleType, account: AccountId) -> Result<(), AccessControlError>;
+
+    /// Grants each role of `grants` to the paired account.
+    /// The batch is applied all-or-nothing: if any grant fails, none of them is applied.
+    ///
+    /// On success a `RoleGranted` event is emitted for each grant.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller can't grant any of the roles.
+    /// Returns with `RoleRedundant` error if any account has the role or the grant is duplicated.
+    #[ink(message)]
+    fn grant_role_batch(&mut self, grants: Vec<(RoleType, AccountId)>) -> Result<(), AccessControlError>;
+
+    /// Revokes each role of `revokes` from the paired account.
+    /// The batch is applied all-or-nothing: if any revoke fails, none of them is applied.
+    ///
+    /// On success a `RoleRevoked` event is emitted for each revoke.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller can't revoke any of the roles,
+    /// or if any account doesn't have the role or the revoke is duplicated.
+    #[ink(message)]
+    fn revoke_role_batch(&mut self, revokes: Vec<(RoleType, AccountId)>) -> Result<(), AccessControlError>;
 }
@@ -0,0 +1,96 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`AccessControl`] that transfers the default admin role with a delay.
+pub use crate::traits::errors::AccessControlError;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type AccessControlDefaultAdminRulesRef = dyn AccessControlDefaultAdminRules;
+
+/// The default admin nominates the new admin with `begin_default_admin_transfer`, and the new admin
+/// accepts the role with `accept_default_admin_transfer` after the delay. The role is revoked from
+/// the admin which began the transfer. The delay can be changed, and the change takes effect after the current delay.
+#[openbrush::trait_definition]
+pub trait AccessControlDefaultAdminRules {
+    /// Returns the delay between the beginning and the acceptance of the default admin transfer.
+    #[ink(message)]
+    fn default_admin_delay(&self) -> Timestamp;
+
+    /// Returns the new delay and the timestamp when it takes effect,
+    /// or zeros if there is no scheduled change of the delay.
+    #[ink(message)]
+    fn pending_default_admin_delay(&self) -> (Timestamp, Timestamp);
+
+    /// Returns the pending admin and the timestamp after which it can accept the role,
+    /// or zeros if there is no pending transfer.
+    #[ink(message)]
+    fn pending_default_admin(&self) -> (AccountId, Timestamp);
+
+    /// Nominates `new_admin` as the pending default admin. It replaces the previous pending admin.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller doesn't have the default admin role.
+    #[ink(message)]
+    fn begin_default_admin_transfer(&mut self, new_admin: AccountId) -> Result<(), AccessControlError>;
+
+    /// Removes the pending default admin.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller doesn't have the default admin role.
+    ///
+    /// Returns with `NoPendingDefaultAdminTransfer` error if there is no pending transfer.
+    #[ink(message)]
+    fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError>;
+
+    /// The pending default admin accepts the role.
+    ///
+    /// On success `RoleGranted` and `RoleRevoked` events are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `InvalidCaller` error if caller is not the pending default admin.
+    ///
+    /// Returns with `DefaultAdminDelayNotPassed` error if the delay is not passed yet.
+    #[ink(message)]
+    fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError>;
+
+    /// Schedules the change of the delay to `new_delay`. It takes effect after the current delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller doesn't have the default admin role.
+    #[ink(message)]
+    fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError>;
+
+    /// Cancels the scheduled change of the delay which has not taken effect yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller doesn't have the default admin role.
+    #[ink(message)]
+    fn rollback_default_admin_delay(&mut self) -> Result<(), AccessControlError>;
+}
@@ -34,6 +34,9 @@ pub type DiamondRef = dyn Diamond;
 
 pub type Selector = [u8; 4];
 
+/// Identifier of an interface in terms of ERC-165
+pub type InterfaceId = [u8; 4];
+
 /// Struct which we use to initialize/update/remove a facet in the diamond
 #[derive(Default, Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -42,6 +45,34 @@ pub struct FacetCut {
     pub hash: Hash,
     /// The selector bytes that identify the function that should be called.
     pub selectors: Vec<Selector>,
+    /// The interfaces which the facet implements. They are registered when the facet is added
+    /// or updated, and deregistered when the facet is removed.
+    pub interfaces: Vec<InterfaceId>,
+}
+
+/// Kind of the change applied to a facet during the diamond cut,
+/// or the change requested by [`FacetCutWithAction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum FacetCutAction {
+    /// The facet was added to the diamond
+    Add,
+    /// The selectors of the already registered facet were updated
+    Replace,
+    /// The facet was removed from the diamond
+    Remove,
+}
+
+/// Facet cut with the explicit action, in the ERC-2535 way
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct FacetCutWithAction {
+    /// The action which should be applied to `selectors`.
+    pub action: FacetCutAction,
+    /// The `hash` of the code that should be executed. It is ignored by the `Remove` action.
+    pub hash: Hash,
+    /// The selector bytes that identify the functions affected by the action.
+    pub selectors: Vec<Selector>,
 }
 
 /// Struct which we use to initialize the diamond contract
@@ -54,6 +85,11 @@ pub struct InitCall {
     pub selector: Selector,
     /// The SCALE encoded parameters that are passed to the called function.
     pub input: Vec<u8>,
+    /// Executes the call even if the facet with `hash` was already initialized.
+    pub force: bool,
+    /// Allows `hash` to be the code which is not registered in the diamond.
+    /// Only set it if you deliberately initialize the diamond with external code.
+    pub external: bool,
 }
 
 /// Trait to be implemented in the contract which holds the diamond storage
@@ -62,12 +98,96 @@ pub trait Diamond {
     /// This function is used to add, replace and remove facets from the diamond
     ///
     /// `cuts` vector of facet cuts, each cut contains the code hash of the facet
-    /// as well as the selectors of functions.
+    /// as well as the selectors of functions and the interfaces which the facet implements.
+    /// The interfaces are registered for the facet unless the cut removes it
     /// If `cuts` is empty, we will remove this facet from diamond
     /// If `cuts` contains a selector which already exists for a different facet we will return an error (user should remove this facet first)
     /// If `cuts` does not contain some selectors which are already registered for this facet, those selectors will be removed from diamond
+    /// The cut is applied all-or-nothing: if any of `cuts` fails, none of them is applied
     /// `init` optional struct which identifies a call to be executed, this struct contains the code hash
     /// of the executed contract, selector of the executed function and input data to be passed to the called
+    /// Each facet is initialized once: if the facet of `init` was already initialized (even if it was removed
+    /// and added again since then), `FacetAlreadyInitialized` error is returned unless `init.force` is set
+    /// The hash of `init` must be the code hash of the diamond itself or the facet registered after `cuts`,
+    /// otherwise `InitHashNotRegistered` error is returned unless `init.external` is set
     #[ink(message)]
     fn diamond_cut(&mut self, cuts: Vec<FacetCut>, init: Option<InitCall>) -> Result<(), DiamondError>;
+
+    /// This function is used to add, replace and remove selectors of facets with the explicit action of each cut
+    ///
+    /// `Add` registers `selectors` for the facet, each selector must not be registered yet, otherwise `ReplaceExisting` error is returned
+    /// `Replace` moves `selectors` from their current facets to the facet, each selector must be registered
+    /// for another facet, otherwise `FunctionDoesNotExist` or `ReplaceSameFacet` error is returned
+    /// `Remove` removes `selectors` from the diamond, the hash of the cut is ignored,
+    /// each selector must be registered, otherwise `FunctionDoesNotExist` error is returned
+    /// Facets which lose all their selectors are removed from the diamond
+    /// The cuts are applied in order and all-or-nothing, `init` is executed the same way as in `diamond_cut`
+    #[ink(message)]
+    fn diamond_cut_with_actions(
+        &mut self,
+        cuts: Vec<FacetCutWithAction>,
+        init: Option<InitCall>,
+    ) -> Result<(), DiamondError>;
+
+    /// This function works like `diamond_cut`, but a selector already registered for another facet
+    /// is moved to the facet of the cut instead of returning `ReplaceExisting` error
+    /// The selector is pruned from the previous facet, and the facet is removed if it has no selectors left
+    /// The cuts are applied in order and all-or-nothing, `init` is executed the same way as in `diamond_cut`
+    #[ink(message)]
+    fn diamond_cut_override(&mut self, cuts: Vec<FacetCut>, init: Option<InitCall>) -> Result<(), DiamondError>;
+
+    /// Moves all selectors of the facet with `old_hash` to the facet with `new_hash` and removes the old facet
+    /// The interfaces implemented by the old facet are registered for the new one
+    /// It is a shortcut for upgrading the facet to the new version of its code in one atomic diamond cut
+    /// Returns `FunctionDoesNotExist` error if the old facet is not registered
+    /// and `ReplaceSameFacet` error if both hashes are equal
+    #[ink(message)]
+    fn replace_facet(&mut self, old_hash: Hash, new_hash: Hash) -> Result<(), DiamondError>;
+
+    /// Applies the last diamond cut the same way as `diamond_cut` does and freezes the diamond.
+    /// Any subsequent `diamond_cut` fails with `DiamondFrozen` error. The freeze is irreversible.
+    #[ink(message)]
+    fn diamond_cut_and_freeze(&mut self, cuts: Vec<FacetCut>, init: Option<InitCall>) -> Result<(), DiamondError>;
+
+    /// Returns `true` if one of the facets of the diamond implements the interface
+    /// identified by `interface_id`.
+    #[ink(message)]
+    fn supports_interface(&self, interface_id: InterfaceId) -> bool;
+
+    /// Returns the code hash of the facet which handles `selector`,
+    /// or `None` if the selector is not registered in the diamond.
+    #[ink(message)]
+    fn facet_for_selector(&self, selector: Selector) -> Option<Hash>;
+
+    /// Returns `true` if `selector` is mapped to the code hash of the diamond itself.
+    #[ink(message)]
+    fn is_immutable(&self, selector: Selector) -> bool;
+
+    /// Returns `true` if the init call of the facet with `hash` was executed by the diamond cut.
+    /// The facet stays initialized after it is removed from the diamond.
+    #[ink(message)]
+    fn is_facet_initialized(&self, hash: Hash) -> bool;
+
+    /// Returns the amount of selectors registered in the diamond, including the immutable ones
+    #[ink(message)]
+    fn total_selectors(&self) -> u32;
+
+    /// Returns the amount of facets registered in the diamond
+    /// The diamond itself is counted as a facet if it has immutable functions
+    #[ink(message)]
+    fn total_facets(&self) -> u32;
+
+    /// Pauses the facet with `hash`, so the calls of its functions revert with `FacetPaused` error
+    /// It disables the facet faster than the diamond cut, the selectors stay mapped to the facet
+    /// Returns `FunctionDoesNotExist` error if the facet is not registered
+    #[ink(message)]
+    fn pause_facet(&mut self, hash: Hash) -> Result<(), DiamondError>;
+
+    /// Unpauses the facet with `hash`, so its functions can be called again
+    #[ink(message)]
+    fn unpause_facet(&mut self, hash: Hash) -> Result<(), DiamondError>;
+
+    /// Returns `true` if the facet with `hash` is paused
+    #[ink(message)]
+    fn is_facet_paused(&self, hash: Hash) -> bool;
 }
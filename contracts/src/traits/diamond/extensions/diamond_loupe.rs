@@ -30,6 +30,7 @@ pub type DiamondLoupeRef = dyn DiamondLoupe;
 #[openbrush::trait_definition]
 pub trait DiamondLoupe {
     /// Returns code hashes of all registered facets along with their registered function selectors
+    /// and interfaces
     #[ink(message)]
     fn facets(&self) -> Vec<FacetCut>;
 
@@ -27,4 +27,10 @@ pub enum AccessControlError {
     InvalidCaller,
     MissingRole,
     RoleRedundant,
+    /// The delay of the default admin transfer is not passed yet.
+    DefaultAdminDelayNotPassed,
+    /// The default admin role can only be transferred with `begin_default_admin_transfer`.
+    EnforcedDefaultAdminRules,
+    /// There is no pending transfer of the default admin role.
+    NoPendingDefaultAdminTransfer,
 }
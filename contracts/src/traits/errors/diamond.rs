@@ -30,6 +30,24 @@ pub enum DiamondError {
     FunctionDoesNotExist,
     EmptyCodeHash,
     ReplaceExisting(Hash),
+    /// The diamond cut would leave the protected selector unmapped.
+    RemoveProtectedSelector([u8; 4]),
+    /// The diamond is frozen and can't be cut anymore.
+    DiamondFrozen,
+    /// The `Replace` action targets the facet which already handles the selector.
+    ReplaceSameFacet,
+    /// The diamond was called with the selector which is not registered.
+    FunctionNotRegistered([u8; 4]),
+    /// The selector can't be decoded from the input of the call.
+    CalldataError,
+    /// The facet was already initialized and the init call is not forced.
+    FacetAlreadyInitialized(Hash),
+    /// The selector is mapped to the code hash of the diamond itself and can't be cut.
+    ImmutableFunction([u8; 4]),
+    /// The init call targets the code which is not registered in the diamond and is not marked as external.
+    InitHashNotRegistered(Hash),
+    /// The facet which handles the selector is paused by the owner.
+    FacetPaused(Hash),
 }
 
 impl From<OwnableError> for DiamondError {
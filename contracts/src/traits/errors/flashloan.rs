@@ -42,6 +42,10 @@ impl From<OwnableError> for FlashBorrowerError {
                 FlashBorrowerError::FlashloanRejected(String::from("O::CallerIsNotOwner"))
             }
             OwnableError::NewOwnerIsZero => FlashBorrowerError::FlashloanRejected(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                FlashBorrowerError::FlashloanRejected(String::from("O::CallerIsNotPendingOwner"))
+            }
+            OwnableError::NewOwnerIsOwner => FlashBorrowerError::FlashloanRejected(String::from("O::NewOwnerIsOwner")),
         }
     }
 }
@@ -56,6 +60,15 @@ impl From<AccessControlError> for FlashBorrowerError {
             AccessControlError::InvalidCaller => {
                 FlashBorrowerError::FlashloanRejected(String::from("AC::InvalidCaller"))
             }
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                FlashBorrowerError::FlashloanRejected(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                FlashBorrowerError::FlashloanRejected(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                FlashBorrowerError::FlashloanRejected(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
         }
     }
 }
@@ -110,6 +123,21 @@ impl From<PSP22Error> for FlashLenderError {
             PSP22Error::ZeroRecipientAddress => FlashLenderError::Custom(String::from("PSP22: Zero Recipient Address")),
             PSP22Error::ZeroSenderAddress => FlashLenderError::Custom(String::from("PSP22: Zero Sender Address")),
             PSP22Error::SafeTransferCheckFailed(message) => FlashLenderError::Custom(message),
+            PSP22Error::PermitExpired => FlashLenderError::Custom(String::from("PSP22: Permit Expired")),
+            PSP22Error::PermitInvalidSignature => {
+                FlashLenderError::Custom(String::from("PSP22: Permit Invalid Signature"))
+            }
+            PSP22Error::Overflow => FlashLenderError::Custom(String::from("PSP22: Overflow")),
+            PSP22Error::AccountBlocked(_) => FlashLenderError::Custom(String::from("PSP22: Account Blocked")),
+            PSP22Error::MintRateLimitExceeded => {
+                FlashLenderError::Custom(String::from("PSP22: Mint Rate Limit Exceeded"))
+            }
+            PSP22Error::CooldownNotElapsed => FlashLenderError::Custom(String::from("PSP22: Cooldown Not Elapsed")),
+            PSP22Error::MaxBalanceExceeded => FlashLenderError::Custom(String::from("PSP22: Max Balance Exceeded")),
+            PSP22Error::MaxTxExceeded => FlashLenderError::Custom(String::from("PSP22: Max Tx Exceeded")),
+            PSP22Error::InsufficientUnfrozenBalance => {
+                FlashLenderError::Custom(String::from("PSP22: Insufficient Unfrozen Balance"))
+            }
         }
     }
 }
@@ -127,6 +155,10 @@ impl From<OwnableError> for FlashLenderError {
         match ownable {
             OwnableError::CallerIsNotOwner => FlashLenderError::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => FlashLenderError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                FlashLenderError::Custom(String::from("O::CallerIsNotPendingOwner"))
+            }
+            OwnableError::NewOwnerIsOwner => FlashLenderError::Custom(String::from("O::NewOwnerIsOwner")),
         }
     }
 }
@@ -137,6 +169,15 @@ impl From<AccessControlError> for FlashLenderError {
             AccessControlError::MissingRole => FlashLenderError::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => FlashLenderError::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => FlashLenderError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                FlashLenderError::Custom(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                FlashLenderError::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                FlashLenderError::Custom(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
         }
     }
 }
@@ -0,0 +1,91 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::{
+    AccessControlError,
+    OwnableError,
+    PausableError,
+    ReentrancyGuardError,
+};
+use openbrush::traits::String;
+
+/// The Governor error type. Contract will throw one of this errors.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum GovernorError {
+    Custom(String),
+    EmptyProposal,
+    ProposalAlreadyExists,
+    ProposalNotFound,
+    UnexpectedProposalState,
+    AlreadyVoted,
+    SnapshotNotCreated,
+    ProposalNotReady,
+    UnderlyingTransactionReverted,
+    CallerMustBeGovernor,
+}
+
+impl From<AccessControlError> for GovernorError {
+    fn from(access: AccessControlError) -> Self {
+        match access {
+            AccessControlError::MissingRole => GovernorError::Custom(String::from("AC::MissingRole")),
+            AccessControlError::RoleRedundant => GovernorError::Custom(String::from("AC::RoleRedundant")),
+            AccessControlError::InvalidCaller => GovernorError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                GovernorError::Custom(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                GovernorError::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                GovernorError::Custom(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
+        }
+    }
+}
+
+impl From<OwnableError> for GovernorError {
+    fn from(ownable: OwnableError) -> Self {
+        match ownable {
+            OwnableError::CallerIsNotOwner => GovernorError::Custom(String::from("O::CallerIsNotOwner")),
+            OwnableError::NewOwnerIsZero => GovernorError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => GovernorError::Custom(String::from("O::CallerIsNotPendingOwner")),
+            OwnableError::NewOwnerIsOwner => GovernorError::Custom(String::from("O::NewOwnerIsOwner")),
+        }
+    }
+}
+
+impl From<PausableError> for GovernorError {
+    fn from(pausable: PausableError) -> Self {
+        match pausable {
+            PausableError::Paused => GovernorError::Custom(String::from("P::Paused")),
+            PausableError::NotPaused => GovernorError::Custom(String::from("P::NotPaused")),
+        }
+    }
+}
+
+impl From<ReentrancyGuardError> for GovernorError {
+    fn from(guard: ReentrancyGuardError) -> Self {
+        match guard {
+            ReentrancyGuardError::ReentrantCall => GovernorError::Custom(String::from("RG::ReentrantCall")),
+        }
+    }
+}
@@ -21,7 +21,10 @@
 
 mod access_control;
 mod diamond;
+mod escrow;
 mod flashloan;
+mod governor;
+mod multicall;
 mod ownable;
 mod pausable;
 mod payment_splitter;
@@ -29,23 +32,31 @@ mod psp22;
 mod psp34;
 mod psp37;
 mod reentrancy_guard;
+mod signature;
 mod timelock_controller;
+mod vesting_wallet;
 
 pub use access_control::AccessControlError;
 pub use diamond::DiamondError;
+pub use escrow::EscrowError;
 pub use flashloan::{
     FlashBorrowerError,
     FlashLenderError,
 };
+pub use governor::GovernorError;
+pub use multicall::MulticallError;
 pub use ownable::OwnableError;
 pub use pausable::PausableError;
 pub use payment_splitter::PaymentSplitterError;
 pub use psp22::{
+    PSP22CrowdsaleError,
     PSP22Error,
+    PSP22MerkleClaimError,
     PSP22ReceiverError,
     PSP22TokenTimelockError,
 };
 pub use psp34::{
+    PSP34DutchAuctionError,
     PSP34Error,
     PSP34ReceiverError,
 };
@@ -54,4 +65,6 @@ pub use psp37::{
     PSP37ReceiverError,
 };
 pub use reentrancy_guard::ReentrancyGuardError;
+pub use signature::SignatureError;
 pub use timelock_controller::TimelockControllerError;
+pub use vesting_wallet::VestingWalletError;
@@ -47,6 +47,15 @@ impl From<AccessControlError> for PaymentSplitterError {
             AccessControlError::MissingRole => PaymentSplitterError::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PaymentSplitterError::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PaymentSplitterError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                PaymentSplitterError::Custom(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PaymentSplitterError::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                PaymentSplitterError::Custom(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
         }
     }
 }
@@ -56,6 +65,10 @@ impl From<OwnableError> for PaymentSplitterError {
         match ownable {
             OwnableError::CallerIsNotOwner => PaymentSplitterError::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PaymentSplitterError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                PaymentSplitterError::Custom(String::from("O::CallerIsNotPendingOwner"))
+            }
+            OwnableError::NewOwnerIsOwner => PaymentSplitterError::Custom(String::from("O::NewOwnerIsOwner")),
         }
     }
 }
@@ -25,7 +25,10 @@ use super::{
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use openbrush::traits::{
+    AccountId,
+    String,
+};
 
 /// The PSP22 error type. Contract will throw one of this errors.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -43,6 +46,24 @@ pub enum PSP22Error {
     ZeroSenderAddress,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if the deadline of the permit has passed
+    PermitExpired,
+    /// Returned if the signature of the permit is invalid
+    PermitInvalidSignature,
+    /// Returned if the total supply or the balance of the account would exceed `Balance::MAX`
+    Overflow,
+    /// Returned if the sender or the recipient is blocked
+    AccountBlocked(AccountId),
+    /// Returned if the mint exceeds the amount allowed within the current period
+    MintRateLimitExceeded,
+    /// Returned if the sender transfers again before the transfer cooldown has elapsed
+    CooldownNotElapsed,
+    /// Returned if the transfer would make the balance of the recipient exceed the max balance
+    MaxBalanceExceeded,
+    /// Returned if the amount of the transfer exceeds the max transaction amount
+    MaxTxExceeded,
+    /// Returned if the sender transfers more than the unfrozen part of its balance
+    InsufficientUnfrozenBalance,
 }
 
 impl From<OwnableError> for PSP22Error {
@@ -50,6 +71,8 @@ impl From<OwnableError> for PSP22Error {
         match ownable {
             OwnableError::CallerIsNotOwner => PSP22Error::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PSP22Error::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => PSP22Error::Custom(String::from("O::CallerIsNotPendingOwner")),
+            OwnableError::NewOwnerIsOwner => PSP22Error::Custom(String::from("O::NewOwnerIsOwner")),
         }
     }
 }
@@ -60,6 +83,15 @@ impl From<AccessControlError> for PSP22Error {
             AccessControlError::MissingRole => PSP22Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP22Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP22Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                PSP22Error::Custom(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PSP22Error::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                PSP22Error::Custom(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
         }
     }
 }
@@ -118,6 +150,21 @@ impl From<PSP22Error> for PSP22TokenTimelockError {
             PSP22Error::SafeTransferCheckFailed(message) => {
                 PSP22TokenTimelockError::PSP22Error(PSP22Error::SafeTransferCheckFailed(message))
             }
+            PSP22Error::PermitExpired => PSP22TokenTimelockError::PSP22Error(PSP22Error::PermitExpired),
+            PSP22Error::PermitInvalidSignature => {
+                PSP22TokenTimelockError::PSP22Error(PSP22Error::PermitInvalidSignature)
+            }
+            PSP22Error::Overflow => PSP22TokenTimelockError::PSP22Error(PSP22Error::Overflow),
+            PSP22Error::AccountBlocked(account) => {
+                PSP22TokenTimelockError::PSP22Error(PSP22Error::AccountBlocked(account))
+            }
+            PSP22Error::MintRateLimitExceeded => PSP22TokenTimelockError::PSP22Error(PSP22Error::MintRateLimitExceeded),
+            PSP22Error::CooldownNotElapsed => PSP22TokenTimelockError::PSP22Error(PSP22Error::CooldownNotElapsed),
+            PSP22Error::MaxBalanceExceeded => PSP22TokenTimelockError::PSP22Error(PSP22Error::MaxBalanceExceeded),
+            PSP22Error::MaxTxExceeded => PSP22TokenTimelockError::PSP22Error(PSP22Error::MaxTxExceeded),
+            PSP22Error::InsufficientUnfrozenBalance => {
+                PSP22TokenTimelockError::PSP22Error(PSP22Error::InsufficientUnfrozenBalance)
+            }
         }
     }
 }
@@ -145,3 +192,41 @@ impl From<ReentrancyGuardError> for PSP22TokenTimelockError {
         PSP22TokenTimelockError::PSP22Error(guard.into())
     }
 }
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22CrowdsaleError {
+    PSP22Error(PSP22Error),
+    /// Returned if the beneficiary of the purchase is zero address
+    ZeroBeneficiaryAddress,
+    /// Returned if the purchase doesn't transfer any funds
+    ZeroPurchase,
+    /// Returned if the cap of the sale was already reached
+    CapReached,
+    /// Returned if the purchase exceeds the limit of the beneficiary
+    WalletLimitExceeded,
+    /// Returned if the transfer of the native currency failed
+    TransferFailed,
+}
+
+impl From<PSP22Error> for PSP22CrowdsaleError {
+    fn from(error: PSP22Error) -> Self {
+        PSP22CrowdsaleError::PSP22Error(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22MerkleClaimError {
+    PSP22Error(PSP22Error),
+    /// Returned if the leaf is already claimed
+    AlreadyClaimed,
+    /// Returned if the merkle proof of the leaf is invalid
+    InvalidProof,
+}
+
+impl From<PSP22Error> for PSP22MerkleClaimError {
+    fn from(error: PSP22Error) -> Self {
+        PSP22MerkleClaimError::PSP22Error(error)
+    }
+}
@@ -43,6 +43,16 @@ pub enum PSP34Error {
     TokenNotExists,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if minting exceeds the cap of the collection
+    CollectionIsFull,
+    /// Returned if the voucher is not signed by the owner of the collection
+    VoucherInvalidSignature,
+    /// Returned if the voucher for the same id was already redeemed
+    VoucherAlreadyRedeemed,
+    /// Returned if the transferred value is below the price of the voucher
+    VoucherInsufficientValue,
+    /// Returned if the payment for the voucher can't be transferred
+    VoucherPaymentFailed,
 }
 
 impl From<OwnableError> for PSP34Error {
@@ -50,6 +60,8 @@ impl From<OwnableError> for PSP34Error {
         match ownable {
             OwnableError::CallerIsNotOwner => PSP34Error::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PSP34Error::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => PSP34Error::Custom(String::from("O::CallerIsNotPendingOwner")),
+            OwnableError::NewOwnerIsOwner => PSP34Error::Custom(String::from("O::NewOwnerIsOwner")),
         }
     }
 }
@@ -60,6 +72,15 @@ impl From<AccessControlError> for PSP34Error {
             AccessControlError::MissingRole => PSP34Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP34Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP34Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                PSP34Error::Custom(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PSP34Error::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                PSP34Error::Custom(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
         }
     }
 }
@@ -96,3 +117,23 @@ impl From<PSP34ReceiverError> for PSP34Error {
         }
     }
 }
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP34DutchAuctionError {
+    PSP34Error(PSP34Error),
+    /// Returned if the end price of the auction is above the start price
+    InvalidPriceRange,
+    /// Returned if the auction didn't start yet
+    AuctionNotStarted,
+    /// Returned if the transferred value is below the current price
+    InsufficientValue,
+    /// Returned if the transfer of the native currency failed
+    TransferFailed,
+}
+
+impl From<PSP34Error> for PSP34DutchAuctionError {
+    fn from(error: PSP34Error) -> Self {
+        PSP34DutchAuctionError::PSP34Error(error)
+    }
+}
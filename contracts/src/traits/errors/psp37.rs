@@ -25,7 +25,11 @@ use super::{
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use crate::traits::types::Id;
+use openbrush::traits::{
+    Balance,
+    String,
+};
 
 /// The PSP37 error type. Contract will throw one of this errors.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -45,6 +49,10 @@ pub enum PSP37Error {
     SelfApprove,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if minting exceeds the cap of the token
+    CapExceeded,
+    /// Returned if the account doesn't hold the amount of the token `Id` required by the transfer.
+    InsufficientTokenBalance(Id, Balance),
 }
 
 impl From<OwnableError> for PSP37Error {
@@ -52,6 +60,8 @@ impl From<OwnableError> for PSP37Error {
         match ownable {
             OwnableError::CallerIsNotOwner => PSP37Error::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PSP37Error::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => PSP37Error::Custom(String::from("O::CallerIsNotPendingOwner")),
+            OwnableError::NewOwnerIsOwner => PSP37Error::Custom(String::from("O::NewOwnerIsOwner")),
         }
     }
 }
@@ -62,6 +72,15 @@ impl From<AccessControlError> for PSP37Error {
             AccessControlError::MissingRole => PSP37Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP37Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP37Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                PSP37Error::Custom(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PSP37Error::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                PSP37Error::Custom(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
         }
     }
 }
@@ -54,6 +54,15 @@ impl From<AccessControlError> for TimelockControllerError {
             AccessControlError::InvalidCaller => {
                 TimelockControllerError::AccessControlError(AccessControlError::InvalidCaller)
             }
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                TimelockControllerError::AccessControlError(AccessControlError::DefaultAdminDelayNotPassed)
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                TimelockControllerError::AccessControlError(AccessControlError::EnforcedDefaultAdminRules)
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                TimelockControllerError::AccessControlError(AccessControlError::NoPendingDefaultAdminTransfer)
+            }
         }
     }
 }
@@ -63,6 +72,10 @@ impl From<OwnableError> for TimelockControllerError {
         match ownable {
             OwnableError::CallerIsNotOwner => TimelockControllerError::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => TimelockControllerError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                TimelockControllerError::Custom(String::from("O::CallerIsNotPendingOwner"))
+            }
+            OwnableError::NewOwnerIsOwner => TimelockControllerError::Custom(String::from("O::NewOwnerIsOwner")),
         }
     }
 }
@@ -0,0 +1,87 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::{
+    AccessControlError,
+    OwnableError,
+    PausableError,
+    ReentrancyGuardError,
+};
+use openbrush::traits::String;
+
+/// The VestingWallet error type. Contract will throw one of this errors.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum VestingWalletError {
+    Custom(String),
+    BeneficiaryZeroAddress,
+    NothingToRelease,
+    TransferFailed,
+}
+
+impl From<AccessControlError> for VestingWalletError {
+    fn from(access: AccessControlError) -> Self {
+        match access {
+            AccessControlError::MissingRole => VestingWalletError::Custom(String::from("AC::MissingRole")),
+            AccessControlError::RoleRedundant => VestingWalletError::Custom(String::from("AC::RoleRedundant")),
+            AccessControlError::InvalidCaller => VestingWalletError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::DefaultAdminDelayNotPassed => {
+                VestingWalletError::Custom(String::from("AC::DefaultAdminDelayNotPassed"))
+            }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                VestingWalletError::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::NoPendingDefaultAdminTransfer => {
+                VestingWalletError::Custom(String::from("AC::NoPendingDefaultAdminTransfer"))
+            }
+        }
+    }
+}
+
+impl From<OwnableError> for VestingWalletError {
+    fn from(ownable: OwnableError) -> Self {
+        match ownable {
+            OwnableError::CallerIsNotOwner => VestingWalletError::Custom(String::from("O::CallerIsNotOwner")),
+            OwnableError::NewOwnerIsZero => VestingWalletError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                VestingWalletError::Custom(String::from("O::CallerIsNotPendingOwner"))
+            }
+            OwnableError::NewOwnerIsOwner => VestingWalletError::Custom(String::from("O::NewOwnerIsOwner")),
+        }
+    }
+}
+
+impl From<PausableError> for VestingWalletError {
+    fn from(pausable: PausableError) -> Self {
+        match pausable {
+            PausableError::Paused => VestingWalletError::Custom(String::from("P::Paused")),
+            PausableError::NotPaused => VestingWalletError::Custom(String::from("P::NotPaused")),
+        }
+    }
+}
+
+impl From<ReentrancyGuardError> for VestingWalletError {
+    fn from(guard: ReentrancyGuardError) -> Self {
+        match guard {
+            ReentrancyGuardError::ReentrantCall => VestingWalletError::Custom(String::from("RG::ReentrantCall")),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::EscrowError;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+pub mod extensions {
+    pub mod conditional;
+}
+
+#[openbrush::wrapper]
+pub type EscrowRef = dyn Escrow;
+
+/// This contract holds the native tokens deposited for the payees until they are withdrawn.
+///
+/// Anyone can deposit the funds for the payee, and the funds can only be withdrawn to the payee.
+/// Restrict `deposit` and `withdraw` with modifiers (for example `only_owner`) if the escrow
+/// must be managed by one account, or override `_withdrawal_allowed` to gate the withdrawals.
+#[openbrush::trait_definition]
+pub trait Escrow {
+    /// Returns the amount of the native tokens deposited for `payee`.
+    #[ink(message)]
+    fn deposits_of(&self, payee: AccountId) -> Balance;
+
+    /// Stores the transferred native tokens as the deposit for `payee`.
+    ///
+    /// On success a `Deposited` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `ZeroPayeeAddress` error if `payee` is zero address.
+    #[ink(message, payable)]
+    fn deposit(&mut self, payee: AccountId) -> Result<(), EscrowError>;
+
+    /// Transfers all funds deposited for `payee` to `payee` and clears the deposit.
+    ///
+    /// On success a `Withdrawn` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `WithdrawalNotAllowed` error if `_withdrawal_allowed` returns `false` for `payee`.
+    ///
+    /// Returns with `TransferFailed` error if the transfer to `payee` fails.
+    #[ink(message)]
+    fn withdraw(&mut self, payee: AccountId) -> Result<(), EscrowError>;
+}
@@ -0,0 +1,186 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    errors::GovernorError,
+    timelock_controller::Transaction,
+};
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Hash,
+    String,
+    Timestamp,
+};
+
+pub type ProposalId = Hash;
+
+/// The state of the proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ProposalState {
+    /// The voting hasn't started yet.
+    Pending,
+    /// The voting is in progress.
+    Active,
+    /// The voting has ended without reaching the quorum or the majority.
+    Defeated,
+    /// The voting has ended with the quorum and the majority, so the proposal can be queued.
+    Succeeded,
+    /// The proposal is queued and can be executed after the execution delay.
+    Queued,
+    /// The transactions of the proposal were executed.
+    Executed,
+}
+
+/// The support of the voter for the proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum VoteType {
+    Against,
+    For,
+    Abstain,
+}
+
+#[openbrush::wrapper]
+pub type GovernorRef = dyn Governor;
+
+/// Contract module of on-chain governance in the style of GovernorBravo.
+///
+/// Anyone can propose a set of transactions. The holders of the votes token vote for the proposal
+/// with the weight they had at the last snapshot of the token created before the proposal.
+/// The succeeded proposal is queued and, after the execution delay, its transactions are executed
+/// by the governor, so the governor should own the governed contracts.
+///
+/// The voting delay, the voting period and the quorum can be changed only by the proposal
+/// executed by the governor itself.
+#[openbrush::trait_definition]
+pub trait Governor {
+    /// Returns the address of the token used for the voting.
+    #[ink(message)]
+    fn token(&self) -> AccountId;
+
+    /// Returns the delay between the proposal and the start of the voting.
+    #[ink(message)]
+    fn voting_delay(&self) -> Timestamp;
+
+    /// Returns the duration of the voting.
+    #[ink(message)]
+    fn voting_period(&self) -> Timestamp;
+
+    /// Returns the delay between queuing and the execution of the proposal.
+    #[ink(message)]
+    fn execution_delay(&self) -> Timestamp;
+
+    /// Returns the minimal amount of `For` and `Abstain` votes required for the proposal
+    /// created at the snapshot `snapshot_id` to succeed.
+    #[ink(message)]
+    fn quorum(&self, snapshot_id: u64) -> Balance;
+
+    /// Returns the identifier of the proposal of `transactions` with `description_hash`.
+    #[ink(message)]
+    fn hash_proposal(&self, transactions: Vec<Transaction>, description_hash: Hash) -> ProposalId;
+
+    /// Returns the current state of the proposal.
+    ///
+    /// On error returns `ProposalNotFound` if the proposal doesn't exist.
+    #[ink(message)]
+    fn state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError>;
+
+    /// Returns the id of the token snapshot used for the votes of the proposal, or `0` if it doesn't exist.
+    #[ink(message)]
+    fn proposal_snapshot(&self, proposal_id: ProposalId) -> u64;
+
+    /// Returns the timestamp when the voting of the proposal ends, or `0` if it doesn't exist.
+    #[ink(message)]
+    fn proposal_deadline(&self, proposal_id: ProposalId) -> Timestamp;
+
+    /// Returns the timestamp after which the queued proposal can be executed, or `0` if it isn't queued.
+    #[ink(message)]
+    fn proposal_eta(&self, proposal_id: ProposalId) -> Timestamp;
+
+    /// Returns the `Against`, `For` and `Abstain` votes of the proposal.
+    #[ink(message)]
+    fn proposal_votes(&self, proposal_id: ProposalId) -> (Balance, Balance, Balance);
+
+    /// Returns whether `account` has voted for the proposal.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Creates the proposal to execute `transactions`. Each transaction holds the target,
+    /// the transferred value and the call data of one call.
+    ///
+    /// On success a `ProposalCreated` event is emitted.
+    ///
+    /// On error returns `EmptyProposal` if there are no transactions,
+    /// `ProposalAlreadyExists` if the same proposal was already created and
+    /// `SnapshotNotCreated` if the token fails to create the snapshot of the proposal.
+    #[ink(message)]
+    fn propose(&mut self, transactions: Vec<Transaction>, description: String) -> Result<ProposalId, GovernorError>;
+
+    /// Casts the vote of the caller with the weight of the caller at the snapshot of the proposal.
+    ///
+    /// Returns the weight of the vote.
+    ///
+    /// On success a `VoteCast` event is emitted.
+    ///
+    /// On error returns `UnexpectedProposalState` if the voting isn't active
+    /// and `AlreadyVoted` if the caller has already voted.
+    #[ink(message)]
+    fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType) -> Result<Balance, GovernorError>;
+
+    /// Queues the succeeded proposal, so it can be executed after the execution delay.
+    ///
+    /// On success a `ProposalQueued` event is emitted.
+    ///
+    /// On error returns `UnexpectedProposalState` if the proposal hasn't succeeded.
+    #[ink(message)]
+    fn queue(&mut self, transactions: Vec<Transaction>, description_hash: Hash) -> Result<ProposalId, GovernorError>;
+
+    /// Executes the transactions of the queued proposal.
+    ///
+    /// On success a `ProposalExecuted` event is emitted.
+    ///
+    /// On error returns `UnexpectedProposalState` if the proposal isn't queued,
+    /// `ProposalNotReady` if the execution delay hasn't passed and
+    /// `UnderlyingTransactionReverted` if any transaction failed.
+    #[ink(message, payable)]
+    fn execute(&mut self, transactions: Vec<Transaction>, description_hash: Hash) -> Result<ProposalId, GovernorError>;
+
+    /// Changes the voting delay.
+    ///
+    /// On error returns `CallerMustBeGovernor` if the caller isn't the governor itself.
+    #[ink(message)]
+    fn set_voting_delay(&mut self, voting_delay: Timestamp) -> Result<(), GovernorError>;
+
+    /// Changes the voting period.
+    ///
+    /// On error returns `CallerMustBeGovernor` if the caller isn't the governor itself.
+    #[ink(message)]
+    fn set_voting_period(&mut self, voting_period: Timestamp) -> Result<(), GovernorError>;
+
+    /// Changes the quorum.
+    ///
+    /// On error returns `CallerMustBeGovernor` if the caller isn't the governor itself.
+    #[ink(message)]
+    fn set_quorum(&mut self, quorum: Balance) -> Result<(), GovernorError>;
+}
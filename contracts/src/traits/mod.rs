@@ -22,7 +22,10 @@
 pub mod access_control;
 pub mod diamond;
 pub mod errors;
+pub mod escrow;
 pub mod flashloan;
+pub mod governor;
+pub mod multicall;
 pub mod ownable;
 pub mod pausable;
 pub mod payment_splitter;
@@ -30,6 +33,8 @@ pub mod proxy;
 pub mod psp22;
 pub mod psp34;
 pub mod psp37;
+pub mod pull_payment;
 pub mod timelock_controller;
+pub mod vesting_wallet;
 
 mod types;
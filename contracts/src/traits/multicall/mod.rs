@@ -0,0 +1,60 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::MulticallError;
+use ink_prelude::vec::Vec;
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type MulticallRef = dyn Multicall;
+
+/// Each call is a pair of the callee and the SCALE encoded input of the message,
+/// which is the selector followed by the arguments.
+pub type MulticallCall = (AccountId, Vec<u8>);
+
+/// This contract aggregates several read-only calls to other contracts into one call.
+///
+/// The calls are executed one by one from the account of the contract, and the raw SCALE encoded
+/// outputs of the called messages are returned in the same order.
+#[openbrush::trait_definition]
+pub trait Multicall {
+    /// Executes all `calls` and returns their outputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CallFailed` error with the index of the first call which reverted.
+    #[ink(message)]
+    fn aggregate(&self, calls: Vec<MulticallCall>) -> Result<Vec<Vec<u8>>, MulticallError>;
+
+    /// Executes all `calls` and returns the success flag and the output of each call.
+    /// The output of the reverted call is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CallFailed` error with the index of the first call which reverted,
+    /// if `require_success` is `true`.
+    #[ink(message)]
+    fn try_aggregate(
+        &self,
+        require_success: bool,
+        calls: Vec<MulticallCall>,
+    ) -> Result<Vec<(bool, Vec<u8>)>, MulticallError>;
+}
@@ -0,0 +1,37 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`Ownable`] that validates signatures on behalf of the contract.
+use ink_prelude::vec::Vec;
+
+#[openbrush::wrapper]
+pub type OwnableSignatureRef = dyn OwnableSignature;
+
+/// EIP-1271 style signature validation. Off-chain signed messages are authorized
+/// by the contract if they are signed by the current owner.
+#[openbrush::trait_definition]
+pub trait OwnableSignature {
+    /// Returns `true` if `signature` is the ecdsa signature of `hash` made by the owner.
+    ///
+    /// Returns `false` if the signature is malformed or made by any other account.
+    #[ink(message)]
+    fn is_valid_signature(&self, hash: [u8; 32], signature: Vec<u8>) -> bool;
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`Ownable`] that transfers ownership in two steps.
+pub use crate::traits::errors::OwnableError;
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type Ownable2StepRef = dyn Ownable2Step;
+
+/// The current owner nominates a pending owner with `transfer_ownership`,
+/// and the ownership is transferred only when the pending owner calls `accept_ownership`.
+#[openbrush::trait_definition]
+pub trait Ownable2Step {
+    /// Returns the address of the pending owner.
+    #[ink(message)]
+    fn pending_owner(&self) -> Option<AccountId>;
+
+    /// The pending owner accepts the ownership of the contract.
+    ///
+    /// On success a `OwnershipTransferred` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CallerIsNotPendingOwner` error if caller is not the pending owner.
+    #[ink(message)]
+    fn accept_ownership(&mut self) -> Result<(), OwnableError>;
+}
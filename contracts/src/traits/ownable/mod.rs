@@ -22,6 +22,11 @@
 pub use crate::traits::errors::OwnableError;
 use openbrush::traits::AccountId;
 
+pub mod extensions {
+    pub mod signature;
+    pub mod two_step;
+}
+
 #[openbrush::wrapper]
 pub type OwnableRef = dyn Ownable;
 
@@ -58,6 +63,9 @@ pub trait Ownable {
     /// Panics with `CallerIsNotOwner` error if caller is not owner.
     ///
     /// Panics with `NewOwnerIsZero` error if new owner's address is zero.
+    /// Use `renounce_ownership` to leave the contract without owner.
+    ///
+    /// Panics with `NewOwnerIsOwner` error if new owner is the current owner.
     #[ink(message)]
     fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError>;
 }
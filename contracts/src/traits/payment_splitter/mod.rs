@@ -38,7 +38,9 @@ pub type PaymentSplitterRef = dyn PaymentSplitter;
 /// `PaymentSplitter` follows a pull payment model. This means that payments are not automatically forwarded to the
 /// accounts but kept in this contract, and the actual transfer is triggered as a separate step by calling the `release`
 /// function.
-// TODO: Support payments in PSP22
+///
+/// In addition to the native token, the contract splits the balances of any PSP22 token that it holds.
+/// The tokens are released with the `release_token` function.
 #[openbrush::trait_definition]
 pub trait PaymentSplitter {
     /// Getter for the total shares held by payees.
@@ -76,4 +78,24 @@ pub trait PaymentSplitter {
     /// On success a `PaymentReleased` event is emitted.
     #[ink(message)]
     fn release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Getter for the total amount of `token` already released.
+    #[ink(message)]
+    fn total_released_token(&self, token: AccountId) -> Balance;
+
+    /// Getter for the amount of `token` already released to a payee.
+    #[ink(message)]
+    fn released_token(&self, token: AccountId, account: AccountId) -> Balance;
+
+    /// Getter for the amount of `token` that `account` can release,
+    /// according to their percentage of the total shares and their previous withdrawals.
+    #[ink(message)]
+    fn releasable_token(&self, token: AccountId, account: AccountId) -> Balance;
+
+    /// Triggers a transfer to `account` of the amount of `token` they are owed, according to their percentage of the
+    /// total shares and their previous withdrawals. `token` must be a PSP22 token.
+    ///
+    /// On success a `TokenPaymentReleased` event is emitted.
+    #[ink(message)]
+    fn release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError>;
 }
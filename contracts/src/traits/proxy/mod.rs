@@ -23,6 +23,7 @@ pub use crate::traits::{
     errors::OwnableError,
     ownable::*,
 };
+use ink_prelude::vec::Vec;
 use openbrush::traits::Hash;
 
 #[openbrush::wrapper]
@@ -35,4 +36,21 @@ pub trait Proxy: Ownable {
 
     #[ink(message)]
     fn change_delegate_code(&mut self, new_code_hash: Hash) -> Result<(), OwnableError>;
+
+    /// Changes the delegate code to `new_code_hash` and calls the function of the new code
+    /// identified by `selector` with SCALE encoded `input` in the same transaction.
+    /// It allows to initialize the upgraded storage before any other call uses the new code.
+    ///
+    /// The call is a tail call, so the output of the called function is the output of this method.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CallerIsNotOwner` error if caller is not owner.
+    #[ink(message)]
+    fn change_delegate_code_and_call(
+        &mut self,
+        new_code_hash: Hash,
+        selector: [u8; 4],
+        input: Vec<u8>,
+    ) -> Result<(), OwnableError>;
 }
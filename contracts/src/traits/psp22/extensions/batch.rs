@@ -0,0 +1,50 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that allows to transfer tokens to many recipients in one call
+pub use crate::traits::errors::PSP22Error;
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP22BatchRef = dyn PSP22Batch;
+
+#[openbrush::trait_definition]
+pub trait PSP22Batch {
+    /// Transfers tokens from the caller's account to each recipient of `transfers`
+    /// with additional `data` in unspecified format.
+    ///
+    /// On success a `Transfer` event is emitted for each recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` error if the caller's account Balance is not enough
+    /// for all transfers. In this case none of the transfers is performed.
+    ///
+    /// Returns `ZeroSenderAddress` error if sender's address is zero.
+    ///
+    /// Returns `ZeroRecipientAddress` error if one of recipient's address is zero.
+    #[ink(message)]
+    fn batch_transfer(&mut self, transfers: Vec<(AccountId, Balance)>, data: Vec<u8>) -> Result<(), PSP22Error>;
+}
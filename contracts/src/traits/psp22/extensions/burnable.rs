@@ -38,4 +38,15 @@ pub trait PSP22Burnable {
     /// See [`PSP22::_burn_from`].
     #[ink(message)]
     fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+
+    /// Destroys `amount` tokens from `account`, deducting them from the caller's allowance
+    /// like `transfer_from` does. The unlimited allowance (`Balance::MAX`) is not decreased.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientAllowance` error if the caller doesn't have enough allowance.
+    ///
+    /// Returns `InsufficientBalance` error if `account` doesn't contain enough balance.
+    #[ink(message)]
+    fn burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 }
@@ -0,0 +1,54 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that limits the balance of each wallet and the amount of each transfer
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::Balance;
+
+#[openbrush::wrapper]
+pub type PSP22LimitsRef = dyn PSP22Limits;
+
+#[openbrush::trait_definition]
+pub trait PSP22Limits {
+    /// Returns the max balance which one account can receive by transfers. Zero means no limit.
+    #[ink(message)]
+    fn max_balance(&self) -> Balance;
+
+    /// Returns the max amount of one transfer. Zero means no limit.
+    #[ink(message)]
+    fn max_tx_amount(&self) -> Balance;
+
+    /// Sets the max balance which one account can receive by transfers. Zero removes the limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not the owner.
+    #[ink(message)]
+    fn set_max_balance(&mut self, limit: Balance) -> Result<(), PSP22Error>;
+
+    /// Sets the max amount of one transfer. Zero removes the limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not the owner.
+    #[ink(message)]
+    fn set_max_tx_amount(&mut self, limit: Balance) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that limits the amount of tokens minted per period
+use openbrush::traits::{
+    Balance,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type PSP22MintRateLimitRef = dyn PSP22MintRateLimit;
+
+#[openbrush::trait_definition]
+pub trait PSP22MintRateLimit {
+    /// Returns the length of the mint window in milliseconds. Zero means that the mints are not limited.
+    #[ink(message)]
+    fn mint_period(&self) -> Timestamp;
+
+    /// Returns the amount of tokens which can be minted within one window.
+    #[ink(message)]
+    fn mint_cap_per_period(&self) -> Balance;
+
+    /// Returns the amount of tokens which still can be minted within the current window.
+    #[ink(message)]
+    fn mint_allowance_remaining(&self) -> Balance;
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that allows to approve spending of tokens with a signed message
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type PSP22PermitRef = dyn PSP22Permit;
+
+#[openbrush::trait_definition]
+pub trait PSP22Permit {
+    /// Sets `value` as the allowance of `spender` over the tokens of `owner`,
+    /// given the approval signed by `owner`. The call can be submitted by anyone.
+    ///
+    /// `signature` is the ecdsa signature of `owner` over the blake2x256 hash of SCALE encoded
+    /// `(domain_separator, owner, spender, value, nonce, deadline)`, where `nonce` is `nonces(owner)`.
+    ///
+    /// On success an `Approval` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` error if `deadline` is before the current block timestamp.
+    ///
+    /// Returns `PermitInvalidSignature` error if `signature` isn't signed by `owner`.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: [u8; 65],
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current nonce of `owner`, which should be included into the next permit.
+    #[ink(message)]
+    fn nonces(&self, owner: AccountId) -> u64;
+
+    /// Returns the domain separator used in the permit message.
+    /// It depends on the account of the contract to prevent replays of the permit on other tokens.
+    #[ink(message)]
+    fn domain_separator(&self) -> [u8; 32];
+}
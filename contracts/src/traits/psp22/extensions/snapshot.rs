@@ -0,0 +1,63 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that allows to query balances and total supply
+/// at the moment of the snapshot, for example for on-chain voting
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP22SnapshotRef = dyn PSP22Snapshot;
+
+#[openbrush::trait_definition]
+pub trait PSP22Snapshot {
+    /// Returns the id of the last created snapshot.
+    ///
+    /// Returns `0` if no snapshot has been created yet.
+    #[ink(message)]
+    fn current_snapshot_id(&self) -> u64;
+
+    /// Returns the balance of `account` at the moment when the snapshot `snapshot_id` was created.
+    ///
+    /// Returns `0` if the snapshot `snapshot_id` doesn't exist yet.
+    #[ink(message)]
+    fn balance_of_at(&self, account: AccountId, snapshot_id: u64) -> Balance;
+
+    /// Returns the total supply at the moment when the snapshot `snapshot_id` was created.
+    ///
+    /// Returns `0` if the snapshot `snapshot_id` doesn't exist yet.
+    #[ink(message)]
+    fn total_supply_at(&self, snapshot_id: u64) -> Balance;
+
+    /// Creates a new snapshot and returns its id.
+    ///
+    /// Only the owner can create the snapshot by default, for example, the governor
+    /// that owns the votes token.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not the owner.
+    #[ink(message)]
+    fn snapshot(&mut self) -> Result<u64, PSP22Error>;
+}
@@ -0,0 +1,39 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that charges a fee on each transfer
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type PSP22TaxableRef = dyn PSP22Taxable;
+
+#[openbrush::trait_definition]
+pub trait PSP22Taxable {
+    /// Returns the fee charged on each transfer in basis points (1/10000 of the amount).
+    #[ink(message)]
+    fn fee_bps(&self) -> u16;
+
+    /// Returns the account which receives the fees.
+    ///
+    /// Returns `None` if the recipient is not set, in that case no fee is charged.
+    #[ink(message)]
+    fn fee_recipient(&self) -> Option<AccountId>;
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that requires a minimum time between transfers of each sender
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type PSP22TransferCooldownRef = dyn PSP22TransferCooldown;
+
+#[openbrush::trait_definition]
+pub trait PSP22TransferCooldown {
+    /// Returns the minimum time in milliseconds between two transfers of one sender.
+    /// Zero means that the transfers are not limited.
+    #[ink(message)]
+    fn transfer_cooldown(&self) -> Timestamp;
+
+    /// Returns the time of the last transfer of `account` or `None` if it didn't transfer yet.
+    #[ink(message)]
+    fn last_transfer(&self, account: AccountId) -> Option<Timestamp>;
+
+    /// Sets the minimum time in milliseconds between two transfers of one sender.
+    /// Zero disables the cooldown.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not the owner.
+    #[ink(message)]
+    fn set_transfer_cooldown(&mut self, cooldown: Timestamp) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] which turns the token into the shares of a vault of underlying assets
+pub use crate::traits::errors::PSP22Error;
+pub use crate::traits::psp22::*;
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// The idea of PSP22Vault is that the share token is PSP22 by itself.
+/// The vault holds the underlying PSP22 asset and mints shares proportionally to the deposited assets.
+#[openbrush::wrapper]
+pub type PSP22VaultRef = dyn PSP22Vault + PSP22;
+
+#[openbrush::trait_definition]
+pub trait PSP22Vault: PSP22 {
+    /// Returns the address of the underlying asset.
+    #[ink(message)]
+    fn asset(&self) -> AccountId;
+
+    /// Returns the amount of underlying assets managed by the vault.
+    #[ink(message)]
+    fn total_assets(&self) -> Balance;
+
+    /// Returns the amount of shares which the vault would exchange for `assets`, rounded down.
+    #[ink(message)]
+    fn convert_to_shares(&self, assets: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of assets which the vault would exchange for `shares`, rounded down.
+    #[ink(message)]
+    fn convert_to_assets(&self, shares: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of shares which `deposit` of `assets` would mint, rounded down.
+    #[ink(message)]
+    fn preview_deposit(&self, assets: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of assets which `mint` of `shares` would take, rounded up.
+    #[ink(message)]
+    fn preview_mint(&self, shares: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of shares which `withdraw` of `assets` would burn, rounded up.
+    #[ink(message)]
+    fn preview_withdraw(&self, assets: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of assets which `redeem` of `shares` would return, rounded down.
+    #[ink(message)]
+    fn preview_redeem(&self, shares: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Takes `assets` of underlying tokens from the caller and mints the shares to `receiver`.
+    ///
+    /// Returns the amount of minted shares.
+    #[ink(message)]
+    fn deposit(&mut self, assets: Balance, receiver: AccountId) -> Result<Balance, PSP22Error>;
+
+    /// Mints exactly `shares` to `receiver` taking the required underlying tokens from the caller.
+    ///
+    /// Returns the amount of taken assets.
+    #[ink(message)]
+    fn mint(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance, PSP22Error>;
+
+    /// Burns the shares of `owner` and sends exactly `assets` of underlying tokens to `receiver`.
+    ///
+    /// Returns the amount of burned shares.
+    ///
+    /// On error returns `InsufficientAllowance` if the caller is not `owner`
+    /// and is not allowed to spend the shares.
+    #[ink(message)]
+    fn withdraw(&mut self, assets: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error>;
+
+    /// Burns exactly `shares` of `owner` and sends the underlying tokens to `receiver`.
+    ///
+    /// Returns the amount of sent assets.
+    ///
+    /// On error returns `InsufficientAllowance` if the caller is not `owner`
+    /// and is not allowed to spend the shares.
+    #[ink(message)]
+    fn redeem(&mut self, shares: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error>;
+}
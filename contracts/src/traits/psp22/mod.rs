@@ -24,13 +24,28 @@ mod psp22;
 pub use psp22::*;
 
 pub mod extensions {
+    pub mod batch;
     pub mod burnable;
     pub mod capped;
+    pub mod circulating_supply;
+    pub mod holder_count;
+    pub mod limits;
     pub mod metadata;
+    pub mod mint_rate_limit;
     pub mod mintable;
+    pub mod partial_freeze;
+    pub mod permit;
+    pub mod restricted;
+    pub mod snapshot;
+    pub mod taxable;
+    pub mod transfer_cooldown;
+    pub mod vault;
     pub mod wrapper;
 }
 
 pub mod utils {
+    pub mod crowdsale;
+    pub mod merkle_claim;
+    pub mod recoverable;
     pub mod token_timelock;
 }
@@ -75,6 +75,9 @@ pub trait PSP22 {
     ///
     /// On success a `Transfer` and `Approval` events are emitted.
     ///
+    /// An allowance of `Balance::MAX` is treated as unlimited: it is not decreased
+    /// and no `Approval` event is emitted.
+    ///
     /// # Errors
     ///
     /// Returns `InsufficientAllowance` error if there are not enough tokens allowed
@@ -0,0 +1,80 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::PSP22CrowdsaleError;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP22CrowdsaleRef = dyn PSP22Crowdsale;
+
+/// Sale of the PSP22 tokens for the native currency at the fixed rate, capped at the hard cap
+#[openbrush::trait_definition]
+pub trait PSP22Crowdsale {
+    /// Returns the address of the token being sold
+    #[ink(message)]
+    fn token(&self) -> AccountId;
+
+    /// Returns the address where the raised funds are forwarded
+    #[ink(message)]
+    fn wallet(&self) -> AccountId;
+
+    /// Returns the number of tokens the buyer gets per unit of the native currency
+    #[ink(message)]
+    fn rate(&self) -> Balance;
+
+    /// Returns the amount of the native currency raised by the sale
+    #[ink(message)]
+    fn funds_raised(&self) -> Balance;
+
+    /// Returns the maximum amount of the native currency the sale can raise
+    #[ink(message)]
+    fn cap(&self) -> Balance;
+
+    /// Returns the maximum amount of the native currency one beneficiary can contribute
+    #[ink(message)]
+    fn wallet_limit(&self) -> Balance;
+
+    /// Returns the amount of the native currency contributed on behalf of `beneficiary`
+    #[ink(message)]
+    fn contribution_of(&self, beneficiary: AccountId) -> Balance;
+
+    /// Buys tokens for the transferred value and delivers them to `beneficiary`.
+    ///
+    /// If the purchase would exceed the cap, only the remainder up to the cap is accepted
+    /// and the excess is refunded to the caller.
+    ///
+    /// On success a `TokensPurchased` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `ZeroBeneficiaryAddress` error if `beneficiary` is zero address.
+    ///
+    /// Returns with `ZeroPurchase` error if the transferred value is zero.
+    ///
+    /// Returns with `CapReached` error if the cap was already reached.
+    ///
+    /// Returns with `WalletLimitExceeded` error if the accepted value exceeds the limit of `beneficiary`.
+    #[ink(message, payable)]
+    fn buy_tokens(&mut self, beneficiary: AccountId) -> Result<(), PSP22CrowdsaleError>;
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::PSP22MerkleClaimError;
+use ink_prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP22MerkleClaimRef = dyn PSP22MerkleClaim;
+
+/// Distribution of the PSP22 tokens to the list of recipients committed with the merkle root,
+/// so the list itself is not stored on-chain
+#[openbrush::trait_definition]
+pub trait PSP22MerkleClaim {
+    /// Returns the address of the distributed token
+    #[ink(message)]
+    fn token(&self) -> AccountId;
+
+    /// Returns the merkle root of the distribution
+    #[ink(message)]
+    fn merkle_root(&self) -> [u8; 32];
+
+    /// Returns `true` if the leaf with `index` is already claimed
+    #[ink(message)]
+    fn is_claimed(&self, index: u32) -> bool;
+
+    /// Claims `amount` of tokens for `account` if the leaf `(index, account, amount)`
+    /// belongs to the merkle tree.
+    ///
+    /// On success a `Claimed` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `AlreadyClaimed` error if the leaf with `index` is already claimed.
+    ///
+    /// Returns with `InvalidProof` error if `proof` doesn't prove the leaf against the merkle root.
+    #[ink(message)]
+    fn claim(
+        &mut self,
+        index: u32,
+        account: AccountId,
+        amount: Balance,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), PSP22MerkleClaimError>;
+}
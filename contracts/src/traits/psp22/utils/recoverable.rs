@@ -0,0 +1,46 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Recovery of the PSP22 tokens sent to the contract by mistake
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP22RecoverableRef = dyn PSP22Recoverable;
+
+#[openbrush::trait_definition]
+pub trait PSP22Recoverable {
+    /// Transfers `amount` of the PSP22 `token` held by the contract to `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not the owner.
+    ///
+    /// Returns with `Custom` error if `token` is protected from the recovery,
+    /// by default it is the token of the contract itself.
+    ///
+    /// Returns with `ZeroRecipientAddress` error if `to` is zero address.
+    #[ink(message)]
+    fn recover_token(&mut self, token: AccountId, amount: Balance, to: AccountId) -> Result<(), PSP22Error>;
+}
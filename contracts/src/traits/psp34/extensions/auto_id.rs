@@ -0,0 +1,38 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP34`] that mints tokens with sequential ids
+pub use crate::traits::errors::PSP34Error;
+pub use crate::traits::psp34::Id;
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type PSP34AutoIdRef = dyn PSP34AutoId;
+
+#[openbrush::trait_definition]
+pub trait PSP34AutoId {
+    /// Mints a new token to `account` with the next free `Id::U64` id and returns that id.
+    ///
+    /// Ids are never reused, even if the token with that id was burned.
+    /// Ids which are already minted explicitly are skipped.
+    #[ink(message)]
+    fn mint_next(&mut self, account: AccountId) -> Result<Id, PSP34Error>;
+}
@@ -22,6 +22,7 @@
 /// Extension of [`PSP34`] that allows token holders to destroy their tokens
 pub use crate::traits::errors::PSP34Error;
 pub use crate::traits::psp34::Id;
+use ink_prelude::vec::Vec;
 use openbrush::traits::AccountId;
 
 #[openbrush::wrapper]
@@ -31,8 +32,29 @@ pub type PSP34BurnableRef = dyn PSP34Burnable;
 pub trait PSP34Burnable {
     /// Destroys token with id equal to `id` from `account`
     ///
-    /// Caller must be approved to transfer tokens from `account`
-    /// or to transfer token with `id`
+    /// Caller must be the owner of the token, approved to transfer tokens from `account`
+    /// or to transfer token with `id`. The approval of the caller for `id` is cleared after the burn.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if token does not exist.
+    ///
+    /// Returns `NotApproved` error if `account` is not the owner of the token
+    /// or the caller is not approved to burn the token.
     #[ink(message)]
     fn burn(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error>;
+
+    /// Destroys tokens with `ids` from `account`, the `Transfer` event is emitted for each id.
+    ///
+    /// Each token is checked the same way as in `burn` before burning,
+    /// so either all of them are burned or none.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if one of `ids` does not exist or is repeated in `ids`.
+    ///
+    /// Returns `NotApproved` error if `account` is not the owner of one of the tokens
+    /// or the caller is not approved to burn it.
+    #[ink(message)]
+    fn burn_batch(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error>;
 }
@@ -0,0 +1,62 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP34`] that allows minting tokens by redeeming the vouchers signed by the owner
+pub use crate::traits::errors::PSP34Error;
+pub use crate::traits::psp34::Id;
+use openbrush::traits::Balance;
+
+/// The permission to mint the token with `id` for `price`, signed off-chain by the owner of the collection.
+///
+/// The `signature` is the ecdsa signature of the voucher message hash,
+/// see `Internal::_voucher_message_hash` of the implementation.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Voucher {
+    pub id: Id,
+    pub price: Balance,
+    pub signature: [u8; 65],
+}
+
+#[openbrush::wrapper]
+pub type PSP34LazyMintRef = dyn PSP34LazyMint;
+
+#[openbrush::trait_definition]
+pub trait PSP34LazyMint {
+    /// Mints the token of `voucher` to the caller and forwards the price to the owner of the collection.
+    /// The value transferred above the price is refunded to the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoucherInvalidSignature` error if the voucher is not signed by the owner.
+    ///
+    /// Returns `VoucherAlreadyRedeemed` error if the voucher for the same id was redeemed before.
+    ///
+    /// Returns `VoucherInsufficientValue` error if the transferred value is below the price.
+    ///
+    /// Returns `TokenExists` error if the token with the id of the voucher already exists.
+    #[ink(message, payable)]
+    fn redeem(&mut self, voucher: Voucher) -> Result<(), PSP34Error>;
+
+    /// Returns `true` if the voucher for `id` was redeemed.
+    #[ink(message)]
+    fn is_redeemed(&self, id: Id) -> bool;
+}
@@ -34,4 +34,34 @@ pub trait PSP34Metadata {
     /// If `id` is a collection id of the token, it returns attributes for collection.
     #[ink(message)]
     fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>>;
+
+    /// Returns the number of attribute keys set for `id`.
+    #[ink(message)]
+    fn get_attribute_count(&self, id: Id) -> u32;
+
+    /// Returns the attribute key of `id` at the given `index`.
+    ///
+    /// Together with `get_attribute_count` it allows to enumerate all attributes of the token.
+    #[ink(message)]
+    fn get_attribute_name(&self, id: Id, index: u32) -> Option<Vec<u8>>;
+
+    /// Returns the URI of the token `id`: the base URI, followed by the representation
+    /// of `id` and the URI suffix (for example `.json`).
+    ///
+    /// Integer ids are represented as decimal numbers and `Id::Bytes` as lowercase hex.
+    /// Returns an empty URI if the base URI is not set.
+    #[ink(message)]
+    fn token_uri(&self, id: Id) -> Vec<u8>;
+
+    /// Returns the name of the whole collection, stored as the `name` attribute of the collection id.
+    ///
+    /// Returns an empty name if it is not set.
+    #[ink(message)]
+    fn collection_name(&self) -> Vec<u8>;
+
+    /// Returns the symbol of the whole collection, stored as the `symbol` attribute of the collection id.
+    ///
+    /// Returns an empty symbol if it is not set.
+    #[ink(message)]
+    fn collection_symbol(&self) -> Vec<u8>;
 }
@@ -22,6 +22,7 @@
 /// Extension of [`PSP34`] that exposes the mint function
 pub use crate::traits::errors::PSP34Error;
 pub use crate::traits::psp34::Id;
+use ink_prelude::vec::Vec;
 use openbrush::traits::AccountId;
 
 #[openbrush::wrapper]
@@ -34,4 +35,14 @@ pub trait PSP34Mintable {
     /// See [`PSP34::_mint_to`].
     #[ink(message)]
     fn mint(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error>;
+
+    /// Mints new tokens with `ids` to `account`, the `Transfer` event is emitted for each id.
+    ///
+    /// All `ids` are checked before minting, so either all of them are minted or none.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenExists` error if one of `ids` already exists or is repeated in `ids`.
+    #[ink(message)]
+    fn mint_batch(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error>;
 }
@@ -0,0 +1,41 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP34`] that allows to enumerate the distinct owners of the collection
+pub use crate::traits::errors::PSP34Error;
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type PSP34OwnersRef = dyn PSP34Owners;
+
+#[openbrush::trait_definition]
+pub trait PSP34Owners {
+    /// Returns the owner at `index` of the list of the accounts which own at least one token.
+    ///
+    /// The order of the owners changes when an owner loses its last token.
+    /// Returns `None` if `index` is out of bounds.
+    #[ink(message)]
+    fn owner_by_index(&self, index: u128) -> Option<AccountId>;
+
+    /// Returns the number of the accounts which own at least one token.
+    #[ink(message)]
+    fn owner_count(&self) -> u128;
+}
@@ -23,8 +23,17 @@ mod psp34;
 
 pub use psp34::*;
 pub mod extensions {
+    pub mod auto_id;
     pub mod burnable;
+    pub mod capped;
     pub mod enumerable;
+    pub mod lazy_mint;
     pub mod metadata;
     pub mod mintable;
+    pub mod owners;
+    pub mod royalty;
+}
+
+pub mod utils {
+    pub mod dutch_auction;
 }
@@ -0,0 +1,83 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    errors::PSP34DutchAuctionError,
+    psp34::Id,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type PSP34DutchAuctionRef = dyn PSP34DutchAuction;
+
+/// Sale of the PSP34 tokens for the native currency at the price linearly decreasing over time
+#[openbrush::trait_definition]
+pub trait PSP34DutchAuction {
+    /// Returns the address of the collection being sold
+    #[ink(message)]
+    fn token(&self) -> AccountId;
+
+    /// Returns the address where the funds of the sales are forwarded
+    #[ink(message)]
+    fn seller(&self) -> AccountId;
+
+    /// Returns the price at the start of the auction
+    #[ink(message)]
+    fn start_price(&self) -> Balance;
+
+    /// Returns the floor price, which is reached at the end of the auction
+    #[ink(message)]
+    fn end_price(&self) -> Balance;
+
+    /// Returns the time when the auction starts
+    #[ink(message)]
+    fn start_time(&self) -> Timestamp;
+
+    /// Returns the time in milliseconds during which the price decreases from the start price to the end price
+    #[ink(message)]
+    fn duration(&self) -> Timestamp;
+
+    /// Returns the price of the token at the current block.
+    ///
+    /// The price is the start price before the start of the auction and the end price after its end.
+    #[ink(message)]
+    fn current_price(&self) -> Balance;
+
+    /// Buys the token `id` owned by the auction at the current price and transfers it to the caller.
+    ///
+    /// The price is forwarded to the seller and the excess of the transferred value is refunded to the caller.
+    ///
+    /// On success a `TokenPurchased` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `AuctionNotStarted` error if the auction didn't start yet.
+    ///
+    /// Returns with `InsufficientValue` error if the transferred value is below the current price.
+    ///
+    /// Returns with `PSP34Error` error if the token can't be transferred to the caller.
+    #[ink(message, payable)]
+    fn buy(&mut self, id: Id) -> Result<(), PSP34DutchAuctionError>;
+}
@@ -35,6 +35,10 @@ pub type PSP37BatchRef = dyn PSP37Batch;
 
 #[openbrush::trait_definition]
 pub trait PSP37Batch {
+    /// Transfers the amounts of all ids in `ids_amounts` from the caller to `to`.
+    ///
+    /// The batch is applied all-or-nothing. It returns `InsufficientTokenBalance` error with the id
+    /// and its total amount if the caller doesn't hold the amount of any id, zero amounts are skipped.
     #[ink(message)]
     fn batch_transfer(
         &mut self,
@@ -43,6 +47,9 @@ pub trait PSP37Batch {
         data: Vec<u8>,
     ) -> Result<(), PSP37Error>;
 
+    /// Transfers the amounts of all ids in `ids_amounts` from `from` to `to` the same way as `batch_transfer`.
+    ///
+    /// Returns `NotAllowed` error if the caller is not allowed to transfer the amount of any id.
     #[ink(message)]
     fn batch_transfer_from(
         &mut self,
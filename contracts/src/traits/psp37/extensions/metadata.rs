@@ -30,4 +30,12 @@ pub type PSP37MetadataRef = dyn PSP37Metadata;
 pub trait PSP37Metadata {
     #[ink(message)]
     fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>>;
+
+    /// Returns the URI of the token `id`, in the ERC-1155 way.
+    ///
+    /// Each `{id}` placeholder of the base URI is substituted with the lowercase hex-encoded `id`.
+    /// Integer ids are encoded in big-endian and left-padded with zeros to 64 hex characters,
+    /// `Id::Bytes` is encoded as is.
+    #[ink(message)]
+    fn uri(&self, id: Id) -> Vec<u8>;
 }
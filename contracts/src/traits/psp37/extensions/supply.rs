@@ -0,0 +1,37 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP37`] that exposes the number of distinct tokens in circulation
+pub use crate::traits::errors::PSP37Error;
+pub use crate::traits::psp37::Id;
+
+#[openbrush::wrapper]
+pub type PSP37SupplyRef = dyn PSP37Supply;
+
+/// The supply of each token is available via [`PSP37::total_supply`] with `Some(id)`.
+#[openbrush::trait_definition]
+pub trait PSP37Supply {
+    /// Returns the number of distinct tokens which have a non-zero total supply.
+    ///
+    /// The token stops being counted when its whole supply is burned.
+    #[ink(message)]
+    fn total_token_count(&self) -> u128;
+}
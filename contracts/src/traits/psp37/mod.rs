@@ -25,7 +25,9 @@ pub use psp37::*;
 pub mod extensions {
     pub mod batch;
     pub mod burnable;
+    pub mod capped;
     pub mod enumerable;
     pub mod metadata;
     pub mod mintable;
+    pub mod supply;
 }
@@ -46,6 +46,12 @@ pub trait PSP37 {
     #[ink(message)]
     fn balance_of(&self, owner: AccountId, id: Option<Id>) -> Balance;
 
+    /// Returns the amounts of tokens for each `(account, id)` pair of `accounts_and_ids`.
+    ///
+    /// Balances are returned in the same order as the input pairs. Unknown ids have zero balance.
+    #[ink(message)]
+    fn balance_of_batch(&self, accounts_and_ids: Vec<(AccountId, Id)>) -> Vec<Balance>;
+
     /// Returns the total amount of token type `id` in the supply.
     ///
     /// If `id` is `None` returns the total number of tokens.
@@ -54,6 +60,10 @@ pub trait PSP37 {
 
     /// Returns amount of `id` token of `owner` that `operator` can withdraw
     /// If `id` is `None` returns allowance `Balance::MAX` of all tokens of `owner`
+    /// or zero if `operator` is not approved for all tokens
+    ///
+    /// The approval for all tokens takes precedence: while it exists, `Balance::MAX` is returned for any `id`.
+    /// The allowance for `id` is not changed by it and is returned again after the approval for all is revoked
     #[ink(message)]
     fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> Balance;
 
@@ -76,7 +86,7 @@ pub trait PSP37 {
     ///
     /// Returns `NotAllowed` error if transfer is not approved.
     ///
-    /// Returns `InsufficientBalance` error if `caller` doesn't contain enough balance.
+    /// Returns `InsufficientTokenBalance` error if `caller` doesn't contain enough balance.
     ///
     /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
     #[ink(message)]
@@ -92,7 +102,7 @@ pub trait PSP37 {
     ///
     /// Returns `NotAllowed` error if transfer is not approved.
     ///
-    /// Returns `InsufficientBalance` error if `from` doesn't contain enough balance.
+    /// Returns `InsufficientTokenBalance` error if `from` doesn't contain enough balance.
     ///
     /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
     #[ink(message)]
@@ -0,0 +1,51 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::EscrowError;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PullPaymentRef = dyn PullPayment;
+
+/// This mixin implements the withdrawal pattern: instead of sending the native tokens to the recipient,
+/// the contract credits them with `_async_transfer`, and the recipient withdraws them with `withdraw_payments`.
+///
+/// The payments computation is separated from the transfers, so a recipient which can't receive
+/// the funds doesn't block the payments of others. The payments are stored in the `escrow::Data`.
+#[openbrush::trait_definition]
+pub trait PullPayment {
+    /// Returns the amount of the native tokens owed to `dest`.
+    #[ink(message)]
+    fn payments(&self, dest: AccountId) -> Balance;
+
+    /// Transfers all payments owed to `payee` to `payee`.
+    ///
+    /// Anyone can call it, the funds only ever go to `payee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `TransferFailed` error if the transfer to `payee` fails. The payments stay owed in that case.
+    #[ink(message)]
+    fn withdraw_payments(&mut self, payee: AccountId) -> Result<(), EscrowError>;
+}
@@ -137,11 +137,13 @@ pub trait TimelockController: AccessControl {
         delay: Timestamp,
     ) -> Result<(), TimelockControllerError>;
 
-    /// Cancel an operation.
+    /// Cancel an operation which is scheduled but not executed yet, even if it is ready.
     ///
     /// On success a `Cancelled` event is emitted.
     ///
-    /// Note: the caller must have the 'PROPOSER_ROLE' role.
+    /// Returns `OperationCannonBeCanceled` error if the operation is unknown or already done.
+    ///
+    /// Note: the caller must have the 'CANCELLER_ROLE' role. The proposers get this role during the initialization.
     #[ink(message)]
     fn cancel(&mut self, id: OperationId) -> Result<(), TimelockControllerError>;
 
@@ -175,10 +177,12 @@ pub trait TimelockController: AccessControl {
     ///
     /// Emits a `MinDelayChange` event.
     ///
-    /// Note: The caller must has `TIMELOCK_ADMIN_ROLE` itself.
-    /// This can only be achieved by scheduling and later executing
+    /// Note: The caller must be the timelock itself, even the admin can't call it directly.
+    /// So the delay can only be changed by scheduling and later executing
     /// an operation where the timelock is the target and the data is the
-    /// ABI-encoded call to this function.
+    /// SCALE-encoded call to this function, and this operation waits for the current delay.
+    ///
+    /// Returns `CallerMustBeTimeLock` error if the caller is not the timelock.
     #[ink(message)]
     fn update_delay(&mut self, new_delay: Timestamp) -> Result<(), TimelockControllerError>;
 }
@@ -0,0 +1,90 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::VestingWalletError;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type VestingWalletRef = dyn VestingWallet;
+
+/// This contract handles the vesting of the native token and PSP22 tokens for a given beneficiary.
+/// Custody of multiple tokens can be given to this contract, which will release the tokens
+/// to the beneficiary following a given vesting schedule.
+///
+/// The default vesting schedule is linear: nothing is vested before `start`, everything is vested
+/// after `start + duration`, and the amount grows linearly in between.
+/// The schedule can be customized by overriding `_vesting_schedule`.
+///
+/// Any token transferred to this contract will follow the vesting schedule as if it was locked from the beginning.
+#[openbrush::trait_definition]
+pub trait VestingWallet {
+    /// Getter for the beneficiary address.
+    #[ink(message)]
+    fn beneficiary(&self) -> AccountId;
+
+    /// Getter for the start timestamp.
+    #[ink(message)]
+    fn start(&self) -> Timestamp;
+
+    /// Getter for the vesting duration.
+    #[ink(message)]
+    fn duration(&self) -> Timestamp;
+
+    /// Getter for the amount of native token already released.
+    #[ink(message)]
+    fn released(&self) -> Balance;
+
+    /// Getter for the amount of `token` already released.
+    #[ink(message)]
+    fn released_token(&self, token: AccountId) -> Balance;
+
+    /// Returns the amount of native token vested at `timestamp`.
+    #[ink(message)]
+    fn vested_amount(&self, timestamp: Timestamp) -> Balance;
+
+    /// Returns the amount of `token` vested at `timestamp`. `token` must be a PSP22 token.
+    #[ink(message)]
+    fn vested_amount_token(&self, token: AccountId, timestamp: Timestamp) -> Balance;
+
+    /// Getter for the amount of native token that can be released now.
+    #[ink(message)]
+    fn releasable(&self) -> Balance;
+
+    /// Getter for the amount of `token` that can be released now.
+    #[ink(message)]
+    fn releasable_token(&self, token: AccountId) -> Balance;
+
+    /// Transfers the native token that has already vested to the beneficiary.
+    ///
+    /// On success a `Released` event is emitted.
+    #[ink(message)]
+    fn release(&mut self) -> Result<(), VestingWalletError>;
+
+    /// Transfers `token` that has already vested to the beneficiary. `token` must be a PSP22 token.
+    ///
+    /// On success a `TokenReleased` event is emitted.
+    #[ink(message)]
+    fn release_token(&mut self, token: AccountId) -> Result<(), VestingWalletError>;
+}
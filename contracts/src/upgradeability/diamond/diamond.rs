@@ -37,6 +37,7 @@ use ink_env::{
         Selector as InkSelector,
     },
     Clear,
+    ReturnFlags,
 };
 use ink_prelude::vec::Vec;
 use ink_storage::traits::{
@@ -55,10 +56,15 @@ use openbrush::{
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
-// TODO: Add support of Erc165
+/// The storage of the diamond.
+///
+/// The data is stored under the `KEY` storage key. The default implementation of the traits works
+/// with the default `STORAGE_KEY`, a custom key allows keeping several diamond-like storages in one contract.
+/// Changing the key of the deployed contract makes the data stored under the previous key unreachable,
+/// so the data should be migrated to the new key during the upgrade.
 #[derive(Default, Debug)]
-#[openbrush::upgradeable_storage(STORAGE_KEY)]
-pub struct Data<D: DiamondCut = ()> {
+#[openbrush::upgradeable_storage(KEY)]
+pub struct Data<D: DiamondCut = (), const KEY: u32 = STORAGE_KEY> {
     // Selector mapped to its facet
     pub selector_to_hash: Mapping<Selector, Hash>,
     // Facet mapped to all functions it supports
@@ -66,6 +72,26 @@ pub struct Data<D: DiamondCut = ()> {
     // Handler of each facet add and remove.
     // It is empty by default but can be extended with loup logic.
     pub handler: D,
+    // Interfaces supported by the diamond
+    pub supported_interfaces: Mapping<InterfaceId, bool>,
+    // Facet mapped to all interfaces it implements
+    pub hash_to_interfaces: Mapping<Hash, Vec<InterfaceId>>,
+    // Interface mapped to the amount of facets which implement it
+    pub interface_facets_count: Mapping<InterfaceId, u32>,
+    // Selectors which can't be removed from the diamond by the diamond cut
+    pub protected_selectors: Mapping<Selector, bool>,
+    // The diamond can't be cut anymore if it is frozen
+    pub frozen: bool,
+    // Code hash of the diamond itself, selectors mapped to it are immutable
+    pub self_hash: Option<Hash>,
+    // Facets which were initialized by the init call of the diamond cut
+    pub initialized_facets: Mapping<Hash, bool>,
+    // Amount of selectors mapped to facets, the mappings can't be iterated to count them
+    pub selector_count: u32,
+    // Amount of facets with registered selectors
+    pub facet_count: u32,
+    // Facets whose functions can't be called through the fallback
+    pub paused_facets: Mapping<Hash, bool>,
 }
 
 impl<D, T> Diamond for T
@@ -79,22 +105,220 @@ where
     default fn diamond_cut(&mut self, diamond_cut: Vec<FacetCut>, init: Option<InitCall>) -> Result<(), DiamondError> {
         self._diamond_cut(diamond_cut, init)
     }
+
+    #[modifiers(ownable::only_owner)]
+    default fn diamond_cut_with_actions(
+        &mut self,
+        cuts: Vec<FacetCutWithAction>,
+        init: Option<InitCall>,
+    ) -> Result<(), DiamondError> {
+        let diamond_cut = self._facet_cuts_from_actions(&cuts)?;
+        self._diamond_cut(diamond_cut, init)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn diamond_cut_override(
+        &mut self,
+        cuts: Vec<FacetCut>,
+        init: Option<InitCall>,
+    ) -> Result<(), DiamondError> {
+        let diamond_cut = self._facet_cuts_with_override(&cuts)?;
+        self._diamond_cut(diamond_cut, init)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn replace_facet(&mut self, old_hash: Hash, new_hash: Hash) -> Result<(), DiamondError> {
+        self._replace_facet(old_hash, new_hash)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn diamond_cut_and_freeze(
+        &mut self,
+        diamond_cut: Vec<FacetCut>,
+        init: Option<InitCall>,
+    ) -> Result<(), DiamondError> {
+        self._apply_diamond_cut(&diamond_cut, &init)?;
+        // the diamond is frozen before the init call, so the init facet can't cut it anymore
+        self._freeze();
+
+        if init.is_some() {
+            self.flush();
+            self._init_call(init.unwrap());
+        }
+
+        Ok(())
+    }
+
+    default fn supports_interface(&self, interface_id: InterfaceId) -> bool {
+        self.data::<Data<D>>()
+            .supported_interfaces
+            .get(&interface_id)
+            .unwrap_or(false)
+    }
+
+    default fn facet_for_selector(&self, selector: Selector) -> Option<Hash> {
+        self.data::<Data<D>>().selector_to_hash.get(&selector)
+    }
+
+    default fn is_immutable(&self, selector: Selector) -> bool {
+        match self.data::<Data<D>>().self_hash {
+            Some(self_hash) => self.facet_for_selector(selector) == Some(self_hash),
+            None => false,
+        }
+    }
+
+    default fn is_facet_initialized(&self, hash: Hash) -> bool {
+        self._is_facet_initialized(&hash)
+    }
+
+    default fn total_selectors(&self) -> u32 {
+        self.data::<Data<D>>().selector_count
+    }
+
+    default fn total_facets(&self) -> u32 {
+        self.data::<Data<D>>().facet_count
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn pause_facet(&mut self, hash: Hash) -> Result<(), DiamondError> {
+        self._pause_facet(hash)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    default fn unpause_facet(&mut self, hash: Hash) -> Result<(), DiamondError> {
+        self._unpause_facet(hash);
+        Ok(())
+    }
+
+    default fn is_facet_paused(&self, hash: Hash) -> bool {
+        self._is_facet_paused(&hash)
+    }
 }
 
 pub trait Internal {
     fn _emit_diamond_cut_event(&self, diamond_cut: &Vec<FacetCut>, init: &Option<InitCall>);
 
+    /// Emitted for each facet changed during the diamond cut, so indexers can reconstruct the state.
+    fn _emit_facet_cut_event(&self, code_hash: Hash, selectors: &Vec<Selector>, action: FacetCutAction);
+
+    /// Emitted right before the init call with `hash` and `selector` is fired.
+    /// The init call is a tail call, so nothing can be emitted after it.
+    fn _emit_diamond_init_executed_event(&self, hash: Hash, selector: Selector);
+
     fn _diamond_cut(&mut self, diamond_cut: Vec<FacetCut>, init: Option<InitCall>) -> Result<(), DiamondError>;
 
+    /// Validates and applies all facet cuts of `diamond_cut` without the init call.
+    /// The facet of `init` is marked as initialized and the init event is emitted, so the init call must follow.
+    ///
+    /// Returns `DiamondFrozen` error if the diamond is frozen.
+    ///
+    /// Returns `FacetAlreadyInitialized` error if the facet of `init` was already initialized
+    /// and the init call is not forced.
+    ///
+    /// Returns `InitHashNotRegistered` error if the hash of `init` is not registered after the cut
+    /// and the init call is not external.
+    fn _apply_diamond_cut(&mut self, diamond_cut: &Vec<FacetCut>, init: &Option<InitCall>) -> Result<(), DiamondError>;
+
+    /// Freezes the diamond, so it can't be cut anymore. There is no way to unfreeze it.
+    fn _freeze(&mut self);
+
+    /// Converts the facet cuts with explicit actions into the facet cuts accepted by `_diamond_cut`.
+    ///
+    /// The selectors are removed from the facets first and added after that,
+    /// so they can be moved between facets within one `_diamond_cut`.
+    fn _facet_cuts_from_actions(&self, cuts: &Vec<FacetCutWithAction>) -> Result<Vec<FacetCut>, DiamondError>;
+
+    /// Converts the facet cuts, which may take the selectors registered for other facets,
+    /// into the facet cuts accepted by `_diamond_cut`.
+    fn _facet_cuts_with_override(&self, cuts: &Vec<FacetCut>) -> Result<Vec<FacetCut>, DiamondError>;
+
+    /// Moves all selectors and interfaces of the facet with `old_hash` to the facet with `new_hash`
+    /// and removes the old facet. The selectors already registered for the new facet are kept.
+    ///
+    /// Returns `FunctionDoesNotExist` error if the old facet is not registered.
+    ///
+    /// Returns `ReplaceSameFacet` error if `old_hash` is equal to `new_hash`.
+    fn _replace_facet(&mut self, old_hash: Hash, new_hash: Hash) -> Result<(), DiamondError>;
+
     fn _diamond_cut_facet(&mut self, facet_cut: &FacetCut) -> Result<(), DiamondError>;
 
+    /// Checks that `diamond_cut` can be applied to the current state of the diamond.
+    /// It runs the same checks as `_diamond_cut`, but doesn't modify the storage and doesn't emit events.
+    fn _validate_diamond_cut(&self, diamond_cut: &Vec<FacetCut>) -> Result<(), DiamondError>;
+
+    /// Delegates the call to the facet registered for the selector of the call.
+    ///
+    /// The delegate call is a tail call, so the output of the facet (including the values
+    /// returned by getters) is forwarded to the caller of the diamond as is.
+    ///
+    /// If the selector can't be decoded or is not registered, the call is reverted
+    /// with `CalldataError` or `FunctionNotRegistered` error via `_revert`.
     fn _fallback(&self) -> !;
 
+    /// Returns the code hash of the facet registered for `selector`, the fallback delegates the call to it.
+    ///
+    /// Returns `FunctionNotRegistered` error if `selector` is not registered
+    /// and `FacetPaused` error if the facet is paused.
+    fn _facet_code_hash(&self, selector: &Selector) -> Result<Hash, DiamondError>;
+
+    /// Reverts the call with `error`, encoded as `Result::Err`,
+    /// so callers can decode it as the error of any `Result<_, DiamondError>`.
+    fn _revert(&self, error: DiamondError) -> !;
+
     fn _init_call(&self, call: InitCall) -> !;
 
     fn _remove_facet(&mut self, code_hash: Hash);
 
     fn _remove_selectors(&mut self, facet_cut: &FacetCut);
+
+    /// Unmaps `selector` from its facet and decreases the selector counter if it was mapped.
+    fn _unmap_selector(&mut self, selector: &Selector);
+
+    /// Marks `interface_id` as implemented by the facet with `code_hash`.
+    fn _register_interface(&mut self, code_hash: Hash, interface_id: InterfaceId);
+
+    /// Unmarks `interface_id` as implemented by the facet with `code_hash`.
+    /// The interface stays supported while at least one other facet implements it.
+    fn _deregister_interface(&mut self, code_hash: Hash, interface_id: InterfaceId);
+
+    /// Protects `selector` from being removed, so the diamond cut fails if it would leave `selector` unmapped.
+    fn _protect_selector(&mut self, selector: Selector);
+
+    /// Allows `selector` to be removed by the diamond cut again.
+    fn _unprotect_selector(&mut self, selector: Selector);
+
+    fn _is_protected_selector(&self, selector: &Selector) -> bool;
+
+    /// Sets the code hash of the diamond itself. Selectors mapped to this hash are immutable.
+    fn _set_self_hash(&mut self, self_hash: Hash);
+
+    /// Maps `selectors` to the code hash of the diamond itself, so they are immutable.
+    /// Use it to register the functions implemented by the diamond directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `EmptyCodeHash` if the code hash of the diamond itself is not set.
+    /// Returns with `ReplaceExisting` if the selector is already mapped to another facet.
+    fn _add_immutable_functions(&mut self, selectors: Vec<Selector>) -> Result<(), DiamondError>;
+
+    fn _is_facet_initialized(&self, code_hash: &Hash) -> bool;
+
+    /// Pauses the facet with `code_hash`, so the fallback doesn't delegate the calls to it.
+    ///
+    /// Returns `FunctionDoesNotExist` error if the facet is not registered.
+    fn _pause_facet(&mut self, code_hash: Hash) -> Result<(), DiamondError>;
+
+    /// Unpauses the facet with `code_hash`.
+    fn _unpause_facet(&mut self, code_hash: Hash);
+
+    fn _is_facet_paused(&self, code_hash: &Hash) -> bool;
+
+    /// Marks the facet with `code_hash` as initialized.
+    fn _set_facet_initialized(&mut self, code_hash: Hash);
+
+    /// Returns `true` if `code_hash` is the code hash of the diamond itself
+    /// or the facet which stays registered after `diamond_cut` is applied.
+    fn _is_registered_after_cut(&self, code_hash: &Hash, diamond_cut: &Vec<FacetCut>) -> bool;
 }
 
 impl<D, T> Internal for T
@@ -105,14 +329,18 @@ where
 {
     default fn _emit_diamond_cut_event(&self, _diamond_cut: &Vec<FacetCut>, _init: &Option<InitCall>) {}
 
-    default fn _diamond_cut(&mut self, diamond_cut: Vec<FacetCut>, init: Option<InitCall>) -> Result<(), DiamondError> {
-        for facet_cut in diamond_cut.iter() {
-            self._diamond_cut_facet(facet_cut)?;
-        }
+    default fn _emit_facet_cut_event(&self, _code_hash: Hash, _selectors: &Vec<Selector>, _action: FacetCutAction) {}
 
-        self._emit_diamond_cut_event(&diamond_cut, &init);
+    default fn _emit_diamond_init_executed_event(&self, _hash: Hash, _selector: Selector) {}
+
+    default fn _diamond_cut(&mut self, diamond_cut: Vec<FacetCut>, init: Option<InitCall>) -> Result<(), DiamondError> {
+        self._apply_diamond_cut(&diamond_cut, &init)?;
 
         if init.is_some() {
+            // All facets are applied and flushed before the init call, and the init call is a tail call,
+            // so the outer call can't observe any changes made by a reentrant `diamond_cut` from the init facet.
+            // That is why `diamond_cut` is not guarded by `reentrancy_guard::non_reentrant`:
+            // the guard would never be released after the tail call.
             self.flush();
             self._init_call(init.unwrap());
         }
@@ -120,6 +348,194 @@ where
         Ok(())
     }
 
+    default fn _apply_diamond_cut(
+        &mut self,
+        diamond_cut: &Vec<FacetCut>,
+        init: &Option<InitCall>,
+    ) -> Result<(), DiamondError> {
+        if self.data().frozen {
+            return Err(DiamondError::DiamondFrozen)
+        }
+        if let Some(call) = init {
+            if !call.force && self._is_facet_initialized(&call.hash) {
+                return Err(DiamondError::FacetAlreadyInitialized(call.hash))
+            }
+            if !call.external && !self._is_registered_after_cut(&call.hash, diamond_cut) {
+                return Err(DiamondError::InitHashNotRegistered(call.hash))
+            }
+        }
+
+        // validate the whole cut first, so it is applied all-or-nothing
+        self._validate_diamond_cut(diamond_cut)?;
+
+        for facet_cut in diamond_cut.iter() {
+            self._diamond_cut_facet(facet_cut)?;
+        }
+        if let Some(call) = init {
+            self._set_facet_initialized(call.hash);
+        }
+
+        self._emit_diamond_cut_event(diamond_cut, init);
+        // the init call follows the cut immediately, so it is recorded here
+        if let Some(call) = init {
+            self._emit_diamond_init_executed_event(call.hash, call.selector);
+        }
+        Ok(())
+    }
+
+    default fn _freeze(&mut self) {
+        self.data().frozen = true;
+    }
+
+    default fn _facet_cuts_from_actions(&self, cuts: &Vec<FacetCutWithAction>) -> Result<Vec<FacetCut>, DiamondError> {
+        // changes which the previous cuts would apply to the storage
+        let mut selectors_overlay: Vec<(Selector, Option<Hash>)> = Vec::new();
+        // the registered and the resulting selectors of each affected facet
+        let mut facets: Vec<(Hash, Vec<Selector>, Vec<Selector>)> = Vec::new();
+
+        for cut in cuts.iter() {
+            if cut.action != FacetCutAction::Remove && cut.hash.is_clear() {
+                return Err(DiamondError::EmptyCodeHash)
+            }
+
+            for selector in cut.selectors.iter() {
+                let selector_hash = match selectors_overlay.iter().find(|(key, _)| key == selector) {
+                    Some((_, hash)) => *hash,
+                    None => self.data().selector_to_hash.get(selector),
+                };
+
+                let (old_hash, new_hash) = match (cut.action, selector_hash) {
+                    (FacetCutAction::Add, Some(hash)) => return Err(DiamondError::ReplaceExisting(hash)),
+                    (FacetCutAction::Add, None) => (None, Some(cut.hash)),
+                    (FacetCutAction::Replace, Some(hash)) if hash == cut.hash => {
+                        return Err(DiamondError::ReplaceSameFacet)
+                    }
+                    (FacetCutAction::Replace, Some(hash)) => (Some(hash), Some(cut.hash)),
+                    (FacetCutAction::Remove, Some(hash)) => (Some(hash), None),
+                    (_, None) => return Err(DiamondError::FunctionDoesNotExist),
+                };
+
+                if let Some(hash) = old_hash {
+                    let index = facet_index(self.data(), &mut facets, hash);
+                    facets[index].2.retain(|registered| registered != selector);
+                }
+                if let Some(hash) = new_hash {
+                    let index = facet_index(self.data(), &mut facets, hash);
+                    facets[index].2.push(*selector);
+                }
+                overlay_insert(&mut selectors_overlay, *selector, new_hash);
+            }
+        }
+
+        Ok(facet_cuts_from_changes(&facets))
+    }
+
+    default fn _facet_cuts_with_override(&self, cuts: &Vec<FacetCut>) -> Result<Vec<FacetCut>, DiamondError> {
+        // changes which the previous cuts would apply to the storage
+        let mut selectors_overlay: Vec<(Selector, Option<Hash>)> = Vec::new();
+        // the registered and the resulting selectors of each affected facet
+        let mut facets: Vec<(Hash, Vec<Selector>, Vec<Selector>)> = Vec::new();
+
+        for cut in cuts.iter() {
+            if cut.hash.is_clear() {
+                return Err(DiamondError::EmptyCodeHash)
+            }
+
+            let index = facet_index(self.data(), &mut facets, cut.hash);
+            if cut.selectors.is_empty() && facets[index].2.is_empty() {
+                return Err(DiamondError::FunctionDoesNotExist)
+            }
+            // selectors which are not in the cut anymore are unmapped
+            for selector in facets[index].2.clone().iter() {
+                if !cut.selectors.contains(selector) {
+                    overlay_insert(&mut selectors_overlay, *selector, None);
+                }
+            }
+
+            for selector in cut.selectors.iter() {
+                let selector_hash = match selectors_overlay.iter().find(|(key, _)| key == selector) {
+                    Some((_, hash)) => *hash,
+                    None => self.data().selector_to_hash.get(selector),
+                };
+
+                if let Some(hash) = selector_hash.filter(|hash| hash != &cut.hash) {
+                    let previous = facet_index(self.data(), &mut facets, hash);
+                    facets[previous].2.retain(|registered| registered != selector);
+                }
+                overlay_insert(&mut selectors_overlay, *selector, Some(cut.hash));
+            }
+
+            let index = facet_index(self.data(), &mut facets, cut.hash);
+            facets[index].2 = cut.selectors.clone();
+        }
+
+        let mut diamond_cut = facet_cuts_from_changes(&facets);
+        // the interfaces are registered by the last cut of their facet, if the facet stays in the diamond
+        for cut in cuts.iter().filter(|cut| !cut.interfaces.is_empty()) {
+            let index = facet_index(self.data(), &mut facets, cut.hash);
+            let selectors = facets[index].2.clone();
+            if selectors.is_empty() {
+                continue
+            }
+            match diamond_cut
+                .iter_mut()
+                .rev()
+                .find(|facet_cut| facet_cut.hash == cut.hash && !facet_cut.selectors.is_empty())
+            {
+                Some(facet_cut) => facet_cut.interfaces.extend(cut.interfaces.iter().cloned()),
+                None => {
+                    diamond_cut.push(FacetCut {
+                        hash: cut.hash,
+                        selectors,
+                        interfaces: cut.interfaces.clone(),
+                    })
+                }
+            }
+        }
+        Ok(diamond_cut)
+    }
+
+    default fn _replace_facet(&mut self, old_hash: Hash, new_hash: Hash) -> Result<(), DiamondError> {
+        if old_hash == new_hash {
+            return Err(DiamondError::ReplaceSameFacet)
+        }
+        let selectors = self
+            .data()
+            .hash_to_selectors
+            .get(&old_hash)
+            .ok_or(DiamondError::FunctionDoesNotExist)?;
+        let mut new_selectors = self
+            .data()
+            .hash_to_selectors
+            .get(&new_hash)
+            .unwrap_or(Vec::<Selector>::new());
+        for selector in selectors.into_iter() {
+            if !new_selectors.contains(&selector) {
+                new_selectors.push(selector);
+            }
+        }
+        let interfaces = self
+            .data()
+            .hash_to_interfaces
+            .get(&old_hash)
+            .unwrap_or(Vec::<InterfaceId>::new());
+
+        // the old facet is removed first, so its selectors are free for the new one
+        let diamond_cut = vec![
+            FacetCut {
+                hash: old_hash,
+                selectors: Vec::new(),
+                interfaces: Vec::new(),
+            },
+            FacetCut {
+                hash: new_hash,
+                selectors: new_selectors,
+                interfaces,
+            },
+        ];
+        self._apply_diamond_cut(&diamond_cut, &None)
+    }
+
     default fn _diamond_cut_facet(&mut self, facet_cut: &FacetCut) -> Result<(), DiamondError> {
         let code_hash = facet_cut.hash;
         if code_hash.is_clear() {
@@ -127,7 +543,13 @@ where
         }
         if facet_cut.selectors.is_empty() {
             // means that we want to remove this facet
+            let selectors = self
+                .data()
+                .hash_to_selectors
+                .get(&code_hash)
+                .unwrap_or(Vec::<Selector>::new());
             self._remove_facet(code_hash);
+            self._emit_facet_cut_event(code_hash, &selectors, FacetCutAction::Remove);
         } else {
             for selector in facet_cut.selectors.iter() {
                 let selector_hash = self.data().selector_to_hash.get(&selector);
@@ -141,31 +563,108 @@ where
                 } else {
                     // map selector to its facet
                     self.data().selector_to_hash.insert(&selector, &code_hash);
+                    self.data().selector_count += 1;
                 }
             }
 
-            if self.data().hash_to_selectors.get(&code_hash).is_none() {
+            let action = if self.data().hash_to_selectors.get(&code_hash).is_none() {
                 self.data().handler.on_add_facet(code_hash);
-            }
+                self.data().facet_count += 1;
+                FacetCutAction::Add
+            } else {
+                FacetCutAction::Replace
+            };
             // remove selectors from this facet which may be registered but will not be used anymore
             self._remove_selectors(facet_cut);
             // map this code hash to its selectors
             self.data().hash_to_selectors.insert(&code_hash, &facet_cut.selectors);
+            for interface_id in facet_cut.interfaces.iter() {
+                self._register_interface(code_hash, *interface_id);
+            }
+            self._emit_facet_cut_event(code_hash, &facet_cut.selectors, action);
         }
         Ok(())
     }
 
-    default fn _fallback(&self) -> ! {
-        let selector = ink_env::decode_input::<Selector>().unwrap_or_else(|_| panic!("Calldata error"));
+    default fn _validate_diamond_cut(&self, diamond_cut: &Vec<FacetCut>) -> Result<(), DiamondError> {
+        // changes which the previous facet cuts of `diamond_cut` would apply to the storage
+        let mut selectors_overlay: Vec<(Selector, Option<Hash>)> = Vec::new();
+        let mut facets_overlay: Vec<(Hash, Vec<Selector>)> = Vec::new();
 
-        let delegate_code = self.data().selector_to_hash.get(&selector);
+        for facet_cut in diamond_cut.iter() {
+            let code_hash = facet_cut.hash;
+            if code_hash.is_clear() {
+                return Err(DiamondError::EmptyCodeHash)
+            }
 
-        if delegate_code.is_none() {
-            panic!("Function is not registered");
+            let registered_selectors = match facets_overlay.iter().find(|(hash, _)| hash == &code_hash) {
+                Some((_, selectors)) => selectors.clone(),
+                None => {
+                    self.data()
+                        .hash_to_selectors
+                        .get(&code_hash)
+                        .unwrap_or(Vec::<Selector>::new())
+                }
+            };
+
+            if facet_cut.selectors.is_empty() {
+                if registered_selectors.is_empty() {
+                    return Err(DiamondError::FunctionDoesNotExist)
+                }
+                for selector in registered_selectors.iter() {
+                    overlay_insert(&mut selectors_overlay, *selector, None);
+                }
+            } else {
+                for selector in facet_cut.selectors.iter() {
+                    let selector_hash = match selectors_overlay.iter().find(|(key, _)| key == selector) {
+                        Some((_, hash)) => *hash,
+                        None => self.data().selector_to_hash.get(selector),
+                    };
+
+                    match selector_hash {
+                        Some(hash) if hash == code_hash => continue,
+                        Some(hash) => return Err(DiamondError::ReplaceExisting(hash)),
+                        None => overlay_insert(&mut selectors_overlay, *selector, Some(code_hash)),
+                    }
+                }
+                for selector in registered_selectors.iter() {
+                    if !facet_cut.selectors.contains(selector) {
+                        overlay_insert(&mut selectors_overlay, *selector, None);
+                    }
+                }
+            }
+            overlay_insert(&mut facets_overlay, code_hash, facet_cut.selectors.clone());
+        }
+
+        // protected selectors can be moved between facets, but they must stay mapped after the whole cut
+        for (selector, hash) in selectors_overlay.iter() {
+            if hash.is_none() && self._is_protected_selector(selector) {
+                return Err(DiamondError::RemoveProtectedSelector(*selector))
+            }
         }
+        // selectors of the diamond itself can't be moved or removed
+        if let Some(self_hash) = self.data().self_hash {
+            for (selector, hash) in selectors_overlay.iter() {
+                if hash != &Some(self_hash) && self.data().selector_to_hash.get(selector) == Some(self_hash) {
+                    return Err(DiamondError::ImmutableFunction(*selector))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    default fn _fallback(&self) -> ! {
+        let delegate_code = match ink_env::decode_input::<Selector>()
+            .map_err(|_| DiamondError::CalldataError)
+            .and_then(|selector| self._facet_code_hash(&selector))
+        {
+            Ok(delegate_code) => delegate_code,
+            Err(error) => self._revert(error),
+        };
 
+        // The delegate call can't be limited by gas, it gets all gas left to the diamond.
         ink_env::call::build_call::<ink_env::DefaultEnvironment>()
-            .call_type(DelegateCall::new().code_hash(delegate_code.unwrap()))
+            .call_type(DelegateCall::new().code_hash(delegate_code))
             .call_flags(
                 ink_env::CallFlags::default()
                 // We don't plan to use the input data after the delegated call, so the 
@@ -180,6 +679,22 @@ where
         unreachable!("the _fallback call will never return since `tail_call` was set");
     }
 
+    default fn _facet_code_hash(&self, selector: &Selector) -> Result<Hash, DiamondError> {
+        let code_hash = self
+            .data()
+            .selector_to_hash
+            .get(selector)
+            .ok_or(DiamondError::FunctionNotRegistered(*selector))?;
+        if self._is_facet_paused(&code_hash) {
+            return Err(DiamondError::FacetPaused(code_hash))
+        }
+        Ok(code_hash)
+    }
+
+    default fn _revert(&self, error: DiamondError) -> ! {
+        ink_env::return_value::<Result<(), DiamondError>>(ReturnFlags::default().set_reverted(true), &Err(error))
+    }
+
     default fn _init_call(&self, call: InitCall) -> ! {
         ink_env::call::build_call::<ink_env::DefaultEnvironment>()
             .call_type(DelegateCall::new().code_hash(call.hash))
@@ -197,9 +712,20 @@ where
     default fn _remove_facet(&mut self, code_hash: Hash) {
         let vec = self.data().hash_to_selectors.get(&code_hash).unwrap();
         vec.iter().for_each(|old_selector| {
-            self.data().selector_to_hash.remove(&old_selector);
+            self._unmap_selector(old_selector);
         });
         self.data().hash_to_selectors.remove(&code_hash);
+        self.data().facet_count = self.data().facet_count.saturating_sub(1);
+        // the facet added again later starts unpaused
+        self._unpause_facet(code_hash);
+        let interfaces = self
+            .data()
+            .hash_to_interfaces
+            .get(&code_hash)
+            .unwrap_or(Vec::<InterfaceId>::new());
+        interfaces.iter().for_each(|interface_id| {
+            self._deregister_interface(code_hash, *interface_id);
+        });
         self.data().handler.on_remove_facet(code_hash);
     }
 
@@ -211,9 +737,199 @@ where
             .unwrap_or(Vec::<Selector>::new());
         for selector in selectors.iter() {
             if !facet_cut.selectors.contains(&selector) {
-                self.data().selector_to_hash.remove(&selector);
+                self._unmap_selector(selector);
+            }
+        }
+    }
+
+    default fn _unmap_selector(&mut self, selector: &Selector) {
+        // the registered selectors may contain duplicates, so only the mapped ones are counted
+        if self.data().selector_to_hash.get(selector).is_some() {
+            self.data().selector_to_hash.remove(selector);
+            self.data().selector_count = self.data().selector_count.saturating_sub(1);
+        }
+    }
+
+    default fn _register_interface(&mut self, code_hash: Hash, interface_id: InterfaceId) {
+        let mut interfaces = self
+            .data()
+            .hash_to_interfaces
+            .get(&code_hash)
+            .unwrap_or(Vec::<InterfaceId>::new());
+        if interfaces.contains(&interface_id) {
+            return
+        }
+        interfaces.push(interface_id);
+        self.data().hash_to_interfaces.insert(&code_hash, &interfaces);
+
+        let count = self.data().interface_facets_count.get(&interface_id).unwrap_or(0);
+        self.data().interface_facets_count.insert(&interface_id, &(count + 1));
+        self.data().supported_interfaces.insert(&interface_id, &true);
+    }
+
+    default fn _deregister_interface(&mut self, code_hash: Hash, interface_id: InterfaceId) {
+        let mut interfaces = self
+            .data()
+            .hash_to_interfaces
+            .get(&code_hash)
+            .unwrap_or(Vec::<InterfaceId>::new());
+        if !interfaces.contains(&interface_id) {
+            return
+        }
+        interfaces.retain(|id| id != &interface_id);
+        if interfaces.is_empty() {
+            self.data().hash_to_interfaces.remove(&code_hash);
+        } else {
+            self.data().hash_to_interfaces.insert(&code_hash, &interfaces);
+        }
+
+        let count = self.data().interface_facets_count.get(&interface_id).unwrap_or(0);
+        if count <= 1 {
+            self.data().interface_facets_count.remove(&interface_id);
+            self.data().supported_interfaces.remove(&interface_id);
+        } else {
+            self.data().interface_facets_count.insert(&interface_id, &(count - 1));
+        }
+    }
+
+    default fn _protect_selector(&mut self, selector: Selector) {
+        self.data().protected_selectors.insert(&selector, &true);
+    }
+
+    default fn _unprotect_selector(&mut self, selector: Selector) {
+        self.data().protected_selectors.remove(&selector);
+    }
+
+    default fn _is_protected_selector(&self, selector: &Selector) -> bool {
+        self.data().protected_selectors.get(selector).unwrap_or(false)
+    }
+
+    default fn _set_self_hash(&mut self, self_hash: Hash) {
+        self.data().self_hash = Some(self_hash);
+    }
+
+    default fn _add_immutable_functions(&mut self, selectors: Vec<Selector>) -> Result<(), DiamondError> {
+        let self_hash = self.data().self_hash.ok_or(DiamondError::EmptyCodeHash)?;
+        let mut immutable = self
+            .data()
+            .hash_to_selectors
+            .get(&self_hash)
+            .unwrap_or(Vec::<Selector>::new());
+        for selector in selectors.into_iter() {
+            if !immutable.contains(&selector) {
+                immutable.push(selector);
             }
         }
+        let facet_cut = FacetCut {
+            hash: self_hash,
+            selectors: immutable,
+            interfaces: Vec::new(),
+        };
+
+        self._validate_diamond_cut(&vec![facet_cut.clone()])?;
+        self._diamond_cut_facet(&facet_cut)
+    }
+
+    default fn _is_facet_initialized(&self, code_hash: &Hash) -> bool {
+        self.data().initialized_facets.get(code_hash).unwrap_or(false)
+    }
+
+    default fn _set_facet_initialized(&mut self, code_hash: Hash) {
+        self.data().initialized_facets.insert(&code_hash, &true);
+    }
+
+    default fn _pause_facet(&mut self, code_hash: Hash) -> Result<(), DiamondError> {
+        if self.data().hash_to_selectors.get(&code_hash).is_none() {
+            return Err(DiamondError::FunctionDoesNotExist)
+        }
+        self.data().paused_facets.insert(&code_hash, &true);
+        Ok(())
+    }
+
+    default fn _unpause_facet(&mut self, code_hash: Hash) {
+        self.data().paused_facets.remove(&code_hash);
+    }
+
+    default fn _is_facet_paused(&self, code_hash: &Hash) -> bool {
+        self.data().paused_facets.get(code_hash).unwrap_or(false)
+    }
+
+    default fn _is_registered_after_cut(&self, code_hash: &Hash, diamond_cut: &Vec<FacetCut>) -> bool {
+        if self.data().self_hash == Some(*code_hash) {
+            return true
+        }
+        // the last cut of the facet defines whether it stays in the diamond, an empty cut removes it
+        match diamond_cut.iter().rev().find(|facet_cut| &facet_cut.hash == code_hash) {
+            Some(facet_cut) => !facet_cut.selectors.is_empty(),
+            None => self.data().hash_to_selectors.get(code_hash).is_some(),
+        }
+    }
+}
+
+/// Returns the facet cuts which change the registered selectors of `facets` to the resulting ones.
+fn facet_cuts_from_changes(facets: &Vec<(Hash, Vec<Selector>, Vec<Selector>)>) -> Vec<FacetCut> {
+    let mut diamond_cut = Vec::new();
+    // the selectors of each facet after the first step
+    let mut intermediate: Vec<Vec<Selector>> = Vec::new();
+    // remove the selectors first, so they can be registered for other facets
+    for (hash, registered, selectors) in facets.iter() {
+        let mut kept: Vec<Selector> = registered
+            .iter()
+            .filter(|selector| selectors.contains(selector))
+            .cloned()
+            .collect();
+        if kept.is_empty() && !registered.is_empty() && !selectors.is_empty() {
+            // an empty list of selectors removes the whole facet with its interfaces,
+            // so the facet which stays is replaced with the selectors which are not registered yet.
+            // It is removed and added again only if all its new selectors are taken from other facets.
+            kept = selectors
+                .iter()
+                .filter(|selector| !facets.iter().any(|(_, registered, _)| registered.contains(selector)))
+                .cloned()
+                .collect();
+        }
+        if kept != *registered {
+            diamond_cut.push(FacetCut {
+                hash: *hash,
+                selectors: kept.clone(),
+                interfaces: Vec::new(),
+            });
+        }
+        intermediate.push(kept);
+    }
+    // and register the new ones after that
+    for ((hash, _, selectors), kept) in facets.iter().zip(intermediate.iter()) {
+        if selectors.iter().any(|selector| !kept.contains(selector)) {
+            diamond_cut.push(FacetCut {
+                hash: *hash,
+                selectors: selectors.clone(),
+                interfaces: Vec::new(),
+            });
+        }
+    }
+    diamond_cut
+}
+
+/// Returns the index of the facet with `hash` in `facets`, the facet is added with its registered selectors if it is absent.
+fn facet_index<D: DiamondCut>(
+    data: &Data<D>,
+    facets: &mut Vec<(Hash, Vec<Selector>, Vec<Selector>)>,
+    hash: Hash,
+) -> usize {
+    match facets.iter().position(|(facet, _, _)| facet == &hash) {
+        Some(index) => index,
+        None => {
+            let registered = data.hash_to_selectors.get(&hash).unwrap_or(Vec::<Selector>::new());
+            facets.push((hash, registered.clone(), registered));
+            facets.len() - 1
+        }
+    }
+}
+
+fn overlay_insert<K: PartialEq, V>(overlay: &mut Vec<(K, V)>, key: K, value: V) {
+    match overlay.iter_mut().find(|(k, _)| k == &key) {
+        Some(entry) => entry.1 = value,
+        None => overlay.push((key, value)),
     }
 }
 
@@ -91,7 +91,16 @@ impl<T: Storage<diamond::Data<Loupe>>> DiamondLoupe for T {
         for i in 0..data.code_hashes {
             let hash = data.id_to_hash.get(i).unwrap();
             let selectors = self.data().hash_to_selectors.get(&hash).unwrap();
-            out_vec.push(FacetCut { hash, selectors })
+            let interfaces = self
+                .data()
+                .hash_to_interfaces
+                .get(&hash)
+                .unwrap_or(Vec::<InterfaceId>::new());
+            out_vec.push(FacetCut {
+                hash,
+                selectors,
+                interfaces,
+            })
         }
         out_vec
     }
@@ -30,7 +30,12 @@ pub use crate::{
 pub use ownable::Internal as _;
 pub use proxy::Internal as _;
 
-use ink_env::call::DelegateCall;
+use ink_env::call::{
+    DelegateCall,
+    ExecutionInput,
+    Selector,
+};
+use ink_prelude::vec::Vec;
 use openbrush::{
     modifiers,
     traits::{
@@ -54,11 +59,23 @@ impl<T: Storage<Data> + Storage<ownable::Data>> Proxy for T {
 
     #[modifiers(ownable::only_owner)]
     default fn change_delegate_code(&mut self, new_code_hash: Hash) -> Result<(), OwnableError> {
-        let old_code_hash = self.data::<Data>().forward_to.clone();
-        self.data::<Data>().forward_to = new_code_hash;
-        self._emit_delegate_code_changed_event(Some(old_code_hash), Some(new_code_hash));
+        self._change_delegate_code(new_code_hash);
         Ok(())
     }
+
+    #[modifiers(ownable::only_owner)]
+    default fn change_delegate_code_and_call(
+        &mut self,
+        new_code_hash: Hash,
+        selector: [u8; 4],
+        input: Vec<u8>,
+    ) -> Result<(), OwnableError> {
+        self._change_delegate_code(new_code_hash);
+        // The new delegate code must be in the storage before the call,
+        // because the called code can call the proxy again.
+        self.flush();
+        self._delegate_call(selector, input)
+    }
 }
 
 pub trait Internal {
@@ -66,7 +83,12 @@ pub trait Internal {
 
     fn _init_with_forward_to(&mut self, forward_to: Hash);
 
+    fn _change_delegate_code(&mut self, new_code_hash: Hash);
+
     fn _fallback(&self) -> !;
+
+    /// Calls the function of the delegate code identified by `selector` with SCALE encoded `input`.
+    fn _delegate_call(&self, selector: [u8; 4], input: Vec<u8>) -> !;
 }
 
 impl<T: Storage<Data>> Internal for T {
@@ -77,6 +99,12 @@ impl<T: Storage<Data>> Internal for T {
         self._emit_delegate_code_changed_event(None, Some(forward_to));
     }
 
+    default fn _change_delegate_code(&mut self, new_code_hash: Hash) {
+        let old_code_hash = self.data().forward_to.clone();
+        self.data().forward_to = new_code_hash;
+        self._emit_delegate_code_changed_event(Some(old_code_hash), Some(new_code_hash));
+    }
+
     default fn _fallback(&self) -> ! {
         ink_env::call::build_call::<ink_env::DefaultEnvironment>()
             .call_type(DelegateCall::new().code_hash(self.data().forward_to.clone()))
@@ -99,4 +127,29 @@ impl<T: Storage<Data>> Internal for T {
             });
         unreachable!("the _fallback call will never return since `tail_call` was set");
     }
+
+    default fn _delegate_call(&self, selector: [u8; 4], input: Vec<u8>) -> ! {
+        ink_env::call::build_call::<ink_env::DefaultEnvironment>()
+            .call_type(DelegateCall::new().code_hash(self.data().forward_to.clone()))
+            .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(CallInput(&input)))
+            .call_flags(ink_env::CallFlags::default()
+            // We don't plan to return back to that contract after execution, so we
+            // marked delegated call as "tail", to end the execution of the contract.
+            .set_tail_call(true))
+            .returns::<()>()
+            .fire()
+            .unwrap_or_else(|err| panic!("delegate call failed due to {:?}", err));
+        unreachable!("the _delegate_call call will never return since `tail_call` was set");
+    }
+}
+
+/// A wrapper that allows us to encode a blob of bytes.
+///
+/// We use this to pass the set of untyped (bytes) parameters to the `CallBuilder`.
+struct CallInput<'a>(&'a [u8]);
+
+impl<'a> scale::Encode for CallInput<'a> {
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0);
+    }
 }
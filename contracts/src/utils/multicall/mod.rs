@@ -0,0 +1,126 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    multicall,
+    traits::multicall::*,
+};
+pub use multicall::Internal as _;
+
+use ink_env::{
+    call::{
+        build_call,
+        Call,
+        ExecutionInput,
+        Selector,
+    },
+    DefaultEnvironment,
+};
+use ink_prelude::{
+    vec,
+    vec::Vec,
+};
+use openbrush::traits::{
+    AccountId,
+    Storage,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    pub _reserved: Option<()>,
+}
+
+impl<T: Storage<Data>> Multicall for T {
+    default fn aggregate(&self, calls: Vec<MulticallCall>) -> Result<Vec<Vec<u8>>, MulticallError> {
+        let mut outputs = Vec::with_capacity(calls.len());
+        for (i, (callee, input)) in calls.iter().enumerate() {
+            match self._call(callee, input) {
+                Some(output) => outputs.push(output),
+                None => return Err(MulticallError::CallFailed(i as u32)),
+            }
+        }
+        Ok(outputs)
+    }
+
+    default fn try_aggregate(
+        &self,
+        require_success: bool,
+        calls: Vec<MulticallCall>,
+    ) -> Result<Vec<(bool, Vec<u8>)>, MulticallError> {
+        let mut outputs = Vec::with_capacity(calls.len());
+        for (i, (callee, input)) in calls.iter().enumerate() {
+            match self._call(callee, input) {
+                Some(output) => outputs.push((true, output)),
+                None if require_success => return Err(MulticallError::CallFailed(i as u32)),
+                None => outputs.push((false, Vec::new())),
+            }
+        }
+        Ok(outputs)
+    }
+}
+
+pub trait Internal {
+    /// Calls `callee` with `input` and returns the raw output of the called message.
+    ///
+    /// Returns `None` if the call reverted or `input` doesn't contain the selector.
+    fn _call(&self, callee: &AccountId, input: &Vec<u8>) -> Option<Vec<u8>>;
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _call(&self, callee: &AccountId, input: &Vec<u8>) -> Option<Vec<u8>> {
+        if input.len() < 4 {
+            return None
+        }
+        let (selector, args) = input.split_at(4);
+        let selector = <[u8; 4]>::try_from(selector).ok()?;
+
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(callee.clone()))
+            .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(CallInput(args)))
+            .returns::<CallOutput>()
+            .fire()
+            .ok()
+            .map(|output| output.0)
+    }
+}
+
+/// Arguments of the call which are already SCALE encoded.
+struct CallInput<'a>(&'a [u8]);
+
+impl<'a> scale::Encode for CallInput<'a> {
+    fn encode_to<O: scale::Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(self.0);
+    }
+}
+
+/// Raw output of the call, it takes all bytes returned by the callee.
+struct CallOutput(Vec<u8>);
+
+impl scale::Decode for CallOutput {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let mut output = vec![0u8; input.remaining_len()?.unwrap_or(0)];
+        input.read(&mut output)?;
+        Ok(CallOutput(output))
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Verification of the signatures made off-chain, shared by the extensions which authorize
+//! actions with signed messages, like permit, lazy mint and EIP-1271 style signature validation.
+//!
+//! Only ECDSA is supported: the environment of ink! 3 provides `ecdsa_recover`,
+//! but it has no host function to verify sr25519 signatures.
+
+pub use crate::traits::errors::SignatureError;
+use ink_env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use openbrush::traits::AccountId;
+
+/// Returns the account which signed `message_hash` with the recoverable ecdsa `signature`.
+///
+/// The account of the ecdsa key pair is the blake2x256 hash of its compressed public key,
+/// the same as the account of the ecdsa key in Substrate.
+///
+/// # Errors
+///
+/// Returns `InvalidSignatureLength` error if `signature` is not 65 bytes long.
+///
+/// Returns `RecoveryFailed` error if the recovery id of `signature` is invalid
+/// or the public key can't be recovered from `signature`.
+pub fn recover_ecdsa(message_hash: &[u8; 32], signature: &[u8]) -> Result<AccountId, SignatureError> {
+    let signature: &[u8; 65] = signature
+        .try_into()
+        .map_err(|_| SignatureError::InvalidSignatureLength)?;
+    // the recovery id is 0..=3, or 27/28 in the Ethereum format, other values can't be recovered
+    if !matches!(signature[64], 0..=3 | 27 | 28) {
+        return Err(SignatureError::RecoveryFailed)
+    }
+    // the zero `r` or `s` is never a valid signature
+    if signature[..32] == [0u8; 32] || signature[32..64] == [0u8; 32] {
+        return Err(SignatureError::RecoveryFailed)
+    }
+    let mut public_key = [0u8; 33];
+    ink_env::ecdsa_recover(signature, message_hash, &mut public_key).map_err(|_| SignatureError::RecoveryFailed)?;
+
+    let mut account = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+    Ok(AccountId::from(account))
+}
+
+/// Returns `true` if `signature` is the ecdsa signature of `message_hash` made by `signer`.
+///
+/// Malformed signatures are rejected with `false`.
+pub fn verify_ecdsa(message_hash: &[u8; 32], signature: &[u8], signer: &AccountId) -> bool {
+    recover_ecdsa(message_hash, signature).as_ref() == Ok(signer)
+}
@@ -3,6 +3,7 @@
 
 #[openbrush::contract]
 pub mod my_access_control {
+    use ink_prelude::vec::Vec;
     use ink_storage::traits::SpreadAllocate;
     use openbrush::{
         contracts::{
@@ -53,6 +54,12 @@ pub mod my_access_control {
         fn mint(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
             self._mint_to(account, id)
         }
+
+        #[ink(message)]
+        #[modifiers(only_role(MINTER))]
+        fn mint_batch(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error> {
+            self._mint_batch_to(account, ids)
+        }
     }
 
     impl PSP34Burnable for Contract {
@@ -61,5 +68,11 @@ pub mod my_access_control {
         fn burn(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
             self._burn_from(account, id)
         }
+
+        #[ink(message)]
+        #[modifiers(only_role(MINTER))]
+        fn burn_batch(&mut self, account: AccountId, ids: Vec<Id>) -> Result<(), PSP34Error> {
+            self._burn_batch_from(account, ids)
+        }
     }
 }
@@ -3,6 +3,11 @@
 
 #[openbrush::contract]
 pub mod diamond {
+    use ink_lang::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_prelude::vec::Vec;
     use ink_storage::traits::SpreadAllocate;
     use openbrush::{
         contracts::diamond::extensions::diamond_loupe::*,
@@ -18,11 +23,28 @@ pub mod diamond {
         diamond: diamond::Data<Loupe>,
     }
 
+    #[ink(event)]
+    pub struct DiamondCut {
+        #[ink(topic)]
+        code_hash: Hash,
+        selectors: Vec<Selector>,
+        action: FacetCutAction,
+    }
+
+    #[ink(event)]
+    pub struct DiamondInitExecuted {
+        #[ink(topic)]
+        hash: Hash,
+        selector: Selector,
+    }
+
     impl Contract {
         #[ink(constructor)]
         pub fn new(owner: AccountId) -> Self {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {
                 instance._init_with_owner(owner);
+                // a facet cut must never leave `diamond_cut` unmapped, otherwise the diamond is not upgradeable anymore
+                instance._protect_selector(ink_lang::selector_bytes!("Diamond::diamond_cut"));
             })
         }
 
@@ -36,5 +58,19 @@ pub mod diamond {
 
     impl Diamond for Contract {}
 
+    impl diamond::Internal for Contract {
+        fn _emit_facet_cut_event(&self, code_hash: Hash, selectors: &Vec<Selector>, action: FacetCutAction) {
+            self.env().emit_event(DiamondCut {
+                code_hash,
+                selectors: selectors.clone(),
+                action,
+            })
+        }
+
+        fn _emit_diamond_init_executed_event(&self, hash: Hash, selector: Selector) {
+            self.env().emit_event(DiamondInitExecuted { hash, selector })
+        }
+    }
+
     impl DiamondLoupe for Contract {}
 }
@@ -0,0 +1,161 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod my_psp22_fee_facet {
+    use ink_lang::codegen::Env;
+    use ink_prelude::vec::Vec;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            psp22::*,
+        },
+        modifiers,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    /// The key is derived from the path of `FeeData`, so the storage of the facet
+    /// doesn't overlap with the storages of the diamond and other facets.
+    pub const FEE_STORAGE_KEY: u32 = openbrush::storage_unique_key!(FeeData);
+
+    /// The fee rate is expressed in basis points
+    pub const MAX_FEE_RATE: u16 = 10_000;
+
+    /// The logic unit owned by this facet, other facets don't know about it
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(FEE_STORAGE_KEY)]
+    pub struct FeeData {
+        pub treasury: AccountId,
+        pub fee_rate: u16,
+        pub fees_accrued: Balance,
+        pub _reserved: Option<()>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22FeeFacet {
+        // The same logic unit as in the other PSP22 facets, so the balances are shared with them
+        #[storage_field]
+        psp22: psp22::Data,
+        // Ownable is shared with the diamond, so the owner of the diamond manages the fee
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        fee: FeeData,
+    }
+
+    impl PSP22 for PSP22FeeFacet {
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
+            let from = self.env().caller();
+            self._transfer_with_fee(from, to, value, data)
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let allowance = self._allowance(&from, &caller);
+
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance)
+            }
+            if self._balance_of(&from) < value {
+                return Err(PSP22Error::InsufficientBalance)
+            }
+
+            if allowance != Balance::MAX {
+                self._approve_from_to(from, caller, allowance - value)?;
+            }
+            self._transfer_with_fee(from, to, value, data)
+        }
+    }
+
+    impl PSP22FeeFacet {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|_instance: &mut Self| {})
+        }
+
+        /// The init function of the facet, the fee is disabled until the owner sets it
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn init_psp22_fee(&mut self) -> Result<(), PSP22Error> {
+            let caller = Self::env().caller();
+            self.fee.treasury = caller;
+            self._mint_to(caller, 1000)
+        }
+
+        /// Sets the fee charged on transfers, in basis points
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_fee(&mut self, fee_rate: u16) -> Result<(), PSP22Error> {
+            if fee_rate > MAX_FEE_RATE {
+                return Err(PSP22Error::Custom(String::from("Fee rate is too high")))
+            }
+            self.fee.fee_rate = fee_rate;
+            Ok(())
+        }
+
+        /// Sets the account which receives the fees
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_treasury(&mut self, treasury: AccountId) -> Result<(), PSP22Error> {
+            self.fee.treasury = treasury;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn fee_rate(&self) -> u16 {
+            self.fee.fee_rate
+        }
+
+        #[ink(message)]
+        pub fn treasury(&self) -> AccountId {
+            self.fee.treasury
+        }
+
+        #[ink(message)]
+        pub fn fees_accrued(&self) -> Balance {
+            self.fee.fees_accrued
+        }
+
+        fn _fee_for(&self, value: Balance) -> Balance {
+            let max = MAX_FEE_RATE as Balance;
+            let rate = self.fee.fee_rate as Balance;
+            // split the value to avoid the overflow of `value * rate`
+            value / max * rate + value % max * rate / max
+        }
+
+        /// Moves the fee to the treasury and the rest of `value` to `to`
+        fn _transfer_with_fee(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            // the whole value is checked first, so the fee is not charged for the failed transfer
+            if self._balance_of(&from) < value {
+                return Err(PSP22Error::InsufficientBalance)
+            }
+
+            let fee = self._fee_for(value);
+            if fee > 0 {
+                let treasury = self.fee.treasury;
+                self._transfer_from_to(from, treasury, fee, Vec::new())?;
+                self.fee.fees_accrued += fee;
+            }
+            self._transfer_from_to(from, to, value - fee, data)
+        }
+    }
+}
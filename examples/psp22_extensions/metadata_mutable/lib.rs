@@ -0,0 +1,89 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod my_psp22 {
+    use ink_lang::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            psp22::extensions::metadata_mutable::*,
+        },
+        modifiers,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(event)]
+    pub struct MetadataUpdated {
+        name: Option<String>,
+        symbol: Option<String>,
+        decimals: u8,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl PSP22 for Contract {}
+
+    impl PSP22Metadata for Contract {}
+
+    impl Ownable for Contract {}
+
+    impl metadata_mutable::Internal for Contract {
+        fn _emit_metadata_updated_event(&self, name: Option<String>, symbol: Option<String>, decimals: u8) {
+            self.env().emit_event(MetadataUpdated { name, symbol, decimals })
+        }
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance, name: Option<String>, symbol: Option<String>, decimal: u8) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.metadata.name = name;
+                instance.metadata.symbol = symbol;
+                instance.metadata.decimals = decimal;
+                instance._init_with_owner(instance.env().caller());
+                instance
+                    ._mint_to(instance.env().caller(), total_supply)
+                    .expect("Should mint total_supply");
+            })
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_token_name(&mut self, name: Option<String>) -> Result<(), OwnableError> {
+            self._set_token_name(name);
+            Ok(())
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_token_symbol(&mut self, symbol: Option<String>) -> Result<(), OwnableError> {
+            self._set_token_symbol(symbol);
+            Ok(())
+        }
+
+        /// Changing decimals changes how all balances are displayed, so holders should be notified in advance.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_token_decimals(&mut self, decimals: u8) -> Result<(), OwnableError> {
+            self._set_token_decimals(decimals);
+            Ok(())
+        }
+    }
+}
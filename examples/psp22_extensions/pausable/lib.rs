@@ -6,6 +6,7 @@ pub mod my_psp22_pausable {
     use ink_storage::traits::SpreadAllocate;
     use openbrush::{
         contracts::{
+            ownable::*,
             pausable::*,
             psp22::*,
         },
@@ -20,36 +21,41 @@ pub mod my_psp22_pausable {
         psp22: psp22::Data,
         #[storage_field]
         pause: pausable::Data,
+        #[storage_field]
+        ownable: ownable::Data,
     }
 
     impl PSP22 for Contract {}
 
     impl Transfer for Contract {
-        /// Return `Paused` error if the token is paused
-        #[modifiers(when_not_paused)]
+        /// Return `Paused` error if the token is paused.
+        /// The hook is called during minting and burning too, so they are paused as well.
         fn _before_token_transfer(
             &mut self,
             _from: Option<&AccountId>,
             _to: Option<&AccountId>,
             _amount: &Balance,
         ) -> Result<(), PSP22Error> {
-            // TODO logic for before token transfer
-            Ok(())
+            self._ensure_not_paused()
         }
     }
 
     impl Pausable for Contract {}
 
+    impl Ownable for Contract {}
+
     impl Contract {
         #[ink(constructor)]
         pub fn new(total_supply: Balance) -> Self {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(Self::env().caller());
                 assert!(instance._mint_to(Self::env().caller(), total_supply).is_ok());
             })
         }
 
         /// Function which changes state to unpaused if paused and vice versa
         #[ink(message)]
+        #[modifiers(only_owner)]
         pub fn change_state(&mut self) -> Result<(), PSP22Error> {
             self._switch_pause()
         }
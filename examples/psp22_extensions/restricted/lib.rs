@@ -0,0 +1,71 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod my_psp22_restricted {
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            psp22::extensions::restricted::*,
+        },
+        modifiers,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        restricted: restricted::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl PSP22 for Contract {}
+
+    impl psp22::Transfer for Contract {
+        /// Return `AccountBlocked` error if the sender or the recipient is blocked.
+        /// The hook is called during minting and burning too, so they are restricted as well.
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._ensure_not_blocked(from, to)
+        }
+    }
+
+    impl PSP22Restricted for Contract {}
+
+    impl Ownable for Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(Self::env().caller());
+                assert!(instance._mint_to(Self::env().caller(), total_supply).is_ok());
+            })
+        }
+
+        /// Blocks `account`, so it can't send or receive tokens
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn block_account(&mut self, account: AccountId) -> Result<(), PSP22Error> {
+            self._block_account(account);
+            Ok(())
+        }
+
+        /// Unblocks `account`
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn unblock_account(&mut self, account: AccountId) -> Result<(), PSP22Error> {
+            self._unblock_account(account);
+            Ok(())
+        }
+    }
+}
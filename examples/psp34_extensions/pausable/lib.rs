@@ -0,0 +1,71 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod my_psp34_pausable {
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            pausable::*,
+            psp34::*,
+        },
+        modifiers,
+        traits::Storage,
+    };
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        pause: pausable::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl PSP34 for Contract {}
+
+    impl psp34::Transfer for Contract {
+        /// Return `Paused` error if the token is paused.
+        /// The hook is called during minting and burning too, so they are paused as well.
+        fn _before_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _id: &Id,
+        ) -> Result<(), PSP34Error> {
+            self._ensure_not_paused()
+        }
+    }
+
+    impl Pausable for Contract {}
+
+    impl Ownable for Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(Self::env().caller());
+                instance
+                    ._mint_to(Self::env().caller(), Id::U8(0u8))
+                    .expect("Should mint token with id 0");
+            })
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn mint(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
+            self._mint_to(account, id)
+        }
+
+        /// Function which changes state to unpaused if paused and vice versa
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn change_state(&mut self) -> Result<(), PSP34Error> {
+            self._switch_pause()
+        }
+    }
+}
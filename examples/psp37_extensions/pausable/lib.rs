@@ -0,0 +1,69 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod my_psp37_pausable {
+    use ink_prelude::vec::Vec;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            pausable::*,
+            psp37::*,
+        },
+        modifiers,
+        traits::Storage,
+    };
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp37: psp37::Data,
+        #[storage_field]
+        pause: pausable::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl PSP37 for Contract {}
+
+    impl psp37::Transfer for Contract {
+        /// Return `Paused` error if the token is paused.
+        /// The hook is called during minting and burning too, so they are paused as well.
+        fn _before_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _ids: &Vec<(Id, Balance)>,
+        ) -> Result<(), PSP37Error> {
+            self._ensure_not_paused()
+        }
+    }
+
+    impl Pausable for Contract {}
+
+    impl Ownable for Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(Self::env().caller());
+            })
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn mint_to(&mut self, to: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+            self._mint_to(to, ids_amounts)
+        }
+
+        /// Function which changes state to unpaused if paused and vice versa
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn change_state(&mut self) -> Result<(), PSP37Error> {
+            self._switch_pause()
+        }
+    }
+}
@@ -214,6 +214,8 @@ pub fn modifier_definition(_attrs: TokenStream, _input: TokenStream) -> TokenStr
 ///
 /// Modifiers are designed to be used for methods in impl sections.
 /// The method can have several modifiers. They will be expanded from left to right.
+/// So the first modifier is the outermost one: its code before `body` is executed first,
+/// and its code after `body` is executed last, after all other modifiers.
 /// The modifier can accept arguments from the scope of the method definition
 /// (you can pass an argument from the signature of marked method or from the outside scope of function).
 /// The modifier accepts arguments only by value and the type of argument must support `Clone` trait,
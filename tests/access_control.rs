@@ -76,6 +76,7 @@ mod access_control {
     // And will reduce the chance to have overlapping roles.
     const MINTER: RoleType = ink_lang::selector_id!("MINTER");
     const PAUSER: RoleType = ink_lang::selector_id!("PAUSER");
+    const MINTER_ADMIN: RoleType = ink_lang::selector_id!("MINTER_ADMIN");
 
     #[derive(Default, Storage)]
     #[ink(storage)]
@@ -310,6 +311,33 @@ mod access_control {
         assert_role_granted_event(&emitted_events[3], PAUSER, accounts.bob, Some(accounts.eve));
     }
 
+    #[ink::test]
+    fn only_custom_admin_role_can_grant_role() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(access_control.grant_role(MINTER_ADMIN, accounts.bob).is_ok());
+        access_control._set_role_admin(MINTER, MINTER_ADMIN);
+        assert_eq!(access_control.get_role_admin(MINTER), MINTER_ADMIN);
+
+        // The default admin can't grant `MINTER` anymore
+        assert_eq!(
+            access_control.grant_role(MINTER, accounts.eve),
+            Err(AccessControlError::MissingRole)
+        );
+        change_caller(accounts.eve);
+        assert_eq!(
+            access_control.grant_role(MINTER, accounts.eve),
+            Err(AccessControlError::MissingRole)
+        );
+
+        change_caller(accounts.bob);
+        assert!(access_control.grant_role(MINTER, accounts.eve).is_ok());
+        assert!(access_control.has_role(MINTER, accounts.eve));
+        assert!(access_control.revoke_role(MINTER, accounts.eve).is_ok());
+        assert!(!access_control.has_role(MINTER, accounts.eve));
+    }
+
     #[ink::test]
     fn should_return_error_when_not_admin_grant_role() {
         let accounts = setup();
@@ -354,6 +382,41 @@ mod access_control {
         );
     }
 
+    #[ink::test]
+    fn admin_can_not_renounce_role_of_another_account() {
+        let accounts = setup();
+        change_caller(accounts.alice);
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(access_control.grant_role(PAUSER, accounts.bob).is_ok());
+        assert_eq!(
+            access_control.renounce_role(PAUSER, accounts.bob),
+            Err(AccessControlError::InvalidCaller)
+        );
+
+        assert!(access_control.has_role(PAUSER, accounts.bob));
+        // only `RoleGranted` events, nothing was revoked
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+    }
+
+    #[ink::test]
+    fn admin_can_renounce_own_admin_role() {
+        let accounts = setup();
+        change_caller(accounts.alice);
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(access_control.renounce_role(DEFAULT_ADMIN_ROLE, accounts.alice).is_ok());
+
+        assert!(!access_control.has_role(DEFAULT_ADMIN_ROLE, accounts.alice));
+        assert_eq!(
+            access_control.grant_role(PAUSER, accounts.bob),
+            Err(AccessControlError::MissingRole)
+        );
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_role_revoked_event(&emitted_events[1], DEFAULT_ADMIN_ROLE, accounts.alice, accounts.alice);
+    }
+
     #[ink::test]
     fn should_return_error_when_account_doesnt_have_role() {
         let accounts = setup();
@@ -365,4 +428,128 @@ mod access_control {
             Err(AccessControlError::MissingRole)
         );
     }
+
+    #[ink::test]
+    fn should_grant_role_batch() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(access_control
+            .grant_role_batch(vec![
+                (PAUSER, accounts.bob),
+                (MINTER, accounts.bob),
+                (MINTER, accounts.eve),
+            ])
+            .is_ok());
+
+        assert!(access_control.has_role(PAUSER, accounts.bob));
+        assert!(access_control.has_role(MINTER, accounts.bob));
+        assert!(access_control.has_role(MINTER, accounts.eve));
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 4);
+        assert_role_granted_event(&emitted_events[1], PAUSER, accounts.bob, Some(accounts.alice));
+        assert_role_granted_event(&emitted_events[2], MINTER, accounts.bob, Some(accounts.alice));
+        assert_role_granted_event(&emitted_events[3], MINTER, accounts.eve, Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn should_revoke_role_batch() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        assert!(access_control
+            .grant_role_batch(vec![(PAUSER, accounts.bob), (MINTER, accounts.eve)])
+            .is_ok());
+
+        assert!(access_control
+            .revoke_role_batch(vec![(PAUSER, accounts.bob), (MINTER, accounts.eve)])
+            .is_ok());
+
+        assert!(!access_control.has_role(PAUSER, accounts.bob));
+        assert!(!access_control.has_role(MINTER, accounts.eve));
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 5);
+        assert_role_revoked_event(&emitted_events[3], PAUSER, accounts.bob, accounts.alice);
+        assert_role_revoked_event(&emitted_events[4], MINTER, accounts.eve, accounts.alice);
+    }
+
+    #[ink::test]
+    fn empty_role_batch_is_noop() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert_eq!(access_control.grant_role_batch(Vec::new()), Ok(()));
+        assert_eq!(access_control.revoke_role_batch(Vec::new()), Ok(()));
+        change_caller(accounts.bob);
+        assert_eq!(access_control.grant_role_batch(Vec::new()), Ok(()));
+
+        assert_eq!(ink_env::test::recorded_events().count(), 1);
+    }
+
+    #[ink::test]
+    fn grant_role_batch_is_atomic() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        assert!(access_control.grant_role(MINTER_ADMIN, accounts.bob).is_ok());
+        access_control._set_role_admin(MINTER, MINTER_ADMIN);
+
+        // alice is not the admin of `MINTER` anymore
+        assert_eq!(
+            access_control.grant_role_batch(vec![(PAUSER, accounts.eve), (MINTER, accounts.eve)]),
+            Err(AccessControlError::MissingRole)
+        );
+        assert!(!access_control.has_role(PAUSER, accounts.eve));
+
+        assert_eq!(
+            access_control.grant_role_batch(vec![(PAUSER, accounts.eve), (PAUSER, accounts.eve)]),
+            Err(AccessControlError::RoleRedundant)
+        );
+        assert!(!access_control.has_role(PAUSER, accounts.eve));
+    }
+
+    #[ink::test]
+    fn revoke_role_batch_is_atomic() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        assert!(access_control.grant_role(PAUSER, accounts.bob).is_ok());
+
+        assert_eq!(
+            access_control.revoke_role_batch(vec![(PAUSER, accounts.bob), (MINTER, accounts.bob)]),
+            Err(AccessControlError::MissingRole)
+        );
+        assert!(access_control.has_role(PAUSER, accounts.bob));
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            access_control.revoke_role_batch(vec![(PAUSER, accounts.bob)]),
+            Err(AccessControlError::MissingRole)
+        );
+        assert!(access_control.has_role(PAUSER, accounts.bob));
+    }
+
+    #[ink::test]
+    fn has_all_roles_works() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        assert!(access_control.grant_role(PAUSER, accounts.bob).is_ok());
+        assert!(access_control.grant_role(MINTER, accounts.bob).is_ok());
+
+        assert!(access_control.has_all_roles(accounts.bob, vec![PAUSER, MINTER]));
+        assert!(!access_control.has_all_roles(accounts.bob, vec![PAUSER, MINTER, MINTER_ADMIN]));
+        assert!(!access_control.has_all_roles(accounts.eve, vec![PAUSER]));
+        assert!(access_control.has_all_roles(accounts.eve, vec![]));
+    }
+
+    #[ink::test]
+    fn has_any_role_works() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        assert!(access_control.grant_role(PAUSER, accounts.bob).is_ok());
+
+        assert!(access_control.has_any_role(accounts.bob, vec![MINTER, PAUSER]));
+        assert!(!access_control.has_any_role(accounts.bob, vec![MINTER, MINTER_ADMIN]));
+        assert!(!access_control.has_any_role(accounts.bob, vec![]));
+        assert!(access_control.has_any_role(accounts.alice, vec![MINTER, DEFAULT_ADMIN_ROLE]));
+    }
 }
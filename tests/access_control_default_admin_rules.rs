@@ -0,0 +1,239 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "access_control")]
+#[openbrush::contract]
+mod access_control_default_admin_rules {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::access_control::extensions::default_admin_rules::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            ZERO_ADDRESS,
+        },
+    };
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct AccessControlStruct {
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        default_admin_rules: default_admin_rules::Data,
+    }
+
+    impl access_control::Internal for AccessControlStruct {
+        fn _before_role_change(&mut self, role: RoleType, _account: AccountId) -> Result<(), AccessControlError> {
+            self._ensure_default_admin_rules(role)
+        }
+    }
+
+    impl AccessControl for AccessControlStruct {}
+
+    impl AccessControlDefaultAdminRules for AccessControlStruct {}
+
+    impl AccessControlStruct {
+        #[ink(constructor)]
+        pub fn new(admin: AccountId, delay: Timestamp) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_admin(admin);
+                instance._init_default_admin_delay(delay);
+            })
+        }
+    }
+
+    const DELAY: Timestamp = 120;
+
+    /// Moves the block timestamp to `timestamp`. The off-chain environment moves the time
+    /// only by whole blocks, so `timestamp` must be a multiple of the block time.
+    fn set_block_timestamp(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+        assert_eq!(ink_env::block_timestamp::<ink_env::DefaultEnvironment>(), timestamp);
+    }
+
+    #[ink::test]
+    fn begin_default_admin_transfer_works() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+        assert_eq!(instance.pending_default_admin(), (ZERO_ADDRESS.into(), 0));
+
+        set_block_timestamp(30);
+        assert_eq!(instance.begin_default_admin_transfer(accounts.bob), Ok(()));
+
+        assert_eq!(instance.pending_default_admin(), (accounts.bob, 30 + DELAY));
+        // the role is not transferred yet
+        assert!(instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.alice));
+        assert!(!instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.bob));
+    }
+
+    #[ink::test]
+    fn only_default_admin_can_begin_transfer() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            instance.begin_default_admin_transfer(accounts.bob),
+            Err(AccessControlError::MissingRole)
+        );
+        assert_eq!(instance.pending_default_admin(), (ZERO_ADDRESS.into(), 0));
+    }
+
+    #[ink::test]
+    fn premature_accept_should_fail() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+        assert_eq!(instance.begin_default_admin_transfer(accounts.bob), Ok(()));
+
+        change_caller(accounts.bob);
+        set_block_timestamp(DELAY - 30);
+        assert_eq!(
+            instance.accept_default_admin_transfer(),
+            Err(AccessControlError::DefaultAdminDelayNotPassed)
+        );
+        assert!(!instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.bob));
+        assert_eq!(instance.pending_default_admin(), (accounts.bob, DELAY));
+    }
+
+    #[ink::test]
+    fn accept_after_delay_works() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+        assert_eq!(instance.begin_default_admin_transfer(accounts.bob), Ok(()));
+
+        // only the pending admin can accept the role
+        set_block_timestamp(DELAY);
+        assert_eq!(
+            instance.accept_default_admin_transfer(),
+            Err(AccessControlError::InvalidCaller)
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(instance.accept_default_admin_transfer(), Ok(()));
+
+        assert!(instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.bob));
+        assert!(!instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.alice));
+        assert_eq!(instance.pending_default_admin(), (ZERO_ADDRESS.into(), 0));
+        assert_eq!(
+            instance.accept_default_admin_transfer(),
+            Err(AccessControlError::InvalidCaller)
+        );
+    }
+
+    #[ink::test]
+    fn cancel_default_admin_transfer_works() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+        assert_eq!(instance.begin_default_admin_transfer(accounts.bob), Ok(()));
+
+        assert_eq!(instance.cancel_default_admin_transfer(), Ok(()));
+        assert_eq!(instance.pending_default_admin(), (ZERO_ADDRESS.into(), 0));
+
+        change_caller(accounts.bob);
+        set_block_timestamp(DELAY);
+        assert_eq!(
+            instance.accept_default_admin_transfer(),
+            Err(AccessControlError::InvalidCaller)
+        );
+        assert!(instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.alice));
+        assert!(!instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.bob));
+    }
+
+    #[ink::test]
+    fn cancel_without_pending_transfer_should_fail() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert_eq!(
+            instance.cancel_default_admin_transfer(),
+            Err(AccessControlError::NoPendingDefaultAdminTransfer)
+        );
+    }
+
+    #[ink::test]
+    fn default_admin_role_cannot_be_granted_or_revoked_directly() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+        const MINTER: RoleType = ink_lang::selector_id!("MINTER");
+
+        assert_eq!(
+            instance.grant_role(access_control::DEFAULT_ADMIN_ROLE, accounts.bob),
+            Err(AccessControlError::EnforcedDefaultAdminRules)
+        );
+        assert_eq!(
+            instance.revoke_role(access_control::DEFAULT_ADMIN_ROLE, accounts.alice),
+            Err(AccessControlError::EnforcedDefaultAdminRules)
+        );
+        assert!(!instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.bob));
+        assert!(instance.has_role(access_control::DEFAULT_ADMIN_ROLE, accounts.alice));
+
+        // other roles are not affected
+        assert_eq!(instance.grant_role(MINTER, accounts.bob), Ok(()));
+        assert_eq!(instance.revoke_role(MINTER, accounts.bob), Ok(()));
+    }
+
+    #[ink::test]
+    fn change_default_admin_delay_takes_effect_after_current_delay() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert_eq!(instance.change_default_admin_delay(10), Ok(()));
+        assert_eq!(instance.pending_default_admin_delay(), (10, DELAY));
+        assert_eq!(instance.default_admin_delay(), DELAY);
+
+        // the transfer which begins before the change uses the current delay
+        assert_eq!(instance.begin_default_admin_transfer(accounts.bob), Ok(()));
+        assert_eq!(instance.pending_default_admin(), (accounts.bob, DELAY));
+
+        set_block_timestamp(DELAY);
+        assert_eq!(instance.default_admin_delay(), 10);
+        assert_eq!(instance.pending_default_admin_delay(), (0, 0));
+        assert_eq!(instance.begin_default_admin_transfer(accounts.bob), Ok(()));
+        assert_eq!(instance.pending_default_admin(), (accounts.bob, DELAY + 10));
+    }
+
+    #[ink::test]
+    fn rollback_default_admin_delay_works() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+        assert_eq!(instance.change_default_admin_delay(10), Ok(()));
+
+        assert_eq!(instance.rollback_default_admin_delay(), Ok(()));
+
+        set_block_timestamp(DELAY);
+        assert_eq!(instance.default_admin_delay(), DELAY);
+        assert_eq!(instance.pending_default_admin_delay(), (0, 0));
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            instance.change_default_admin_delay(0),
+            Err(AccessControlError::MissingRole)
+        );
+    }
+}
@@ -112,4 +112,24 @@ mod access_control_enumerable {
         assert!(access_control.revoke_role(PAUSER, alice).is_ok());
         assert_eq!(access_control.get_role_member(PAUSER, 1), None)
     }
+
+    #[ink::test]
+    fn revoke_middle_member_keeps_indices_dense() {
+        let accounts = setup();
+        let alice = accounts.alice;
+        let mut access_control = AccessControlStruct::new(alice);
+
+        assert!(access_control.grant_role(PAUSER, accounts.bob).is_ok());
+        assert!(access_control.grant_role(PAUSER, accounts.charlie).is_ok());
+        assert!(access_control.grant_role(PAUSER, accounts.eve).is_ok());
+
+        assert!(access_control.revoke_role(PAUSER, accounts.charlie).is_ok());
+
+        // The last member takes the place of the revoked one
+        assert_eq!(access_control.get_role_member_count(PAUSER), 2);
+        assert_eq!(access_control.get_role_member(PAUSER, 0), Some(accounts.bob));
+        assert_eq!(access_control.get_role_member(PAUSER, 1), Some(accounts.eve));
+        assert_eq!(access_control.get_role_member(PAUSER, 2), None);
+        assert!(!access_control.has_role(PAUSER, accounts.charlie));
+    }
 }
@@ -0,0 +1,135 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "access_control")]
+#[openbrush::contract]
+mod access_control_signature {
+    use ink_env::hash::{
+        Blake2x256,
+        HashOutput,
+    };
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::access_control::extensions::signature::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+    use secp256k1::{
+        Message,
+        PublicKey,
+        Secp256k1,
+        SecretKey,
+    };
+
+    const SIGNER: RoleType = ink_lang::selector_id!("SIGNER");
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct AccessControlStruct {
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    impl AccessControl for AccessControlStruct {}
+
+    impl AccessControlSignature for AccessControlStruct {}
+
+    impl signature::Internal for AccessControlStruct {
+        fn _signer_role(&self) -> RoleType {
+            SIGNER
+        }
+    }
+
+    impl AccessControlStruct {
+        #[ink(constructor)]
+        pub fn new(admin: AccountId) -> Self {
+            ink_lang::codegen::initialize_contract(|_instance: &mut Self| {
+                _instance._init_with_admin(admin);
+            })
+        }
+    }
+
+    fn key(seed: u8) -> (SecretKey, AccountId) {
+        let secret_key = SecretKey::from_slice(&[seed; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key).serialize();
+        let mut account = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+        (secret_key, AccountId::from(account))
+    }
+
+    fn sign(secret_key: &SecretKey, message_hash: &[u8; 32]) -> Vec<u8> {
+        let message = Message::from_slice(message_hash).unwrap();
+        let (recovery_id, signature) = Secp256k1::new()
+            .sign_ecdsa_recoverable(&message, secret_key)
+            .serialize_compact();
+        let mut output = signature.to_vec();
+        output.push(recovery_id.to_i32() as u8);
+        output
+    }
+
+    #[ink::test]
+    fn signer_signature_is_valid() {
+        let (secret_key, signer) = key(0x42);
+        let mut instance = AccessControlStruct::new(accounts().alice);
+        assert!(instance.grant_role(SIGNER, signer).is_ok());
+        let hash = [0x01; 32];
+
+        assert!(instance.is_valid_signature(hash, sign(&secret_key, &hash)));
+    }
+
+    #[ink::test]
+    fn signature_without_role_is_invalid() {
+        let (secret_key, signer) = key(0x42);
+        let mut instance = AccessControlStruct::new(accounts().alice);
+        let hash = [0x01; 32];
+        assert!(!instance.is_valid_signature(hash, sign(&secret_key, &hash)));
+
+        // the role is checked at the moment of validation
+        assert!(instance.grant_role(SIGNER, signer).is_ok());
+        assert!(instance.is_valid_signature(hash, sign(&secret_key, &hash)));
+        assert!(instance.revoke_role(SIGNER, signer).is_ok());
+        assert!(!instance.is_valid_signature(hash, sign(&secret_key, &hash)));
+    }
+
+    #[ink::test]
+    fn admin_signature_is_invalid_for_signer_role() {
+        let (secret_key, admin) = key(0x42);
+        let instance = AccessControlStruct::new(admin);
+        let hash = [0x01; 32];
+
+        assert!(instance.has_role(access_control::DEFAULT_ADMIN_ROLE, admin));
+        assert!(!instance.is_valid_signature(hash, sign(&secret_key, &hash)));
+    }
+
+    #[ink::test]
+    fn malformed_signature_is_invalid() {
+        let (secret_key, signer) = key(0x42);
+        let mut instance = AccessControlStruct::new(accounts().alice);
+        assert!(instance.grant_role(SIGNER, signer).is_ok());
+        let hash = [0x01; 32];
+        let mut signature = sign(&secret_key, &hash);
+        signature.push(0);
+
+        assert!(!instance.is_valid_signature(hash, signature));
+    }
+}
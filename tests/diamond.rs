@@ -0,0 +1,1350 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "diamond")]
+#[openbrush::contract]
+mod diamond {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::diamond::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct DiamondCut {
+        #[ink(topic)]
+        code_hash: Hash,
+        selectors: Vec<Selector>,
+        action: FacetCutAction,
+    }
+
+    #[ink(event)]
+    pub struct DiamondInitExecuted {
+        #[ink(topic)]
+        hash: Hash,
+        selector: Selector,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct DiamondContract {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        diamond: diamond::Data,
+    }
+
+    impl DiamondContract {
+        #[ink(constructor)]
+        pub fn new(owner: AccountId) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(owner);
+            })
+        }
+        #[ink(message, payable, selector = _)]
+        pub fn forward(&self) {
+            self._fallback()
+        }
+    }
+    impl Ownable for DiamondContract {}
+    impl Diamond for DiamondContract {}
+
+    impl diamond::Internal for DiamondContract {
+        fn _emit_facet_cut_event(&self, code_hash: Hash, selectors: &Vec<Selector>, action: FacetCutAction) {
+            self.env().emit_event(DiamondCut {
+                code_hash,
+                selectors: selectors.clone(),
+                action,
+            })
+        }
+
+        fn _emit_diamond_init_executed_event(&self, hash: Hash, selector: Selector) {
+            self.env().emit_event(DiamondInitExecuted { hash, selector })
+        }
+    }
+
+    type Event = <DiamondContract as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    fn assert_diamond_cut_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_code_hash: Hash,
+        expected_selectors: Vec<Selector>,
+        expected_action: FacetCutAction,
+    ) {
+        if let Event::DiamondCut(DiamondCut {
+            code_hash,
+            selectors,
+            action,
+        }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(code_hash, expected_code_hash);
+            assert_eq!(selectors, expected_selectors);
+            assert_eq!(action, expected_action);
+        } else {
+            panic!("encountered unexpected event kind: expected a DiamondCut event")
+        }
+    }
+
+    fn assert_diamond_init_executed_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_hash: Hash,
+        expected_selector: Selector,
+    ) {
+        if let Event::DiamondInitExecuted(DiamondInitExecuted { hash, selector }) =
+            <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(hash, expected_hash);
+            assert_eq!(selector, expected_selector);
+        } else {
+            panic!("encountered unexpected event kind: expected a DiamondInitExecuted event")
+        }
+    }
+
+    const INTERFACE_ID: InterfaceId = [0x01, 0xff, 0xc9, 0xa7];
+
+    fn facet_cut(hash: u8, selector: u8) -> FacetCut {
+        FacetCut {
+            hash: [hash; 32].into(),
+            selectors: vec![[selector; 4]],
+            interfaces: vec![],
+        }
+    }
+
+    #[ink::test]
+    fn supports_interface_is_false_by_default() {
+        let diamond = DiamondContract::new(accounts().alice);
+
+        assert!(!diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn register_interface_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+
+        diamond._register_interface(cut.hash, INTERFACE_ID);
+
+        assert!(diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn deregister_interface_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        diamond._register_interface(cut.hash, INTERFACE_ID);
+
+        diamond._deregister_interface(cut.hash, INTERFACE_ID);
+
+        assert!(!diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn remove_facet_deregisters_interface() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        diamond._register_interface(cut.hash, INTERFACE_ID);
+
+        let remove = FacetCut {
+            hash: cut.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove], None), Ok(()));
+
+        assert!(!diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn interface_is_supported_until_last_facet_removed() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let first = facet_cut(1, 1);
+        let second = facet_cut(2, 2);
+        assert_eq!(diamond.diamond_cut(vec![first.clone(), second.clone()], None), Ok(()));
+        diamond._register_interface(first.hash, INTERFACE_ID);
+        diamond._register_interface(second.hash, INTERFACE_ID);
+
+        let remove_first = FacetCut {
+            hash: first.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove_first], None), Ok(()));
+        assert!(diamond.supports_interface(INTERFACE_ID));
+
+        let remove_second = FacetCut {
+            hash: second.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove_second], None), Ok(()));
+        assert!(!diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn register_interface_twice_counts_once() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        diamond._register_interface(cut.hash, INTERFACE_ID);
+        diamond._register_interface(cut.hash, INTERFACE_ID);
+
+        diamond._deregister_interface(cut.hash, INTERFACE_ID);
+
+        assert!(!diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn diamond_cut_registers_interfaces_of_facets() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let mut first = facet_cut(1, 1);
+        first.interfaces = vec![INTERFACE_ID];
+        let mut second = facet_cut(2, 2);
+        second.interfaces = vec![INTERFACE_ID];
+        assert_eq!(diamond.diamond_cut(vec![first.clone(), second.clone()], None), Ok(()));
+        assert!(diamond.supports_interface(INTERFACE_ID));
+
+        let remove_first = FacetCut {
+            hash: first.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove_first], None), Ok(()));
+        assert!(diamond.supports_interface(INTERFACE_ID));
+
+        let remove_second = FacetCut {
+            hash: second.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove_second], None), Ok(()));
+        assert!(!diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn diamond_cut_override_registers_interfaces_of_facets() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let mut cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+
+        // the selectors of the facet are not changed, only the interface is added
+        cut.interfaces = vec![INTERFACE_ID];
+        assert_eq!(diamond.diamond_cut_override(vec![cut.clone()], None), Ok(()));
+        assert!(diamond.supports_interface(INTERFACE_ID));
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some(cut.hash));
+    }
+
+    #[ink::test]
+    fn diamond_cut_emits_event_per_facet_action() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let first = facet_cut(1, 1);
+        let second = facet_cut(2, 2);
+        assert_eq!(diamond.diamond_cut(vec![first.clone(), second.clone()], None), Ok(()));
+
+        let replace_first = FacetCut {
+            hash: first.hash,
+            selectors: vec![[1u8; 4], [3u8; 4]],
+            interfaces: vec![],
+        };
+        let remove_second = FacetCut {
+            hash: second.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        let third = facet_cut(3, 4);
+        assert_eq!(
+            diamond.diamond_cut(vec![replace_first.clone(), remove_second, third.clone()], None),
+            Ok(())
+        );
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 5);
+        assert_diamond_cut_event(&emitted_events[0], first.hash, first.selectors, FacetCutAction::Add);
+        assert_diamond_cut_event(
+            &emitted_events[1],
+            second.hash,
+            second.selectors.clone(),
+            FacetCutAction::Add,
+        );
+        assert_diamond_cut_event(
+            &emitted_events[2],
+            first.hash,
+            replace_first.selectors,
+            FacetCutAction::Replace,
+        );
+        assert_diamond_cut_event(
+            &emitted_events[3],
+            second.hash,
+            second.selectors,
+            FacetCutAction::Remove,
+        );
+        assert_diamond_cut_event(&emitted_events[4], third.hash, third.selectors, FacetCutAction::Add);
+    }
+
+    #[ink::test]
+    fn validate_diamond_cut_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let first = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![first.clone()], None), Ok(()));
+
+        assert_eq!(diamond._validate_diamond_cut(&vec![facet_cut(2, 2)]), Ok(()));
+        assert_eq!(
+            diamond._validate_diamond_cut(&vec![facet_cut(2, 1)]),
+            Err(DiamondError::ReplaceExisting(first.hash))
+        );
+        assert_eq!(
+            diamond._validate_diamond_cut(&vec![facet_cut(0, 2)]),
+            Err(DiamondError::EmptyCodeHash)
+        );
+    }
+
+    #[ink::test]
+    fn validate_diamond_cut_takes_previous_cuts_into_account() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let first = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![first.clone()], None), Ok(()));
+
+        // the selector is released by the first cut, so the second cut can take it
+        let remove_first = FacetCut {
+            hash: first.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond._validate_diamond_cut(&vec![remove_first, facet_cut(2, 1)]),
+            Ok(())
+        );
+        // the selector is taken by the first cut, so the second cut conflicts with it
+        assert_eq!(
+            diamond._validate_diamond_cut(&vec![facet_cut(2, 2), facet_cut(3, 2)]),
+            Err(DiamondError::ReplaceExisting([2u8; 32].into()))
+        );
+    }
+
+    #[ink::test]
+    fn validate_diamond_cut_does_not_modify_state() {
+        let diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(diamond._validate_diamond_cut(&vec![facet_cut(1, 1)]), Ok(()));
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 0);
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[1u8; 4]), None);
+        assert_eq!(diamond.diamond.hash_to_selectors.get(&[1u8; 32].into()), None);
+    }
+
+    #[ink::test]
+    fn remove_not_registered_facet_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        let remove = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond.diamond_cut(vec![remove], None),
+            Err(DiamondError::FunctionDoesNotExist)
+        );
+    }
+
+    #[ink::test]
+    fn diamond_cut_is_atomic() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let existing = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![existing.clone()], None), Ok(()));
+
+        let first = facet_cut(2, 2);
+        let conflicting = facet_cut(3, 1);
+        assert_eq!(
+            diamond.diamond_cut(vec![first.clone(), conflicting], None),
+            Err(DiamondError::ReplaceExisting(existing.hash))
+        );
+
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[2u8; 4]), None);
+        assert_eq!(diamond.diamond.hash_to_selectors.get(&first.hash), None);
+        // only the event of the first successful cut
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 1);
+    }
+
+    #[ink::test]
+    fn remove_protected_selector_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        diamond._protect_selector([1u8; 4]);
+
+        let remove_facet = FacetCut {
+            hash: cut.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond.diamond_cut(vec![remove_facet], None),
+            Err(DiamondError::RemoveProtectedSelector([1u8; 4]))
+        );
+        let remove_selector = FacetCut {
+            hash: cut.hash,
+            selectors: vec![[2u8; 4]],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond.diamond_cut(vec![remove_selector], None),
+            Err(DiamondError::RemoveProtectedSelector([1u8; 4]))
+        );
+
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[1u8; 4]), Some(cut.hash));
+    }
+
+    #[ink::test]
+    fn protected_selector_can_be_moved_to_another_facet() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        diamond._protect_selector([1u8; 4]);
+
+        let remove_facet = FacetCut {
+            hash: cut.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        let new_facet = facet_cut(2, 1);
+        assert_eq!(diamond.diamond_cut(vec![remove_facet, new_facet.clone()], None), Ok(()));
+
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[1u8; 4]), Some(new_facet.hash));
+    }
+
+    #[ink::test]
+    fn unprotected_selector_can_be_removed() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        diamond._protect_selector([1u8; 4]);
+        diamond._unprotect_selector([1u8; 4]);
+
+        let remove_facet = FacetCut {
+            hash: cut.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove_facet], None), Ok(()));
+
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[1u8; 4]), None);
+    }
+
+    #[ink::test]
+    fn diamond_cut_and_freeze_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+
+        assert_eq!(diamond.diamond_cut_and_freeze(vec![cut.clone()], None), Ok(()));
+
+        assert!(diamond.diamond.frozen);
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[1u8; 4]), Some(cut.hash));
+    }
+
+    #[ink::test]
+    fn diamond_cut_fails_after_freeze() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut_and_freeze(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(2, 2)], None),
+            Err(DiamondError::DiamondFrozen)
+        );
+        assert_eq!(
+            diamond.diamond_cut_and_freeze(vec![facet_cut(2, 2)], None),
+            Err(DiamondError::DiamondFrozen)
+        );
+        let remove = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond.diamond_cut(vec![remove], None),
+            Err(DiamondError::DiamondFrozen)
+        );
+
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[2u8; 4]), None);
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[1u8; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn diamond_is_not_frozen_if_last_cut_fails() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let remove = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![],
+            interfaces: vec![],
+        };
+
+        assert_eq!(
+            diamond.diamond_cut_and_freeze(vec![remove], None),
+            Err(DiamondError::FunctionDoesNotExist)
+        );
+
+        assert!(!diamond.diamond.frozen);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+    }
+
+    #[ink::test]
+    fn only_owner_can_freeze() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        openbrush::test_utils::change_caller(accounts().bob);
+        assert_eq!(
+            diamond.diamond_cut_and_freeze(vec![facet_cut(1, 1)], None),
+            Err(DiamondError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+        assert!(!diamond.diamond.frozen);
+    }
+
+    #[ink::test]
+    fn facet_for_selector_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some(cut.hash));
+        assert_eq!(diamond.facet_for_selector([2; 4]), None);
+        assert!(!diamond.is_immutable([1; 4]));
+        assert!(!diamond.is_immutable([2; 4]));
+    }
+
+    #[ink::test]
+    fn selector_of_diamond_itself_is_immutable() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let self_cut = facet_cut(0xff, 1);
+        diamond._set_self_hash(self_cut.hash);
+        assert_eq!(
+            diamond.diamond_cut(vec![self_cut.clone(), facet_cut(2, 2)], None),
+            Ok(())
+        );
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some(self_cut.hash));
+        assert!(diamond.is_immutable([1; 4]));
+        assert!(!diamond.is_immutable([2; 4]));
+    }
+
+    fn cut_with_action(action: FacetCutAction, hash: u8, selectors: Vec<u8>) -> FacetCutWithAction {
+        FacetCutWithAction {
+            action,
+            hash: [hash; 32].into(),
+            selectors: selectors.into_iter().map(|selector| [selector; 4]).collect(),
+        }
+    }
+
+    #[ink::test]
+    fn diamond_cut_with_add_action_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(
+            diamond.diamond_cut_with_actions(
+                vec![
+                    cut_with_action(FacetCutAction::Add, 1, vec![1, 2]),
+                    cut_with_action(FacetCutAction::Add, 1, vec![3]),
+                ],
+                None
+            ),
+            Ok(())
+        );
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+        assert_eq!(diamond.facet_for_selector([3; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn add_action_for_registered_selector_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Add, 2, vec![1])], None),
+            Err(DiamondError::ReplaceExisting([1u8; 32].into()))
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn replace_action_moves_selector_to_another_facet() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(
+            diamond.diamond_cut(
+                vec![
+                    FacetCut {
+                        hash: [1u8; 32].into(),
+                        selectors: vec![[1; 4], [2; 4]],
+                        interfaces: vec![],
+                    },
+                    facet_cut(2, 3),
+                ],
+                None
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            diamond.diamond_cut_with_actions(
+                vec![
+                    cut_with_action(FacetCutAction::Replace, 2, vec![1]),
+                    cut_with_action(FacetCutAction::Replace, 3, vec![3]),
+                ],
+                None
+            ),
+            Ok(())
+        );
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([2u8; 32].into()));
+        assert_eq!(diamond.facet_for_selector([2; 4]), Some([1u8; 32].into()));
+        assert_eq!(diamond.facet_for_selector([3; 4]), Some([3u8; 32].into()));
+        assert_eq!(
+            diamond.diamond.hash_to_selectors.get(&[1u8; 32].into()),
+            Some(vec![[2; 4]])
+        );
+        assert_eq!(
+            diamond.diamond.hash_to_selectors.get(&[2u8; 32].into()),
+            Some(vec![[1; 4]])
+        );
+    }
+
+    #[ink::test]
+    fn replace_action_for_the_same_facet_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Replace, 1, vec![1])], None),
+            Err(DiamondError::ReplaceSameFacet)
+        );
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Replace, 2, vec![2])], None),
+            Err(DiamondError::FunctionDoesNotExist)
+        );
+    }
+
+    #[ink::test]
+    fn remove_action_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(
+            diamond.diamond_cut(
+                vec![FacetCut {
+                    hash: [1u8; 32].into(),
+                    selectors: vec![[1; 4], [2; 4]],
+                    interfaces: vec![],
+                }],
+                None
+            ),
+            Ok(())
+        );
+
+        // the hash of the remove action is ignored
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Remove, 0, vec![1])], None),
+            Ok(())
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), None);
+        assert_eq!(diamond.facet_for_selector([2; 4]), Some([1u8; 32].into()));
+
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Remove, 0, vec![2])], None),
+            Ok(())
+        );
+        assert_eq!(diamond.facet_for_selector([2; 4]), None);
+        assert_eq!(diamond.diamond.hash_to_selectors.get(&[1u8; 32].into()), None);
+    }
+
+    #[ink::test]
+    fn remove_action_for_unknown_selector_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Remove, 1, vec![2])], None),
+            Err(DiamondError::FunctionDoesNotExist)
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn replacing_all_selectors_of_facet_keeps_its_interfaces() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        diamond._register_interface(cut.hash, INTERFACE_ID);
+
+        assert_eq!(
+            diamond.diamond_cut_with_actions(
+                vec![
+                    cut_with_action(FacetCutAction::Add, 1, vec![2]),
+                    cut_with_action(FacetCutAction::Remove, 0, vec![1]),
+                ],
+                None
+            ),
+            Ok(())
+        );
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), None);
+        assert_eq!(diamond.facet_for_selector([2; 4]), Some([1u8; 32].into()));
+        assert_eq!(diamond.diamond.hash_to_selectors.get(&cut.hash), Some(vec![[2; 4]]));
+        assert!(diamond.supports_interface(INTERFACE_ID));
+    }
+
+    #[ink::test]
+    fn unknown_selector_is_not_registered() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+
+        assert_eq!(diamond._facet_code_hash(&[1; 4]), Ok(cut.hash));
+        assert_eq!(
+            diamond._facet_code_hash(&[2; 4]),
+            Err(DiamondError::FunctionNotRegistered([2; 4]))
+        );
+    }
+
+    #[ink::test]
+    fn revert_error_is_decodable_by_callers() {
+        // `_revert` encodes the error as `Result<(), DiamondError>`,
+        // the caller can decode it with the return type of the called message
+        let encoded = scale::Encode::encode(&Result::<(), DiamondError>::Err(DiamondError::FunctionNotRegistered(
+            [2; 4],
+        )));
+
+        let decoded = <Result<u128, DiamondError> as scale::Decode>::decode(&mut &encoded[..])
+            .expect("encountered invalid error buffer");
+
+        assert_eq!(decoded, Err(DiamondError::FunctionNotRegistered([2; 4])));
+    }
+
+    #[ink::test]
+    fn diamond_cut_override_moves_selector_in_one_call() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let old_facet = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![[1; 4], [2; 4]],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![old_facet.clone()], None), Ok(()));
+
+        assert_eq!(diamond.diamond_cut_override(vec![facet_cut(2, 1)], None), Ok(()));
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([2u8; 32].into()));
+        assert_eq!(diamond.facet_for_selector([2; 4]), Some(old_facet.hash));
+        // the old facet doesn't claim the selector anymore
+        assert_eq!(
+            diamond.diamond.hash_to_selectors.get(&old_facet.hash),
+            Some(vec![[2; 4]])
+        );
+        assert_eq!(
+            diamond.diamond.hash_to_selectors.get(&[2u8; 32].into()),
+            Some(vec![[1; 4]])
+        );
+    }
+
+    #[ink::test]
+    fn diamond_cut_override_removes_facet_without_selectors() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(2, 1)], None),
+            Err(DiamondError::ReplaceExisting([1u8; 32].into()))
+        );
+
+        assert_eq!(diamond.diamond_cut_override(vec![facet_cut(2, 1)], None), Ok(()));
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([2u8; 32].into()));
+        assert_eq!(diamond.diamond.hash_to_selectors.get(&[1u8; 32].into()), None);
+    }
+
+    #[ink::test]
+    fn only_owner_can_override() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        openbrush::test_utils::change_caller(accounts().bob);
+        assert_eq!(
+            diamond.diamond_cut_override(vec![facet_cut(2, 1)], None),
+            Err(DiamondError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn reentrant_diamond_cut_from_init_reverts() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+
+        // the init facet is executed via the delegate call, so it calls the diamond back
+        // with the diamond itself as the caller
+        let diamond_account = ink_env::test::callee::<ink_env::DefaultEnvironment>();
+        openbrush::test_utils::change_caller(diamond_account);
+        // it tries to replace the facet which the outer call is about to remove
+        assert_eq!(
+            diamond.diamond_cut_override(vec![facet_cut(2, 1)], None),
+            Err(DiamondError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(
+            diamond.diamond_cut(
+                vec![FacetCut {
+                    hash: cut.hash,
+                    selectors: vec![],
+                    interfaces: vec![],
+                }],
+                None
+            ),
+            Err(DiamondError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some(cut.hash));
+        assert_eq!(diamond.diamond.hash_to_selectors.get(&cut.hash), Some(vec![[1; 4]]));
+    }
+
+    #[ink::test]
+    fn replace_facet_moves_all_selectors() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let old_facet = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![[1; 4], [2; 4], [3; 4]],
+            interfaces: vec![],
+        };
+        let new_hash: Hash = [2u8; 32].into();
+        assert_eq!(diamond.diamond_cut(vec![old_facet.clone()], None), Ok(()));
+
+        assert_eq!(diamond.replace_facet(old_facet.hash, new_hash), Ok(()));
+
+        for selector in old_facet.selectors.iter() {
+            assert_eq!(diamond.facet_for_selector(*selector), Some(new_hash));
+        }
+        assert_eq!(diamond.diamond.hash_to_selectors.get(&old_facet.hash), None);
+        assert_eq!(
+            diamond.diamond.hash_to_selectors.get(&new_hash),
+            Some(old_facet.selectors.clone())
+        );
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        let events = &emitted_events[emitted_events.len() - 2..];
+        assert_diamond_cut_event(
+            &events[0],
+            old_facet.hash,
+            old_facet.selectors.clone(),
+            FacetCutAction::Remove,
+        );
+        assert_diamond_cut_event(&events[1], new_hash, old_facet.selectors, FacetCutAction::Add);
+    }
+
+    #[ink::test]
+    fn replace_facet_keeps_selectors_and_interfaces() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(1, 1), facet_cut(2, 2)], None),
+            Ok(())
+        );
+        diamond._register_interface([1u8; 32].into(), INTERFACE_ID);
+        diamond._protect_selector([1; 4]);
+
+        assert_eq!(diamond.replace_facet([1u8; 32].into(), [2u8; 32].into()), Ok(()));
+
+        assert_eq!(
+            diamond.diamond.hash_to_selectors.get(&[2u8; 32].into()),
+            Some(vec![[2; 4], [1; 4]])
+        );
+        assert!(diamond.supports_interface(INTERFACE_ID));
+        assert_eq!(
+            diamond.diamond.hash_to_interfaces.get(&[2u8; 32].into()),
+            Some(vec![INTERFACE_ID])
+        );
+        assert_eq!(diamond.diamond.hash_to_interfaces.get(&[1u8; 32].into()), None);
+    }
+
+    #[ink::test]
+    fn replace_facet_fails_for_unknown_or_same_facet() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert_eq!(
+            diamond.replace_facet([3u8; 32].into(), [2u8; 32].into()),
+            Err(DiamondError::FunctionDoesNotExist)
+        );
+        assert_eq!(
+            diamond.replace_facet([1u8; 32].into(), [1u8; 32].into()),
+            Err(DiamondError::ReplaceSameFacet)
+        );
+        assert_eq!(
+            diamond.replace_facet([1u8; 32].into(), [0u8; 32].into()),
+            Err(DiamondError::EmptyCodeHash)
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn only_owner_can_replace_facet() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        openbrush::test_utils::change_caller(accounts().bob);
+        assert_eq!(
+            diamond.replace_facet([1u8; 32].into(), [2u8; 32].into()),
+            Err(DiamondError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+    }
+
+    fn init_call(hash: u8, force: bool) -> InitCall {
+        InitCall {
+            hash: [hash; 32].into(),
+            selector: [0xff; 4],
+            input: Vec::new(),
+            force,
+            external: false,
+        }
+    }
+
+    #[ink::test]
+    fn facet_is_not_initialized_by_default() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert!(!diamond.is_facet_initialized([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn init_call_marks_facet_initialized() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        // `_init_call` can't be executed off-chain, so the cut is applied without it
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(1, 1)], &Some(init_call(1, false))),
+            Ok(())
+        );
+
+        assert!(diamond.is_facet_initialized([1u8; 32].into()));
+        assert!(!diamond.is_facet_initialized([2u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn init_call_emits_event_after_cut() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(1, 1)], &Some(init_call(1, false))),
+            Ok(())
+        );
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+        assert_diamond_cut_event(&emitted_events[0], [1u8; 32].into(), vec![[1; 4]], FacetCutAction::Add);
+        assert_diamond_init_executed_event(&emitted_events[1], [1u8; 32].into(), [0xff; 4]);
+    }
+
+    #[ink::test]
+    fn cut_without_init_call_does_not_emit_init_event() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 1);
+        assert_diamond_cut_event(&emitted_events[0], [1u8; 32].into(), vec![[1; 4]], FacetCutAction::Add);
+    }
+
+    #[ink::test]
+    fn readded_facet_is_not_initialized_twice() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(1, 1)], &Some(init_call(1, false))),
+            Ok(())
+        );
+
+        // remove the facet
+        let remove = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove], None), Ok(()));
+        assert_eq!(diamond.facet_for_selector([1; 4]), None);
+        assert!(diamond.is_facet_initialized([1u8; 32].into()));
+
+        // adding it again with the init call fails and doesn't apply the cut
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(1, 1)], Some(init_call(1, false))),
+            Err(DiamondError::FacetAlreadyInitialized([1u8; 32].into()))
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), None);
+
+        // adding it again without the init call works
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn forced_init_call_initializes_facet_again() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(1, 1)], &Some(init_call(1, false))),
+            Ok(())
+        );
+
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(1, 2)], &Some(init_call(1, true))),
+            Ok(())
+        );
+        assert_eq!(diamond.facet_for_selector([2; 4]), Some([1u8; 32].into()));
+        assert!(diamond.is_facet_initialized([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn add_immutable_functions_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let self_hash: Hash = [0xff; 32].into();
+        diamond._set_self_hash(self_hash);
+
+        assert_eq!(diamond._add_immutable_functions(vec![[1; 4]]), Ok(()));
+        assert_eq!(diamond._add_immutable_functions(vec![[1; 4], [2; 4]]), Ok(()));
+
+        assert!(diamond.is_immutable([1; 4]));
+        assert!(diamond.is_immutable([2; 4]));
+        assert_eq!(diamond._facet_code_hash(&[1; 4]), Ok(self_hash));
+        assert_eq!(
+            diamond.diamond.hash_to_selectors.get(&self_hash),
+            Some(vec![[1; 4], [2; 4]])
+        );
+    }
+
+    #[ink::test]
+    fn add_immutable_functions_without_self_hash_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(
+            diamond._add_immutable_functions(vec![[1; 4]]),
+            Err(DiamondError::EmptyCodeHash)
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), None);
+    }
+
+    #[ink::test]
+    fn add_immutable_function_registered_for_facet_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        diamond._set_self_hash([0xff; 32].into());
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert_eq!(
+            diamond._add_immutable_functions(vec![[2; 4], [1; 4]]),
+            Err(DiamondError::ReplaceExisting([1u8; 32].into()))
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+        assert_eq!(diamond.facet_for_selector([2; 4]), None);
+    }
+
+    #[ink::test]
+    fn cut_of_immutable_function_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let self_hash: Hash = [0xff; 32].into();
+        diamond._set_self_hash(self_hash);
+        assert_eq!(diamond._add_immutable_functions(vec![[1; 4], [2; 4]]), Ok(()));
+
+        // removing the facet of the diamond itself
+        let remove_self = FacetCut {
+            hash: self_hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond.diamond_cut(vec![remove_self], None),
+            Err(DiamondError::ImmutableFunction([1; 4]))
+        );
+        // removing one of its selectors
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(0xff, 2)], None),
+            Err(DiamondError::ImmutableFunction([1; 4]))
+        );
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Remove, 0, vec![2])], None),
+            Err(DiamondError::ImmutableFunction([2; 4]))
+        );
+        // moving the selector to another facet
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Replace, 1, vec![1])], None),
+            Err(DiamondError::ImmutableFunction([1; 4]))
+        );
+        assert_eq!(
+            diamond.diamond_cut_override(vec![facet_cut(1, 1)], None),
+            Err(DiamondError::ImmutableFunction([1; 4]))
+        );
+
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some(self_hash));
+        assert_eq!(diamond.facet_for_selector([2; 4]), Some(self_hash));
+    }
+
+    #[ink::test]
+    fn init_call_for_registered_facet_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        // the facet is registered by the previous cut
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(2, 2)], &Some(init_call(1, false))),
+            Ok(())
+        );
+        // the facet is registered by the same cut
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(3, 3)], &Some(init_call(3, false))),
+            Ok(())
+        );
+        // the code of the diamond itself
+        diamond._set_self_hash([0xff; 32].into());
+        assert_eq!(
+            diamond._apply_diamond_cut(&vec![facet_cut(4, 4)], &Some(init_call(0xff, false))),
+            Ok(())
+        );
+    }
+
+    #[ink::test]
+    fn init_call_for_unregistered_hash_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(1, 1)], Some(init_call(2, false))),
+            Err(DiamondError::InitHashNotRegistered([2u8; 32].into()))
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), None);
+        assert!(!diamond.is_facet_initialized([2u8; 32].into()));
+
+        // the facet removed by the cut is not registered anymore
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+        let remove = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond.diamond_cut(vec![remove], Some(init_call(1, false))),
+            Err(DiamondError::InitHashNotRegistered([1u8; 32].into()))
+        );
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn external_init_call_for_unregistered_hash_works() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let init = InitCall {
+            external: true,
+            ..init_call(2, false)
+        };
+
+        assert_eq!(diamond._apply_diamond_cut(&vec![facet_cut(1, 1)], &Some(init)), Ok(()));
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some([1u8; 32].into()));
+        assert!(diamond.is_facet_initialized([2u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn counters_are_zero_by_default() {
+        let diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(diamond.total_selectors(), 0);
+        assert_eq!(diamond.total_facets(), 0);
+    }
+
+    #[ink::test]
+    fn counters_follow_sequence_of_cuts() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let facet = |hash: u8, selectors: Vec<u8>| {
+            FacetCut {
+                hash: [hash; 32].into(),
+                selectors: selectors.into_iter().map(|selector| [selector; 4]).collect(),
+                interfaces: vec![],
+            }
+        };
+
+        // add two facets
+        assert_eq!(
+            diamond.diamond_cut(vec![facet(1, vec![1, 2, 3]), facet(2, vec![4])], None),
+            Ok(())
+        );
+        assert_eq!(diamond.total_selectors(), 4);
+        assert_eq!(diamond.total_facets(), 2);
+
+        // shrink the first facet and extend the second one
+        assert_eq!(
+            diamond.diamond_cut(vec![facet(1, vec![1]), facet(2, vec![4, 5])], None),
+            Ok(())
+        );
+        assert_eq!(diamond.total_selectors(), 3);
+        assert_eq!(diamond.total_facets(), 2);
+
+        // move the selector between facets within one cut
+        assert_eq!(
+            diamond.diamond_cut(vec![facet(2, vec![5]), facet(3, vec![4])], None),
+            Ok(())
+        );
+        assert_eq!(diamond.total_selectors(), 3);
+        assert_eq!(diamond.total_facets(), 3);
+
+        // remove the facet with an empty list of selectors
+        assert_eq!(diamond.diamond_cut(vec![facet(3, vec![])], None), Ok(()));
+        assert_eq!(diamond.total_selectors(), 2);
+        assert_eq!(diamond.total_facets(), 2);
+
+        // replace the facet with a new one
+        assert_eq!(diamond.replace_facet([1u8; 32].into(), [4u8; 32].into()), Ok(()));
+        assert_eq!(diamond.total_selectors(), 2);
+        assert_eq!(diamond.total_facets(), 2);
+
+        // override and action based cuts keep the counters in sync too
+        assert_eq!(diamond.diamond_cut_override(vec![facet(4, vec![1, 5])], None), Ok(()));
+        assert_eq!(diamond.total_selectors(), 2);
+        assert_eq!(diamond.total_facets(), 1);
+        assert_eq!(
+            diamond.diamond_cut_with_actions(vec![cut_with_action(FacetCutAction::Remove, 0, vec![1, 5])], None),
+            Ok(())
+        );
+        assert_eq!(diamond.total_selectors(), 0);
+        assert_eq!(diamond.total_facets(), 0);
+    }
+
+    #[ink::test]
+    fn failed_cut_does_not_change_counters() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(2, 2), facet_cut(3, 1)], None),
+            Err(DiamondError::ReplaceExisting([1u8; 32].into()))
+        );
+        assert_eq!(diamond.total_selectors(), 1);
+        assert_eq!(diamond.total_facets(), 1);
+    }
+
+    #[ink::test]
+    fn duplicated_selectors_are_counted_once() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![[1; 4], [1; 4]],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        assert_eq!(diamond.total_selectors(), 1);
+
+        let remove = FacetCut {
+            hash: cut.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove], None), Ok(()));
+        assert_eq!(diamond.total_selectors(), 0);
+        assert_eq!(diamond.total_facets(), 0);
+    }
+
+    #[ink::test]
+    fn paused_facet_reverts_through_fallback() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(
+            diamond.diamond_cut(vec![facet_cut(1, 1), facet_cut(2, 2)], None),
+            Ok(())
+        );
+        let paused: Hash = [1u8; 32].into();
+
+        assert_eq!(diamond.pause_facet(paused), Ok(()));
+
+        assert!(diamond.is_facet_paused(paused));
+        // `_fallback` resolves the facet with `_facet_code_hash` before delegating the call
+        assert_eq!(
+            diamond._facet_code_hash(&[1; 4]),
+            Err(DiamondError::FacetPaused(paused))
+        );
+        assert_eq!(diamond._facet_code_hash(&[2; 4]), Ok([2u8; 32].into()));
+        // the selectors stay mapped to the paused facet
+        assert_eq!(diamond.facet_for_selector([1; 4]), Some(paused));
+
+        assert_eq!(diamond.unpause_facet(paused), Ok(()));
+
+        assert!(!diamond.is_facet_paused(paused));
+        assert_eq!(diamond._facet_code_hash(&[1; 4]), Ok(paused));
+    }
+
+    #[ink::test]
+    fn pause_not_registered_facet_should_fail() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+
+        assert_eq!(
+            diamond.pause_facet([1u8; 32].into()),
+            Err(DiamondError::FunctionDoesNotExist)
+        );
+        assert!(!diamond.is_facet_paused([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn only_owner_can_pause_facet() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        assert_eq!(diamond.diamond_cut(vec![facet_cut(1, 1)], None), Ok(()));
+
+        openbrush::test_utils::change_caller(accounts().bob);
+        assert_eq!(
+            diamond.pause_facet([1u8; 32].into()),
+            Err(DiamondError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+        assert!(!diamond.is_facet_paused([1u8; 32].into()));
+
+        openbrush::test_utils::change_caller(accounts().alice);
+        assert_eq!(diamond.pause_facet([1u8; 32].into()), Ok(()));
+        openbrush::test_utils::change_caller(accounts().bob);
+        assert_eq!(
+            diamond.unpause_facet([1u8; 32].into()),
+            Err(DiamondError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+        assert!(diamond.is_facet_paused([1u8; 32].into()));
+    }
+
+    #[ink::test]
+    fn removed_facet_is_not_paused() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = facet_cut(1, 1);
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        assert_eq!(diamond.pause_facet(cut.hash), Ok(()));
+
+        let remove = FacetCut {
+            hash: cut.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove], None), Ok(()));
+        assert!(!diamond.is_facet_paused(cut.hash));
+
+        // the facet added again starts unpaused
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+        assert_eq!(diamond._facet_code_hash(&[1; 4]), Ok(cut.hash));
+    }
+}
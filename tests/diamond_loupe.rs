@@ -87,6 +87,7 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[0u8; 4]],
+            interfaces: vec![],
         };
         // act
         assert_eq!(
@@ -105,6 +106,7 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [0u8; 32].into(),
             selectors: vec![[0u8; 4]],
+            interfaces: vec![],
         };
         // assert
         assert_eq!(
@@ -121,6 +123,7 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[0u8; 4]],
+            interfaces: vec![],
         };
         let selectors: Vec<Selector> = vec![];
         assert_eq!(diamond.facet_function_selectors(facet_cut.hash), selectors);
@@ -140,6 +143,7 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[0u8; 4]],
+            interfaces: vec![],
         };
         assert_eq!(diamond.facet_code_hashes(), vec![]);
         // act
@@ -159,6 +163,7 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[0u8; 4]],
+            interfaces: vec![],
         };
         assert_eq!(diamond.facet_code_hash(facet_cut.selectors[0]), Option::None);
         // act
@@ -182,6 +187,7 @@ mod diamond {
         let mut facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[1u8; 4]],
+            interfaces: vec![],
         };
         assert_eq!(
             diamond.diamond_cut(vec![facet_cut.clone()], Option::None),
@@ -207,6 +213,7 @@ mod diamond {
         let mut facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[1u8; 4], [2u8; 4], [3u8; 4]],
+            interfaces: vec![],
         };
         assert_eq!(
             diamond.diamond_cut(vec![facet_cut.clone()], Option::None),
@@ -233,6 +240,7 @@ mod diamond {
         let mut facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[1u8; 4], [2u8; 4], [3u8; 4]],
+            interfaces: vec![],
         };
 
         assert_eq!(
@@ -260,6 +268,7 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[1u8; 4]],
+            interfaces: vec![],
         };
         assert_eq!(diamond.facets().len(), 0);
         assert_eq!(
@@ -271,6 +280,7 @@ mod diamond {
         let facet_cut_new = FacetCut {
             hash: [2u8; 32].into(),
             selectors: vec![[2u8; 4]],
+            interfaces: vec![],
         };
         let v = vec![facet_cut, facet_cut_new];
         assert_eq!(diamond.diamond_cut(v.clone(), Option::None), Result::Ok(()));
@@ -288,6 +298,7 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[1u8; 4]],
+            interfaces: vec![],
         };
         assert_eq!(diamond.facets().len(), 0);
         assert_eq!(
@@ -298,6 +309,7 @@ mod diamond {
         let facet_cut_new = FacetCut {
             hash: [2u8; 32].into(),
             selectors: vec![[1u8; 4]],
+            interfaces: vec![],
         };
         let v = vec![facet_cut.clone(), facet_cut_new];
         // act
@@ -316,10 +328,12 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [1u8; 32].into(),
             selectors: vec![[1u8; 4]],
+            interfaces: vec![],
         };
         let mut facet_cut_new = FacetCut {
             hash: [2u8; 32].into(),
             selectors: vec![[2u8; 4]],
+            interfaces: vec![],
         };
         let mut v = vec![facet_cut.clone(), facet_cut_new.clone()];
         assert_eq!(diamond.facets().len(), 0);
@@ -342,10 +356,12 @@ mod diamond {
         let facet_cut = FacetCut {
             hash: [3u8; 32].into(),
             selectors: vec![[1u8; 4]],
+            interfaces: vec![],
         };
         let facet_cut_new = FacetCut {
             hash: [2u8; 32].into(),
             selectors: vec![[2u8; 4]],
+            interfaces: vec![],
         };
         let mut v = vec![facet_cut.clone(), facet_cut_new];
         assert_eq!(diamond.facets().len(), 0);
@@ -358,4 +374,46 @@ mod diamond {
         assert_eq!(diamond.facets().len(), 1);
         assert_eq!(diamond.facets(), vec![facet_cut]);
     }
+
+    #[ink::test]
+    fn loupe_matches_state_after_several_cuts() {
+        let accounts = setup();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        let first = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![[1u8; 4], [2u8; 4]],
+            interfaces: vec![],
+        };
+        let second = FacetCut {
+            hash: [2u8; 32].into(),
+            selectors: vec![[3u8; 4]],
+            interfaces: vec![],
+        };
+        let third = FacetCut {
+            hash: [3u8; 32].into(),
+            selectors: vec![[4u8; 4]],
+            interfaces: vec![],
+        };
+        assert_eq!(
+            diamond.diamond_cut(vec![first.clone(), second.clone(), third.clone()], Option::None),
+            Result::Ok(())
+        );
+        // act
+        let remove_first = FacetCut {
+            hash: first.hash,
+            selectors: vec![],
+            interfaces: vec![],
+        };
+        assert_eq!(diamond.diamond_cut(vec![remove_first], Option::None), Result::Ok(()));
+        // assert
+        assert_eq!(diamond.facets(), vec![third.clone(), second.clone()]);
+        assert_eq!(diamond.facet_code_hashes(), vec![third.hash, second.hash]);
+        assert_eq!(diamond.facet_function_selectors(first.hash), Vec::<Selector>::new());
+        assert_eq!(diamond.facet_function_selectors(second.hash), second.selectors);
+        assert_eq!(diamond.facet_code_hash([1u8; 4]), None);
+        assert_eq!(diamond.facet_code_hash([2u8; 4]), None);
+        assert_eq!(diamond.facet_code_hash([3u8; 4]), Some(second.hash));
+        assert_eq!(diamond.facet_code_hash([4u8; 4]), Some(third.hash));
+    }
 }
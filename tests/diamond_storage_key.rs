@@ -0,0 +1,92 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "diamond")]
+#[openbrush::contract]
+mod diamond_storage_key {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::diamond::*,
+        test_utils::accounts,
+        traits::{
+            OccupyStorage,
+            Storage,
+        },
+    };
+
+    const LEGACY_STORAGE_KEY: u32 = openbrush::storage_unique_key!(LegacyDiamondData);
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct DiamondContract {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        diamond: diamond::Data,
+        #[storage_field]
+        legacy: diamond::Data<(), LEGACY_STORAGE_KEY>,
+    }
+
+    impl DiamondContract {
+        #[ink(constructor)]
+        pub fn new(owner: AccountId) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(owner);
+            })
+        }
+
+        #[ink(message, payable, selector = _)]
+        pub fn forward(&self) {
+            self._fallback()
+        }
+    }
+
+    impl Ownable for DiamondContract {}
+
+    impl Diamond for DiamondContract {}
+
+    #[ink::test]
+    fn diamond_storages_use_different_keys() {
+        assert_eq!(<diamond::Data as OccupyStorage>::KEY, diamond::STORAGE_KEY);
+        assert_eq!(
+            <diamond::Data<(), LEGACY_STORAGE_KEY> as OccupyStorage>::KEY,
+            LEGACY_STORAGE_KEY
+        );
+        assert_ne!(diamond::STORAGE_KEY, LEGACY_STORAGE_KEY);
+    }
+
+    #[ink::test]
+    fn diamond_cut_does_not_touch_storage_with_custom_key() {
+        let mut diamond = DiamondContract::new(accounts().alice);
+        let cut = FacetCut {
+            hash: [1u8; 32].into(),
+            selectors: vec![[1u8; 4]],
+            interfaces: vec![],
+        };
+
+        assert_eq!(diamond.diamond_cut(vec![cut.clone()], None), Ok(()));
+
+        assert_eq!(diamond.diamond.selector_to_hash.get(&[1u8; 4]), Some(cut.hash));
+        assert_eq!(diamond.legacy.selector_to_hash.get(&[1u8; 4]), None);
+    }
+}
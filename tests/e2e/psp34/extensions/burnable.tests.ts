@@ -59,6 +59,8 @@ describe('MY_PSP34_BURNABLE', () => {
 
     await expect(query.balanceOf(sender.address)).to.have.output(3)
 
+    await expect(contract.withSigner(alice).tx.burn(sender.address, IdBuilder.U8(0))).to.eventually.be.rejected
+    await expect(contract.tx.approve(alice.address, IdBuilder.U8(0), true)).to.eventually.be.fulfilled
     await expect(contract.withSigner(alice).tx.burn(sender.address, IdBuilder.U8(0))).to.eventually.be.fulfilled
 
     await expect(query.balanceOf(sender.address)).to.have.output(2)
@@ -92,7 +92,7 @@ describe('MY_PSP34_ENUMERABLE', () => {
     expect((await query.tokenByIndex(0)).value.ok).to.be.deep.equal(IdBuilderReturns.U8(1))
     expect((await query.tokenByIndex(1)).value.ok).to.be.deep.equal(IdBuilderReturns.U8(2))
 
-    await expect(contract.tx.burn(alice.address, psp34_id2)).to.eventually.be.fulfilled
+    await expect(contract.withSigner(alice).tx.burn(alice.address, psp34_id2)).to.eventually.be.fulfilled
 
     await expect(contract.tx.ownersTokenByIndex(alice.address, 0)).to.eventually.be.fulfilled
     await expect(contract.tx.ownersTokenByIndex(alice.address, 1)).to.eventually.be.rejected
@@ -0,0 +1,138 @@
+import {expect, getSigners} from '../helpers'
+import {AbiMessage} from '@polkadot/api-contract/types'
+import {ApiPromise} from '@polkadot/api'
+import ConstructorsFeeFacet from '../../../typechain-generated/constructors/my_psp22_fee_facet'
+import ContractFeeFacet from '../../../typechain-generated/contracts/my_psp22_fee_facet'
+import ConstructorsDiamond from '../../../typechain-generated/constructors/my_diamond'
+import ContractDiamond from '../../../typechain-generated/contracts/my_diamond'
+
+const getSelectorsFromMessages = (messages: AbiMessage[]): number[][] => {
+  return messages.map((message) => {
+    return message.selector.toU8a() as unknown as number[]
+  })
+}
+
+const getSelectorByName = (messages: AbiMessage[], name: string): number[] => {
+  return messages.filter((message) => {
+    return message.identifier == name
+  })[0].selector.toU8a() as unknown as number[]
+}
+
+describe('DIAMOND_FEE_FACET', () => {
+  async function setup() {
+    const api = await ApiPromise.create()
+
+    const signers = getSigners()
+    const defaultSigner = signers[2]
+    const alice = signers[0]
+    const bob = signers[1]
+
+    const facetFactory = new ConstructorsFeeFacet(api, defaultSigner)
+    const facetAddress = (await facetFactory.new()).address
+    const facet = new ContractFeeFacet(facetAddress, defaultSigner, api)
+
+    const diamondFactory = new ConstructorsDiamond(api, defaultSigner)
+    const diamondAddress = (await diamondFactory.new(defaultSigner.address)).address
+    const diamond = new ContractDiamond(diamondAddress, defaultSigner, api)
+
+    // add the fee facet and call its init function
+    const abi = facet.abi
+    const hash = abi.info.source.wasmHash.toString()
+    const init = getSelectorByName(abi.messages, 'init_psp22_fee')
+    const cut = [{hash, selectors: getSelectorsFromMessages(abi.messages), interfaces: []}]
+    await expect(diamond.withSigner(defaultSigner).tx.diamondCut(cut, {hash, selector: init, input: [], force: false, external: false})).to.eventually.be.fulfilled
+
+    // the facet is called through the diamond
+    // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+    // @ts-ignore
+    const proxy: ContractFeeFacet = facet.withAddress(diamond.address)
+
+    return {
+      api,
+      defaultSigner,
+      alice,
+      bob,
+      hash,
+      diamond,
+      proxy,
+      close: async () => {
+        await api.disconnect()
+      }
+    }
+  }
+
+  it('Fee facet is initialized through the diamond', async () => {
+    const { diamond, proxy, hash, defaultSigner, close } = await setup()
+
+    await expect(proxy.query.balanceOf(defaultSigner.address)).to.bnToNumber(1000)
+    await expect(proxy.query.treasury()).to.output(defaultSigner.address)
+    await expect(proxy.query.feeRate()).to.output(0)
+
+    // the storage of the fee doesn't overlap with the storages of the diamond
+    await expect(diamond.query.owner()).to.output(defaultSigner.address)
+    expect((await diamond.query.facetCodeHashes()).value).to.be.deep.equal([hash])
+
+    await close()
+  })
+
+  it('Transfer charges the fee to the treasury', async () => {
+    const { proxy, defaultSigner, alice, bob, close } = await setup()
+
+    // 1% fee
+    await expect(proxy.withSigner(defaultSigner).tx.setFee(100)).to.eventually.be.fulfilled
+    await expect(proxy.withSigner(defaultSigner).tx.setTreasury(bob.address)).to.eventually.be.fulfilled
+
+    await expect(proxy.withSigner(defaultSigner).tx.transfer(alice.address, 500, [])).to.eventually.be.fulfilled
+
+    await expect(proxy.query.balanceOf(defaultSigner.address)).to.bnToNumber(500)
+    await expect(proxy.query.balanceOf(alice.address)).to.bnToNumber(495)
+    await expect(proxy.query.balanceOf(bob.address)).to.bnToNumber(5)
+    await expect(proxy.query.feesAccrued()).to.bnToNumber(5)
+    await expect(proxy.query.totalSupply()).to.bnToNumber(1000)
+
+    await close()
+  })
+
+  it('Transfer from charges the fee to the treasury', async () => {
+    const { proxy, defaultSigner, alice, bob, close } = await setup()
+
+    await expect(proxy.withSigner(defaultSigner).tx.setFee(1000)).to.eventually.be.fulfilled
+    await expect(proxy.withSigner(defaultSigner).tx.setTreasury(bob.address)).to.eventually.be.fulfilled
+    await expect(proxy.withSigner(defaultSigner).tx.approve(alice.address, 100)).to.eventually.be.fulfilled
+
+    await expect(proxy.withSigner(alice).tx.transferFrom(defaultSigner.address, alice.address, 100, [])).to.eventually.be.fulfilled
+
+    await expect(proxy.query.balanceOf(alice.address)).to.bnToNumber(90)
+    await expect(proxy.query.balanceOf(bob.address)).to.bnToNumber(10)
+    await expect(proxy.query.allowance(defaultSigner.address, alice.address)).to.bnToNumber(0)
+
+    await close()
+  })
+
+  it('Only the owner of the diamond can set the fee', async () => {
+    const { proxy, alice, defaultSigner, close } = await setup()
+
+    await expect(proxy.withSigner(alice).tx.setFee(100)).to.eventually.be.rejected
+    await expect(proxy.withSigner(alice).tx.setTreasury(alice.address)).to.eventually.be.rejected
+    await expect(proxy.withSigner(defaultSigner).tx.setFee(10001)).to.eventually.be.rejected
+
+    await expect(proxy.query.feeRate()).to.output(0)
+    await expect(proxy.query.treasury()).to.output(defaultSigner.address)
+
+    await close()
+  })
+
+  it('Fee data survives the ownership transfer of the diamond', async () => {
+    const { diamond, proxy, defaultSigner, alice, close } = await setup()
+
+    await expect(proxy.withSigner(defaultSigner).tx.setFee(100)).to.eventually.be.fulfilled
+    await expect(diamond.withSigner(defaultSigner).tx.transferOwnership(alice.address)).to.eventually.be.fulfilled
+
+    // ownable storage is shared, so the new owner of the diamond manages the fee
+    await expect(proxy.withSigner(defaultSigner).tx.setFee(200)).to.eventually.be.rejected
+    await expect(proxy.withSigner(alice).tx.setFee(200)).to.eventually.be.fulfilled
+    await expect(proxy.query.feeRate()).to.output(200)
+
+    await close()
+  })
+})
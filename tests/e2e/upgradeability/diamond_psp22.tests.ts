@@ -188,7 +188,7 @@ describe('DIAMOND_PSP22', () => {
 
     const psp22Init = getSelectorByName(psp22Messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(psp22Messages)
-    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
@@ -196,7 +196,7 @@ describe('DIAMOND_PSP22', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     // patch methods
     const proxyPSP22 = setupProxy(psp22Facet, diamondContract.address)
@@ -213,10 +213,10 @@ describe('DIAMOND_PSP22', () => {
     const metadataInit = getSelectorByName(metadataMessages, 'init_metadata')
     const metadataSelectors = getSelectorsFromMessages(metadataMessages)
 
-    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors}]
+    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors, interfaces: []}]
 
     // add metadata facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: []})).to.eventually.be
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: [], force: false, external: false})).to.eventually.be
       .fulfilled
 
     // patch methods
@@ -229,8 +229,8 @@ describe('DIAMOND_PSP22', () => {
     // Test Loupe
 
     expect((await diamondContract.query.facets()).value).to.be.deep.equal([
-      { hash: psp22Hash, selectors: getSelectorsFromMessagesString(psp22Messages) },
-      { hash: metadataHash, selectors: getSelectorsFromMessagesString(metadataMessages) }
+      { hash: psp22Hash, selectors: getSelectorsFromMessagesString(psp22Messages), interfaces: [] },
+      { hash: metadataHash, selectors: getSelectorsFromMessagesString(metadataMessages), interfaces: [] }
     ])
 
     expect((await diamondContract.query.facetFunctionSelectors(metadataHash)).value).to.be.deep.equal(getSelectorsFromMessagesString(metadataMessages))
@@ -246,6 +246,31 @@ describe('DIAMOND_PSP22', () => {
     await closeDiamond()
   })
 
+  it('Facet getters return values through the diamond', async () => {
+    const { contract: psp22Facet, abi, defaultSigner, alice, close: closePSP22 } = await setupPSP22Facet()
+
+    const psp22Hash = abi.info.source.wasmHash.toString()
+    const psp22Messages = abi.messages
+
+    const psp22Init = getSelectorByName(psp22Messages, 'init_psp22')
+    const psp22Cut = [{hash: psp22Hash, selectors: getSelectorsFromMessages(psp22Messages), interfaces: []}]
+
+    const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
+
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: [], force: false, external: false})).to.eventually.be.fulfilled
+
+    const proxy = setupProxy(psp22Facet, diamondContract.address)
+
+    // the fallback delegates as a tail call, so the output of the facet is returned to the caller as is
+    await expect(proxy.withSigner(defaultSigner).tx.approve(alice.address, 300)).to.eventually.be.fulfilled
+    await expect(proxy.query.allowance(defaultSigner.address, alice.address)).to.bnToNumber(300)
+    await expect(proxy.query.totalSupply()).to.bnToNumber(1000)
+    await expect(proxy.query.balanceOf(alice.address)).to.bnToNumber(0)
+
+    await closePSP22()
+    await closeDiamond()
+  })
+
   it('Only owner can call diamond cut', async () => {
     // abi of psp22 facet
     const { abi, alice, close: closePSP22 } = await setupPSP22Facet()
@@ -255,13 +280,13 @@ describe('DIAMOND_PSP22', () => {
 
     const initSelector = getSelectorByName(messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(messages)
-    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, bob: wrongSigner, close: closeDiamond } = await setupDiamond(alice.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(wrongSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: []})).to.eventually.be.rejected
+    await expect(diamondContract.withSigner(wrongSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: [], force: false, external: false})).to.eventually.be.rejected
 
     await closePSP22()
     await closeDiamond()
@@ -276,7 +301,7 @@ describe('DIAMOND_PSP22', () => {
 
     const initSelector = getSelectorByName(messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(messages)
-    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, alice, close: closeDiamond } = await setupDiamond(defaultSigner.address)
@@ -284,20 +309,20 @@ describe('DIAMOND_PSP22', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     // we will upgrade to psp22_2
     const { contract: newPsp22, abi: newPsp22Abi, close: closePSP22V2 } = await setupPSP22FacetV2()
 
     // first we need to remove psp22 and replace with psp22_2
-    const facetCutRemove = [{hash: psp22Hash, selectors: []}]
+    const facetCutRemove = [{hash: psp22Hash, selectors: [], interfaces: []}]
     await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCutRemove, null)).to.eventually.be.fulfilled
 
     const psp22NewHash = newPsp22Abi.info.source.wasmHash.toString()
     const messagesNew = newPsp22Abi.messages
 
     const psp22NewSelectors = getSelectorsFromMessages(messagesNew)
-    const facetCutNew = [{hash: psp22NewHash, selectors: psp22NewSelectors}]
+    const facetCutNew = [{hash: psp22NewHash, selectors: psp22NewSelectors, interfaces: []}]
 
     // add new psp22 facet, without init function
     await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCutNew, null)).to.eventually.be.fulfilled
@@ -330,7 +355,7 @@ describe('DIAMOND_PSP22', () => {
 
     const initSelector = getSelectorByName(messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(messages)
-    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
@@ -338,14 +363,14 @@ describe('DIAMOND_PSP22', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     const { abi: newPsp22Abi, close: closePSP22V2 } = await setupPSP22FacetV2()
     const hashReplace = newPsp22Abi.info.source.wasmHash.toString()
     const messagesReplace = newPsp22Abi.messages
 
     const replaceSelector = getSelectorByName(messagesReplace, 'PSP22::transfer_from')
-    const facetCutReplace = [{hash: hashReplace, selectors: [replaceSelector]}]
+    const facetCutReplace = [{hash: hashReplace, selectors: [replaceSelector], interfaces: []}]
 
     // replace functions
     await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCutReplace, null)).to.eventually.be.rejected
@@ -364,7 +389,7 @@ describe('DIAMOND_PSP22', () => {
 
     const initSelector = getSelectorByName(messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(messages)
-    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
@@ -372,7 +397,7 @@ describe('DIAMOND_PSP22', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     // patch methods
     const proxy = setupProxy(psp22Facet, diamondContract.address)
@@ -381,7 +406,7 @@ describe('DIAMOND_PSP22', () => {
     await expect(proxy.query.balanceOf(defaultSigner.address)).to.bnToNumber(1000)
 
     // we will remove the psp22 facet
-    const facetCutRemove = [{hash: psp22Hash, selectors: []}]
+    const facetCutRemove = [{hash: psp22Hash, selectors: [], interfaces: []}]
 
     // remove facet
     await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCutRemove, null)).to.eventually.be.fulfilled
@@ -405,7 +430,7 @@ describe('DIAMOND_PSP22', () => {
 
     const initSelector = getSelectorByName(messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(messages)
-    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
@@ -413,7 +438,7 @@ describe('DIAMOND_PSP22', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     // add metadata facet
     const { abi: metadataAbi, close: closePSP22Metadata } = await setupPSP22MetadataFacet()
@@ -428,14 +453,14 @@ describe('DIAMOND_PSP22', () => {
       })
     )
 
-    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors}]
+    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors, interfaces: []}]
 
     // add metadata facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: []})).to.eventually.be
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: [], force: false, external: false})).to.eventually.be
       .fulfilled
 
     // we will remove the metadata facet
-    const facetCutRemove = [{hash: metadataHash, selectors: []}]
+    const facetCutRemove = [{hash: metadataHash, selectors: [], interfaces: []}]
 
     // remove facet
     await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCutRemove, null)).to.eventually.be.fulfilled
@@ -462,7 +487,7 @@ describe('DIAMOND_PSP22', () => {
 
     const initSelector = getSelectorByName(messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(messages)
-    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const facetCut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
@@ -470,7 +495,7 @@ describe('DIAMOND_PSP22', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCut, {hash: psp22Hash, selector: initSelector, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     // add metadata facet
     const { abi: metadataAbi, close: closePSP22Metadata } = await setupPSP22MetadataFacet()
@@ -485,13 +510,13 @@ describe('DIAMOND_PSP22', () => {
       })
     )
 
-    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors}]
+    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors, interfaces: []}]
 
     // add metadata facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: []})).to.eventually.be
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: [], force: false, external: false})).to.eventually.be
 
     // we will remove the psp22 facet
-    const facetCutRemove = [{hash: psp22Hash, selectors: []}]
+    const facetCutRemove = [{hash: psp22Hash, selectors: [], interfaces: []}]
 
     // remove facet
     await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(facetCutRemove, null)).to.eventually.be.fulfilled
@@ -518,14 +543,14 @@ describe('DIAMOND_PSP22', () => {
 
     const psp22Init = getSelectorByName(psp22Messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(psp22Messages)
-    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
     const proxy = setupProxy(psp22Facet, diamondContract.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: []}))
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: [], force: false, external: false}))
       .to.eventually.be.fulfilled
 
     // we will instantiate the caller contract with which we try to call PSP22Ref on the diamond contract
@@ -557,20 +582,20 @@ describe('DIAMOND_PSP22', () => {
 
     const psp22Init = getSelectorByName(psp22Messages, 'init_psp22')
     const psp22Selectors = getSelectorsFromMessages(psp22Messages)
-    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
     const proxy = setupProxy(psp22Facet, diamondContract.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: []}))
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: [], force: false, external: false}))
       .to.eventually.be.fulfilled
 
     const filteredSelectors = psp22Selectors.filter((selector) => {
       return selector.toString() != psp22Init.toString()
     })
-    const removalCut = [{hash: psp22Hash, selectors: filteredSelectors}]
+    const removalCut = [{hash: psp22Hash, selectors: filteredSelectors, interfaces: []}]
 
     await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(removalCut, null)).to.eventually.be.fulfilled
 
@@ -592,7 +617,7 @@ describe('DIAMOND_PSP22', () => {
     const psp22Messages = abi.messages
 
     const psp22Selectors = getSelectorsFromMessages(psp22Messages)
-    const psp22Cut = [{hash: '', selectors: psp22Selectors}]
+    const psp22Cut = [{hash: '', selectors: psp22Selectors, interfaces: []}]
 
     // initialize diamond contract
     const { contract: diamondContract, close: closeDiamond } = await setupDiamond(defaultSigner.address)
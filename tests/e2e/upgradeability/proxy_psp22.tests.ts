@@ -265,4 +265,34 @@ describe('MY_UPGRADEABLE_PSP22', () => {
     await closeMetadata()
     await closePSP22()
   })
+
+  it('MY_UPGRADEABLE_PSP22 - update psp22 to psp22_metadata and initialize in one call', async () => {
+    const { api, contract: psp22, abi: abi_psp22, bob: receiver, close: closePSP22 } = await setupPSP22()
+    const { contract, close: closeProxy } = await setupProxyContract(abi_psp22.info.source.wasmHash.toString())
+    const proxy = setupProxy(psp22, contract.address)
+    await expect(proxy.tx.initialize(1000)).to.eventually.be.fulfilled
+    await expect(proxy.tx.transfer(receiver.address, 100, [])).to.eventually.be.fulfilled
+
+    const { contract: psp22_metadata, abi: abi_psp22_metadata, close: closeMetadata } = await setupPSP22Metadata()
+    const hash = abi_psp22_metadata.info.source.wasmHash.toString()
+    const initialize = abi_psp22_metadata.messages.filter((message) => message.identifier == 'initialize')[0]
+    const selector = initialize.selector.toU8a() as unknown as number[]
+    const input = api.createType('(u128, Option<Bytes>, Option<Bytes>, u8)', [0, 'COLONY', 'COL', 18]).toU8a() as unknown as number[]
+
+    await expect(contract.withSigner(receiver).tx.changeDelegateCodeAndCall(hash, selector, input)).to.eventually.be.rejected
+    await expect(contract.tx.changeDelegateCodeAndCall(hash, selector, input)).to.eventually.be.fulfilled
+    await expect(contract.query.getDelegateCode()).to.have.output(hash)
+
+    const proxy_metadata = setupProxy(psp22_metadata, contract.address)
+    await expect(proxy_metadata.query.totalSupply()).to.have.bnToNumber(1000)
+    await expect(proxy_metadata.query.tokenName()).to.have.bytesToString('COLONY')
+    await expect(proxy_metadata.query.tokenSymbol()).to.have.bytesToString('COL')
+    await expect(proxy_metadata.query.tokenDecimals()).to.have.output(18)
+    await expect(proxy_metadata.query.balanceOf(receiver.address)).to.have.bnToNumber(100)
+
+    // Close
+    await closeProxy()
+    await closeMetadata()
+    await closePSP22()
+  })
 })
@@ -196,12 +196,13 @@ describe('RAW_DIAMOND', () => {
     const diamondMessages = diamondAbi.messages
 
     const psp22Selectors = getSelectorsFromMessages(psp22Messages)
-    const psp22Cut = {hash: psp22Hash, selectors: psp22Selectors}
+    const psp22Cut = {hash: psp22Hash, selectors: psp22Selectors, interfaces: []}
 
     const diamondSelectors = getSelectorsFromMessages(diamondMessages)
     const diamondCut = {
       hash: diamondHash,
-      selectors: diamondSelectors
+      selectors: diamondSelectors,
+      interfaces: []
     }
 
     const { contract: diamondContractOriginal, defaultSigner, close: closeRustDiamond } = await setupRustDiamond(diamondCut)
@@ -214,7 +215,7 @@ describe('RAW_DIAMOND', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut([psp22Cut], {hash: psp22Hash, selector: psp22Init, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut([psp22Cut], {hash: psp22Hash, selector: psp22Init, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     // patch methods
     const proxyPSP22Facet = setupProxy(psp22Facet, diamondContract.address)
@@ -231,10 +232,10 @@ describe('RAW_DIAMOND', () => {
     const metadataInit = getSelectorByName(metadataMessages, 'init_metadata')
     const metadataSelectors = getSelectorsFromMessages(metadataMessages)
 
-    const metadataCut = {hash: metadataHash, selectors: metadataSelectors}
+    const metadataCut = {hash: metadataHash, selectors: metadataSelectors, interfaces: []}
 
     // add metadata facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut([metadataCut], {hash: metadataHash, selector: metadataInit, input: []})).to.eventually.be
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut([metadataCut], {hash: metadataHash, selector: metadataInit, input: [], force: false, external: false})).to.eventually.be
       .fulfilled
 
     // patch methods
@@ -247,8 +248,8 @@ describe('RAW_DIAMOND', () => {
     // Test Loupe
 
     expect((await diamondContract.query.facets()).value).to.be.deep.equal([
-      { hash: psp22Hash, selectors: getSelectorsFromMessagesString(psp22Messages) },
-      { hash: metadataHash, selectors: getSelectorsFromMessagesString(metadataMessages) }
+      { hash: psp22Hash, selectors: getSelectorsFromMessagesString(psp22Messages), interfaces: [] },
+      { hash: metadataHash, selectors: getSelectorsFromMessagesString(metadataMessages), interfaces: [] }
     ])
 
     expect((await diamondContract.query.facetFunctionSelectors(metadataHash)).value).to.be.deep.equal(getSelectorsFromMessagesString(metadataMessages))
@@ -280,10 +281,10 @@ describe('RAW_DIAMOND', () => {
     const diamondMessages = diamondAbi.messages
 
     const psp22Selectors = getSelectorsFromMessages(psp22Messages)
-    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors}]
+    const psp22Cut = [{hash: psp22Hash, selectors: psp22Selectors, interfaces: []}]
 
     const diamondSelectors = getSelectorsFromMessages(diamondMessages)
-    const diamondCut = {hash: diamondHash, selectors: diamondSelectors}
+    const diamondCut = {hash: diamondHash, selectors: diamondSelectors, interfaces: []}
 
     // initialize diamond contract
     const { contract: diamondContractOriginal, defaultSigner, close: closeInkDiamond } = await setupInkDiamond(diamondCut)
@@ -295,7 +296,7 @@ describe('RAW_DIAMOND', () => {
     await expect(diamondContract.query.owner()).to.output(defaultSigner.address)
 
     // add psp22 facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: []})).to.eventually.be.fulfilled
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(psp22Cut, {hash: psp22Hash, selector: psp22Init, input: [], force: false, external: false})).to.eventually.be.fulfilled
 
     // patch methods
     const proxyPSP22 = setupProxy(psp22Facet, diamondContract.address)
@@ -312,10 +313,10 @@ describe('RAW_DIAMOND', () => {
     const metadataInit = getSelectorByName(metadataMessages, 'init_metadata')
     const metadataSelectors = getSelectorsFromMessages(metadataMessages)
 
-    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors}]
+    const metadataCut = [{hash: metadataHash, selectors: metadataSelectors, interfaces: []}]
 
     // add metadata facet
-    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: []})).to.eventually.be
+    await expect(diamondContract.withSigner(defaultSigner).tx.diamondCut(metadataCut, {hash: metadataHash, selector: metadataInit, input: [], force: false, external: false})).to.eventually.be
       .fulfilled
 
     // patch methods
@@ -328,8 +329,8 @@ describe('RAW_DIAMOND', () => {
     // Test Loupe
 
     expect((await diamondContract.query.facets()).value).to.be.deep.equal([
-      { hash: psp22Hash, selectors: getSelectorsFromMessagesString(psp22Messages) },
-      { hash: metadataHash, selectors: getSelectorsFromMessagesString(metadataMessages) }
+      { hash: psp22Hash, selectors: getSelectorsFromMessagesString(psp22Messages), interfaces: [] },
+      { hash: metadataHash, selectors: getSelectorsFromMessagesString(metadataMessages), interfaces: [] }
     ])
 
     expect((await diamondContract.query.facetFunctionSelectors(metadataHash)).value).to.be.deep.equal(getSelectorsFromMessagesString(metadataMessages))
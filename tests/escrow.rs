@@ -0,0 +1,166 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "escrow")]
+#[openbrush::contract]
+mod escrow {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::escrow::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct Deposited {
+        payee: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Withdrawn {
+        payee: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct EscrowStruct {
+        #[storage_field]
+        escrow: escrow::Data,
+    }
+
+    type Event = <EscrowStruct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl Escrow for EscrowStruct {}
+
+    impl escrow::Internal for EscrowStruct {
+        fn _emit_deposited_event(&self, payee: AccountId, amount: Balance) {
+            self.env().emit_event(Deposited { payee, amount });
+        }
+
+        fn _emit_withdrawn_event(&self, payee: AccountId, amount: Balance) {
+            self.env().emit_event(Withdrawn { payee, amount });
+        }
+    }
+
+    impl EscrowStruct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|_instance: &mut Self| {})
+        }
+    }
+
+    /// Calls `deposit` from `caller` with `value` of the native currency
+    fn deposit(
+        instance: &mut EscrowStruct,
+        caller: AccountId,
+        payee: AccountId,
+        value: Balance,
+    ) -> Result<(), EscrowError> {
+        change_caller(caller);
+        ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(value);
+        let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        let balance = ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(contract).unwrap_or(0);
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(contract, balance + value);
+        let result = instance.deposit(payee);
+        ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(0);
+        result
+    }
+
+    fn balance_of(account: AccountId) -> Balance {
+        ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(account).unwrap()
+    }
+
+    #[ink::test]
+    fn deposits_accumulate() {
+        let accounts = accounts();
+        let mut instance = EscrowStruct::new();
+
+        assert_eq!(deposit(&mut instance, accounts.alice, accounts.bob, 100), Ok(()));
+        assert_eq!(deposit(&mut instance, accounts.charlie, accounts.bob, 50), Ok(()));
+        assert_eq!(deposit(&mut instance, accounts.alice, accounts.django, 10), Ok(()));
+
+        assert_eq!(instance.deposits_of(accounts.bob), 150);
+        assert_eq!(instance.deposits_of(accounts.django), 10);
+        assert_eq!(instance.deposits_of(accounts.eve), 0);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        match <Event as scale::Decode>::decode(&mut &emitted_events[1].data[..]) {
+            Ok(Event::Deposited(Deposited { payee, amount })) => {
+                assert_eq!(payee, accounts.bob);
+                assert_eq!(amount, 50);
+            }
+            _ => panic!("encountered unexpected event kind: expected a Deposited event"),
+        }
+    }
+
+    #[ink::test]
+    fn deposit_for_zero_payee_fails() {
+        let accounts = accounts();
+        let mut instance = EscrowStruct::new();
+
+        assert_eq!(
+            deposit(&mut instance, accounts.alice, [0; 32].into(), 100),
+            Err(EscrowError::ZeroPayeeAddress)
+        );
+    }
+
+    #[ink::test]
+    fn withdraw_works() {
+        let accounts = accounts();
+        let mut instance = EscrowStruct::new();
+        assert_eq!(deposit(&mut instance, accounts.alice, accounts.bob, 100), Ok(()));
+        assert_eq!(deposit(&mut instance, accounts.alice, accounts.charlie, 30), Ok(()));
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(accounts.bob, 0);
+
+        // anyone can trigger the withdrawal, the funds go to the payee
+        change_caller(accounts.django);
+        assert_eq!(instance.withdraw(accounts.bob), Ok(()));
+
+        assert_eq!(balance_of(accounts.bob), 100);
+        assert_eq!(instance.deposits_of(accounts.bob), 0);
+        assert_eq!(instance.deposits_of(accounts.charlie), 30);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        match <Event as scale::Decode>::decode(&mut &emitted_events[2].data[..]) {
+            Ok(Event::Withdrawn(Withdrawn { payee, amount })) => {
+                assert_eq!(payee, accounts.bob);
+                assert_eq!(amount, 100);
+            }
+            _ => panic!("encountered unexpected event kind: expected a Withdrawn event"),
+        }
+
+        // the second withdrawal transfers nothing
+        assert_eq!(instance.withdraw(accounts.bob), Ok(()));
+        assert_eq!(balance_of(accounts.bob), 100);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "escrow")]
+#[openbrush::contract]
+mod escrow_conditional {
+    use ink_lang as ink;
+    use ink_lang::codegen::Env;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::escrow::extensions::conditional::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    /// The escrow which releases the funds after `release_time`
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct TimelockEscrow {
+        #[storage_field]
+        escrow: escrow::Data,
+        release_time: Timestamp,
+    }
+
+    impl Escrow for TimelockEscrow {}
+
+    impl ConditionalEscrow for TimelockEscrow {}
+
+    impl escrow::Internal for TimelockEscrow {
+        fn _withdrawal_allowed(&self, _payee: &AccountId) -> bool {
+            self.env().block_timestamp() >= self.release_time
+        }
+    }
+
+    impl TimelockEscrow {
+        #[ink(constructor)]
+        pub fn new(release_time: Timestamp) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.release_time = release_time;
+            })
+        }
+    }
+
+    const RELEASE_TIME: Timestamp = 1020;
+    const DEPOSIT: Balance = 100;
+
+    fn setup() -> TimelockEscrow {
+        let mut instance = TimelockEscrow::new(RELEASE_TIME);
+        let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(contract, DEPOSIT);
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(accounts().bob, 0);
+        assert_eq!(instance._deposit(accounts().bob, DEPOSIT), Ok(()));
+        instance
+    }
+
+    /// Moves the block timestamp to `timestamp`. The off-chain environment moves the time
+    /// only by whole blocks, so `timestamp` must be a multiple of the block time.
+    fn set_timestamp(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+        assert_eq!(ink_env::block_timestamp::<ink_env::DefaultEnvironment>(), timestamp);
+    }
+
+    fn balance_of(account: AccountId) -> Balance {
+        ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(account).unwrap()
+    }
+
+    #[ink::test]
+    fn withdrawal_is_blocked_until_allowed() {
+        let mut instance = setup();
+        let bob = accounts().bob;
+        set_timestamp(RELEASE_TIME - 30);
+
+        assert!(!instance.withdrawal_allowed(bob));
+        assert_eq!(instance.withdraw(bob), Err(EscrowError::WithdrawalNotAllowed));
+        assert_eq!(instance.deposits_of(bob), DEPOSIT);
+        assert_eq!(balance_of(bob), 0);
+    }
+
+    #[ink::test]
+    fn withdrawal_works_when_allowed() {
+        let mut instance = setup();
+        let bob = accounts().bob;
+        set_timestamp(RELEASE_TIME);
+
+        assert!(instance.withdrawal_allowed(bob));
+        assert_eq!(instance.withdraw(bob), Ok(()));
+        assert_eq!(instance.deposits_of(bob), 0);
+        assert_eq!(balance_of(bob), DEPOSIT);
+    }
+}
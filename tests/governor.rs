@@ -0,0 +1,365 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "governor")]
+#[openbrush::contract]
+mod governor {
+    use ink::codegen::Env;
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::governor::*,
+        storage::Mapping,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    const VOTING_DELAY: Timestamp = 30;
+    const VOTING_PERIOD: Timestamp = 120;
+    const EXECUTION_DELAY: Timestamp = 60;
+    const QUORUM: Balance = 100;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct GovernorStruct {
+        #[storage_field]
+        governor: governor::Data,
+        snapshot_id: u64,
+        snapshots_disabled: bool,
+        votes: Mapping<AccountId, Balance>,
+    }
+
+    impl Governor for GovernorStruct {}
+
+    /// We will override cross-contract calls in tests
+    /// The cross-contract interaction will be tested in integration tests
+    impl governor::Internal for GovernorStruct {
+        fn _create_snapshot(&mut self) -> Result<u64, GovernorError> {
+            if self.snapshots_disabled {
+                return Err(GovernorError::SnapshotNotCreated)
+            }
+            self.snapshot_id += 1;
+            Ok(self.snapshot_id)
+        }
+
+        fn _get_votes(&self, account: &AccountId, _snapshot_id: u64) -> Balance {
+            self.votes.get(account).unwrap_or(0)
+        }
+
+        /// The governor only calls `set_quorum` of itself, other calls revert
+        fn _call(&mut self, transaction: Transaction) -> Result<(), GovernorError> {
+            if transaction.callee != self.env().account_id()
+                || transaction.selector != ink::selector_bytes!("Governor::set_quorum")
+            {
+                return Err(GovernorError::UnderlyingTransactionReverted)
+            }
+            let quorum = <Balance as scale::Decode>::decode(&mut &transaction.input[..])
+                .map_err(|_| GovernorError::UnderlyingTransactionReverted)?;
+
+            let caller = self.env().caller();
+            change_caller(transaction.callee);
+            let result = self.set_quorum(quorum);
+            change_caller(caller);
+            result
+        }
+    }
+
+    impl GovernorStruct {
+        #[ink(constructor)]
+        pub fn new(token: AccountId) -> Self {
+            let mut instance = Self::default();
+            instance._init_governor(token, VOTING_DELAY, VOTING_PERIOD, EXECUTION_DELAY, QUORUM);
+
+            let accounts = accounts();
+            instance.votes.insert(&accounts.alice, &60);
+            instance.votes.insert(&accounts.bob, &50);
+            instance.votes.insert(&accounts.charlie, &30);
+            instance
+        }
+    }
+
+    /// Moves the block timestamp to `timestamp`. The off-chain environment moves the time
+    /// only by whole blocks, so `timestamp` must be a multiple of the block time.
+    fn set_block_timestamp(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+        assert_eq!(ink_env::block_timestamp::<ink_env::DefaultEnvironment>(), timestamp);
+    }
+
+    fn set_quorum_transactions(quorum: Balance) -> Vec<Transaction> {
+        vec![Transaction {
+            callee: ink_env::account_id::<ink_env::DefaultEnvironment>(),
+            selector: ink::selector_bytes!("Governor::set_quorum"),
+            input: scale::Encode::encode(&quorum),
+            transferred_value: 0,
+            gas_limit: 0,
+        }]
+    }
+
+    fn description() -> String {
+        String::from("Set quorum to 200")
+    }
+
+    fn setup() -> (GovernorStruct, ProposalId) {
+        let mut governor = GovernorStruct::new(AccountId::from([0x1; 32]));
+        let id = governor.propose(set_quorum_transactions(200), description()).unwrap();
+        (governor, id)
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let token = AccountId::from([0x1; 32]);
+        let governor = GovernorStruct::new(token);
+
+        assert_eq!(governor.token(), token);
+        assert_eq!(governor.voting_delay(), VOTING_DELAY);
+        assert_eq!(governor.voting_period(), VOTING_PERIOD);
+        assert_eq!(governor.execution_delay(), EXECUTION_DELAY);
+        assert_eq!(governor.quorum(1), QUORUM);
+    }
+
+    #[ink::test]
+    fn propose_works() {
+        let (governor, id) = setup();
+
+        let description_hash = governor._hash_description(&description());
+        assert_eq!(
+            governor.hash_proposal(set_quorum_transactions(200), description_hash),
+            id
+        );
+        assert_eq!(governor.state(id), Ok(ProposalState::Pending));
+        assert_eq!(governor.proposal_snapshot(id), 1);
+        assert_eq!(governor.proposal_deadline(id), VOTING_DELAY + VOTING_PERIOD);
+        assert_eq!(governor.proposal_eta(id), 0);
+        assert_eq!(governor.proposal_votes(id), (0, 0, 0));
+    }
+
+    #[ink::test]
+    fn propose_fails_for_invalid_proposal() {
+        let (mut governor, _) = setup();
+
+        assert_eq!(
+            governor.propose(vec![], description()),
+            Err(GovernorError::EmptyProposal)
+        );
+        assert_eq!(
+            governor.propose(set_quorum_transactions(200), description()),
+            Err(GovernorError::ProposalAlreadyExists)
+        );
+
+        governor.snapshots_disabled = true;
+        assert_eq!(
+            governor.propose(set_quorum_transactions(300), description()),
+            Err(GovernorError::SnapshotNotCreated)
+        );
+    }
+
+    #[ink::test]
+    fn state_of_unknown_proposal_should_fail() {
+        let governor = GovernorStruct::new(AccountId::from([0x1; 32]));
+
+        assert_eq!(
+            governor.state(ProposalId::default()),
+            Err(GovernorError::ProposalNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn proposal_passes_quorum_and_executes_parameter_change() {
+        let accounts = accounts();
+        let (mut governor, id) = setup();
+
+        set_block_timestamp(VOTING_DELAY);
+        assert_eq!(governor.state(id), Ok(ProposalState::Active));
+
+        change_caller(accounts.alice);
+        assert_eq!(governor.cast_vote(id, VoteType::For), Ok(60));
+        change_caller(accounts.bob);
+        assert_eq!(governor.cast_vote(id, VoteType::For), Ok(50));
+        change_caller(accounts.charlie);
+        assert_eq!(governor.cast_vote(id, VoteType::Against), Ok(30));
+
+        assert_eq!(governor.proposal_votes(id), (30, 110, 0));
+        assert!(governor.has_voted(id, accounts.alice));
+        assert!(!governor.has_voted(id, accounts.django));
+
+        // the voting is still active at the deadline
+        set_block_timestamp(VOTING_DELAY + VOTING_PERIOD);
+        assert_eq!(governor.state(id), Ok(ProposalState::Active));
+
+        let end = VOTING_DELAY + VOTING_PERIOD + 30;
+        set_block_timestamp(end);
+        assert_eq!(governor.state(id), Ok(ProposalState::Succeeded));
+
+        let description_hash = governor._hash_description(&description());
+        assert_eq!(governor.queue(set_quorum_transactions(200), description_hash), Ok(id));
+        assert_eq!(governor.state(id), Ok(ProposalState::Queued));
+        assert_eq!(governor.proposal_eta(id), end + EXECUTION_DELAY);
+
+        set_block_timestamp(end + EXECUTION_DELAY - 30);
+        assert_eq!(
+            governor.execute(set_quorum_transactions(200), description_hash),
+            Err(GovernorError::ProposalNotReady)
+        );
+
+        set_block_timestamp(end + EXECUTION_DELAY);
+        assert_eq!(governor.execute(set_quorum_transactions(200), description_hash), Ok(id));
+        assert_eq!(governor.state(id), Ok(ProposalState::Executed));
+        assert_eq!(governor.quorum(1), 200);
+
+        assert_eq!(
+            governor.execute(set_quorum_transactions(200), description_hash),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+    }
+
+    #[ink::test]
+    fn cast_vote_fails_outside_of_voting() {
+        let accounts = accounts();
+        let (mut governor, id) = setup();
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            governor.cast_vote(id, VoteType::For),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+
+        set_block_timestamp(VOTING_DELAY + VOTING_PERIOD + 30);
+        assert_eq!(
+            governor.cast_vote(id, VoteType::For),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+        assert_eq!(governor.proposal_votes(id), (0, 0, 0));
+        assert!(!governor.has_voted(id, accounts.alice));
+    }
+
+    #[ink::test]
+    fn cast_vote_twice_should_fail() {
+        let accounts = accounts();
+        let (mut governor, id) = setup();
+        set_block_timestamp(VOTING_DELAY);
+
+        change_caller(accounts.alice);
+        assert_eq!(governor.cast_vote(id, VoteType::For), Ok(60));
+        assert_eq!(
+            governor.cast_vote(id, VoteType::Against),
+            Err(GovernorError::AlreadyVoted)
+        );
+        assert_eq!(governor.proposal_votes(id), (0, 60, 0));
+    }
+
+    #[ink::test]
+    fn proposal_without_quorum_is_defeated() {
+        let accounts = accounts();
+        let (mut governor, id) = setup();
+        set_block_timestamp(VOTING_DELAY);
+
+        change_caller(accounts.alice);
+        assert!(governor.cast_vote(id, VoteType::For).is_ok());
+
+        set_block_timestamp(VOTING_DELAY + VOTING_PERIOD + 30);
+        assert_eq!(governor.state(id), Ok(ProposalState::Defeated));
+
+        let description_hash = governor._hash_description(&description());
+        assert_eq!(
+            governor.queue(set_quorum_transactions(200), description_hash),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+    }
+
+    #[ink::test]
+    fn proposal_without_majority_is_defeated() {
+        let accounts = accounts();
+        let (mut governor, id) = setup();
+        set_block_timestamp(VOTING_DELAY);
+
+        change_caller(accounts.alice);
+        assert!(governor.cast_vote(id, VoteType::Against).is_ok());
+        change_caller(accounts.bob);
+        assert!(governor.cast_vote(id, VoteType::For).is_ok());
+
+        set_block_timestamp(VOTING_DELAY + VOTING_PERIOD + 30);
+        assert_eq!(governor.state(id), Ok(ProposalState::Defeated));
+    }
+
+    #[ink::test]
+    fn abstain_votes_count_towards_quorum() {
+        let accounts = accounts();
+        let (mut governor, id) = setup();
+        set_block_timestamp(VOTING_DELAY);
+
+        change_caller(accounts.alice);
+        assert!(governor.cast_vote(id, VoteType::Abstain).is_ok());
+        change_caller(accounts.bob);
+        assert!(governor.cast_vote(id, VoteType::For).is_ok());
+
+        set_block_timestamp(VOTING_DELAY + VOTING_PERIOD + 30);
+        assert_eq!(governor.proposal_votes(id), (0, 50, 60));
+        assert_eq!(governor.state(id), Ok(ProposalState::Succeeded));
+    }
+
+    #[ink::test]
+    fn failed_execution_keeps_proposal_queued() {
+        let accounts = accounts();
+        let mut governor = GovernorStruct::new(AccountId::from([0x1; 32]));
+        let mut transactions = set_quorum_transactions(200);
+        transactions[0].selector = [0xff; 4];
+        let id = governor.propose(transactions.clone(), description()).unwrap();
+
+        set_block_timestamp(VOTING_DELAY);
+        change_caller(accounts.alice);
+        assert!(governor.cast_vote(id, VoteType::For).is_ok());
+        change_caller(accounts.bob);
+        assert!(governor.cast_vote(id, VoteType::For).is_ok());
+
+        set_block_timestamp(VOTING_DELAY + VOTING_PERIOD + 30);
+        let description_hash = governor._hash_description(&description());
+        assert!(governor.queue(transactions.clone(), description_hash).is_ok());
+
+        set_block_timestamp(VOTING_DELAY + VOTING_PERIOD + 30 + EXECUTION_DELAY);
+        assert_eq!(
+            governor.execute(transactions, description_hash),
+            Err(GovernorError::UnderlyingTransactionReverted)
+        );
+        assert_eq!(governor.state(id), Ok(ProposalState::Queued));
+        assert_eq!(governor.quorum(1), QUORUM);
+    }
+
+    #[ink::test]
+    fn only_governor_can_change_parameters() {
+        let mut governor = GovernorStruct::new(AccountId::from([0x1; 32]));
+        change_caller([13; 32].into());
+
+        assert_eq!(governor.set_quorum(200), Err(GovernorError::CallerMustBeGovernor));
+        assert_eq!(governor.set_voting_delay(1), Err(GovernorError::CallerMustBeGovernor));
+        assert_eq!(governor.set_voting_period(1), Err(GovernorError::CallerMustBeGovernor));
+        assert_eq!(governor.quorum(1), QUORUM);
+        assert_eq!(governor.voting_delay(), VOTING_DELAY);
+        assert_eq!(governor.voting_period(), VOTING_PERIOD);
+    }
+}
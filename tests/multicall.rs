@@ -0,0 +1,128 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "multicall")]
+#[openbrush::contract]
+mod multicall {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::multicall::*,
+        traits::Storage,
+    };
+    use scale::Encode;
+
+    const GETTER: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MulticallStruct {
+        #[storage_field]
+        multicall: multicall::Data,
+    }
+
+    /// We will override the call so it is not using cross-contract call in tests.
+    /// The callee `[1; 32]` returns `42u128`, the callee `[2; 32]` returns `true`
+    /// and any other callee reverts. The cross-contract interaction will be tested in integration tests.
+    impl multicall::Internal for MulticallStruct {
+        fn _call(&self, callee: &AccountId, input: &Vec<u8>) -> Option<Vec<u8>> {
+            if input[..] != GETTER[..] {
+                return None
+            }
+            if *callee == AccountId::from([0x1; 32]) {
+                Some(42u128.encode())
+            } else if *callee == AccountId::from([0x2; 32]) {
+                Some(true.encode())
+            } else {
+                None
+            }
+        }
+    }
+
+    impl Multicall for MulticallStruct {}
+
+    impl MulticallStruct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    fn getter_call(callee: u8) -> MulticallCall {
+        (AccountId::from([callee; 32]), GETTER.to_vec())
+    }
+
+    #[ink::test]
+    fn aggregate_works() {
+        let instance = MulticallStruct::new();
+
+        assert_eq!(
+            instance.aggregate(vec![getter_call(1), getter_call(2)]),
+            Ok(vec![42u128.encode(), true.encode()])
+        );
+    }
+
+    #[ink::test]
+    fn aggregate_fails_if_any_call_fails() {
+        let instance = MulticallStruct::new();
+
+        assert_eq!(
+            instance.aggregate(vec![getter_call(1), getter_call(3), getter_call(2)]),
+            Err(MulticallError::CallFailed(1))
+        );
+    }
+
+    #[ink::test]
+    fn try_aggregate_tolerates_failed_calls() {
+        let instance = MulticallStruct::new();
+
+        assert_eq!(
+            instance.try_aggregate(false, vec![getter_call(1), getter_call(3), getter_call(2)]),
+            Ok(vec![
+                (true, 42u128.encode()),
+                (false, Vec::new()),
+                (true, true.encode()),
+            ])
+        );
+    }
+
+    #[ink::test]
+    fn try_aggregate_requires_success() {
+        let instance = MulticallStruct::new();
+
+        assert_eq!(
+            instance.try_aggregate(true, vec![getter_call(1), getter_call(2)]),
+            Ok(vec![(true, 42u128.encode()), (true, true.encode())])
+        );
+        assert_eq!(
+            instance.try_aggregate(true, vec![getter_call(1), getter_call(2), getter_call(3)]),
+            Err(MulticallError::CallFailed(2))
+        );
+    }
+
+    #[ink::test]
+    fn aggregate_of_no_calls_is_empty() {
+        let instance = MulticallStruct::new();
+
+        assert_eq!(instance.aggregate(Vec::new()), Ok(Vec::new()));
+        assert_eq!(instance.try_aggregate(true, Vec::new()), Ok(Vec::new()));
+    }
+}
@@ -30,6 +30,7 @@ mod ownable {
     use ink_lang as ink;
     use openbrush::{
         contracts::ownable::*,
+        modifiers,
         test_utils::change_caller,
         traits::{
             AccountIdExt,
@@ -64,6 +65,12 @@ mod ownable {
 
         #[ink(message)]
         pub fn temp(&self) {}
+
+        #[ink(message)]
+        #[modifiers(only_owner_or_self)]
+        pub fn owner_or_self_function(&mut self) -> Result<(), OwnableError> {
+            Ok(())
+        }
     }
 
     impl Ownable for MyOwnable {}
@@ -175,4 +182,59 @@ mod ownable {
             Err(OwnableError::NewOwnerIsZero)
         );
     }
+
+    #[ink::test]
+    fn transfer_ownership_fails_to_current_owner() {
+        let mut my_ownable = MyOwnable::new();
+        let owner = my_ownable.owner();
+        assert_eq!(my_ownable.transfer_ownership(owner), Err(OwnableError::NewOwnerIsOwner));
+        assert_eq!(my_ownable.owner(), owner);
+        // Only the event of the constructor is emitted
+        assert_eq!(1, ink_env::test::recorded_events().count());
+    }
+
+    #[ink::test]
+    fn renounce_ownership_is_the_only_way_to_zero_owner() {
+        let mut my_ownable = MyOwnable::new();
+        let owner = my_ownable.owner();
+        assert_eq!(
+            my_ownable.transfer_ownership(AccountId::from([0u8; 32])),
+            Err(OwnableError::NewOwnerIsZero)
+        );
+        assert_eq!(my_ownable.owner(), owner);
+
+        assert_eq!(my_ownable.renounce_ownership(), Ok(()));
+        assert!(my_ownable.owner().is_zero());
+        // The owner's functions are not available anymore
+        assert_eq!(my_ownable.renounce_ownership(), Err(OwnableError::CallerIsNotOwner));
+    }
+
+    #[ink::test]
+    fn only_owner_or_self_allows_owner() {
+        let mut my_ownable = MyOwnable::new();
+        let owner = AccountId::from([7u8; 32]);
+        assert_eq!(my_ownable.transfer_ownership(owner), Ok(()));
+
+        change_caller(owner);
+        assert_eq!(my_ownable.owner_or_self_function(), Ok(()));
+    }
+
+    #[ink::test]
+    fn only_owner_or_self_allows_contract_itself() {
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(my_ownable.transfer_ownership(AccountId::from([7u8; 32])), Ok(()));
+
+        // simulates the call of the contract to itself
+        change_caller(ink_env::account_id::<ink_env::DefaultEnvironment>());
+        assert_eq!(my_ownable.owner_or_self_function(), Ok(()));
+    }
+
+    #[ink::test]
+    fn only_owner_or_self_rejects_third_party() {
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(my_ownable.transfer_ownership(AccountId::from([7u8; 32])), Ok(()));
+
+        change_caller(AccountId::from([13u8; 32]));
+        assert_eq!(my_ownable.owner_or_self_function(), Err(OwnableError::CallerIsNotOwner));
+    }
 }
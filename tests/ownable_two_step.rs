@@ -0,0 +1,258 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "ownable")]
+#[openbrush::contract]
+mod ownable_two_step {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::ownable::{
+            extensions::two_step::*,
+            only_owner,
+        },
+        modifiers,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            AccountIdExt,
+            Storage,
+            ZERO_ADDRESS,
+        },
+    };
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        new_owner: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyOwnable {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        two_step: two_step::Data,
+    }
+
+    type Event = <MyOwnable as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl MyOwnable {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut inst = Self::default();
+            inst._init_with_owner(Self::env().caller());
+            inst
+        }
+    }
+
+    impl Ownable for MyOwnable {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+            self._start_ownership_transfer(new_owner)
+        }
+    }
+
+    impl Ownable2Step for MyOwnable {}
+
+    impl ownable::Internal for MyOwnable {
+        fn _emit_ownership_transferred_event(&self, previous_owner: Option<AccountId>, new_owner: Option<AccountId>) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl two_step::Internal for MyOwnable {
+        fn _emit_ownership_transfer_started_event(&self, previous_owner: AccountId, new_owner: AccountId) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    fn assert_ownership_transferred_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_previous_owner: Option<AccountId>,
+        expected_new_owner: Option<AccountId>,
+    ) {
+        if let Event::OwnershipTransferred(OwnershipTransferred {
+            previous_owner,
+            new_owner,
+        }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(previous_owner, expected_previous_owner);
+            assert_eq!(new_owner, expected_new_owner);
+        } else {
+            panic!("encountered unexpected event kind: expected a OwnershipTransferred event")
+        }
+    }
+
+    fn assert_ownership_transfer_started_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_previous_owner: AccountId,
+        expected_new_owner: AccountId,
+    ) {
+        if let Event::OwnershipTransferStarted(OwnershipTransferStarted {
+            previous_owner,
+            new_owner,
+        }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(previous_owner, expected_previous_owner);
+            assert_eq!(new_owner, expected_new_owner);
+        } else {
+            panic!("encountered unexpected event kind: expected a OwnershipTransferStarted event")
+        }
+    }
+
+    #[ink::test]
+    fn transfer_ownership_nominates_pending_owner() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(my_ownable.pending_owner(), None);
+
+        assert!(my_ownable.transfer_ownership(accounts.bob).is_ok());
+
+        assert_eq!(my_ownable.owner(), accounts.alice);
+        assert_eq!(my_ownable.pending_owner(), Some(accounts.bob));
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(2, emitted_events.len());
+        assert_ownership_transfer_started_event(&emitted_events[1], accounts.alice, accounts.bob);
+    }
+
+    #[ink::test]
+    fn accept_ownership_works() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert!(my_ownable.transfer_ownership(accounts.bob).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(my_ownable.accept_ownership().is_ok());
+
+        assert_eq!(my_ownable.owner(), accounts.bob);
+        assert_eq!(my_ownable.pending_owner(), None);
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(3, emitted_events.len());
+        assert_ownership_transferred_event(&emitted_events[2], Some(accounts.alice), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn accept_ownership_fails_for_third_party() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert!(my_ownable.transfer_ownership(accounts.bob).is_ok());
+
+        change_caller(accounts.charlie);
+        assert_eq!(
+            my_ownable.accept_ownership(),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+        // The owner also can't accept the nomination
+        change_caller(accounts.alice);
+        assert_eq!(
+            my_ownable.accept_ownership(),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+
+        assert_eq!(my_ownable.owner(), accounts.alice);
+        assert_eq!(my_ownable.pending_owner(), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn new_nomination_overrides_pending_owner() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert!(my_ownable.transfer_ownership(accounts.bob).is_ok());
+        assert!(my_ownable.transfer_ownership(accounts.charlie).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            my_ownable.accept_ownership(),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+        change_caller(accounts.charlie);
+        assert!(my_ownable.accept_ownership().is_ok());
+        assert_eq!(my_ownable.owner(), accounts.charlie);
+    }
+
+    #[ink::test]
+    fn transfer_ownership_fails() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+
+        assert_eq!(
+            my_ownable.transfer_ownership(ZERO_ADDRESS.into()),
+            Err(OwnableError::NewOwnerIsZero)
+        );
+        assert_eq!(
+            my_ownable.transfer_ownership(accounts.alice),
+            Err(OwnableError::NewOwnerIsOwner)
+        );
+        assert_eq!(my_ownable.pending_owner(), None);
+        change_caller(accounts.bob);
+        assert_eq!(
+            my_ownable.transfer_ownership(accounts.bob),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(my_ownable.pending_owner(), None);
+    }
+
+    #[ink::test]
+    fn renounce_ownership_cancels_nomination() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert!(my_ownable.transfer_ownership(accounts.bob).is_ok());
+
+        assert!(my_ownable.renounce_ownership().is_ok());
+        assert!(my_ownable.owner().is_zero());
+        assert_eq!(my_ownable.pending_owner(), None);
+
+        // the default `renounce_ownership` is used, the stale nominee can't take the contract back
+        change_caller(accounts.bob);
+        assert_eq!(
+            my_ownable.accept_ownership(),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+        assert!(my_ownable.owner().is_zero());
+    }
+}
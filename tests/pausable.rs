@@ -55,6 +55,7 @@ mod pausable {
         #[storage_field]
         pause: Data,
         flipped: bool,
+        withdrawn: bool,
     }
 
     impl MyFlipper {
@@ -71,6 +72,13 @@ mod pausable {
 
             Ok(previous)
         }
+
+        #[ink(message)]
+        pub fn emergency_withdraw(&mut self) -> Result<(), PausableError> {
+            self._ensure_paused()?;
+            self.withdrawn = true;
+            Ok(())
+        }
     }
 
     impl Pausable for MyFlipper {}
@@ -184,4 +192,21 @@ mod pausable {
         let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
         assert_unpaused_event(&emitted_events[0], accounts.alice);
     }
+
+    #[ink::test]
+    fn emergency_withdraw_fails_while_not_paused() {
+        let mut inst = MyFlipper::new();
+
+        assert_eq!(inst.emergency_withdraw(), Err(PausableError::NotPaused));
+        assert!(!inst.withdrawn);
+    }
+
+    #[ink::test]
+    fn emergency_withdraw_works_while_paused() {
+        let mut inst = MyFlipper::new();
+
+        assert!(inst._pause::<PausableError>().is_ok());
+        assert_eq!(inst.emergency_withdraw(), Ok(()));
+        assert!(inst.withdrawn);
+    }
 }
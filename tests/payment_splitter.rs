@@ -27,6 +27,7 @@ mod payment_splitter {
     use ink_storage::traits::SpreadAllocate;
     use openbrush::{
         contracts::payment_splitter::*,
+        storage::Mapping,
         test_utils::accounts,
         traits::Storage,
     };
@@ -54,11 +55,20 @@ mod payment_splitter {
         pub amount: Balance,
     }
 
+    #[ink(event)]
+    pub struct TokenPaymentReleased {
+        pub token: AccountId,
+        pub to: AccountId,
+        pub amount: Balance,
+    }
+
     #[ink(storage)]
     #[derive(Default, SpreadAllocate, Storage)]
     pub struct MySplitter {
         #[storage_field]
         splitter: Data,
+        // balances of the mock PSP22 token
+        token_balances: Mapping<AccountId, Balance>,
     }
 
     impl MySplitter {
@@ -84,8 +94,50 @@ mod payment_splitter {
         fn _emit_payment_released_event(&self, to: AccountId, amount: Balance) {
             self.env().emit_event(PaymentReleased { to, amount })
         }
+
+        fn _emit_token_payment_released_event(&self, token: AccountId, to: AccountId, amount: Balance) {
+            self.env().emit_event(TokenPaymentReleased { token, to, amount })
+        }
+
+        /// We will override cross-contract token calls in tests
+        /// The cross-contract interaction will be tested in integration tests
+        fn _token_balance(&self, token: &AccountId) -> Balance {
+            assert_eq!(token, &TOKEN.into());
+            self.token_balances.get(&self.env().account_id()).unwrap_or_default()
+        }
+
+        fn _transfer_token(
+            &mut self,
+            token: &AccountId,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<(), PaymentSplitterError> {
+            let balance = self._token_balance(token);
+            if balance < amount {
+                return Err(PaymentSplitterError::TransferFailed)
+            }
+            let account = self.env().account_id();
+            let to_balance = self.token_balances.get(&to).unwrap_or_default();
+            self.token_balances.insert(&account, &(balance - amount));
+            self.token_balances.insert(&to, &(to_balance + amount));
+            Ok(())
+        }
+    }
+
+    impl MySplitter {
+        fn fund_token(&mut self, amount: Balance) {
+            let account = self.env().account_id();
+            let balance = self.token_balances.get(&account).unwrap_or_default();
+            self.token_balances.insert(&account, &(balance + amount));
+        }
+
+        fn token_balance_of(&self, account: AccountId) -> Balance {
+            self.token_balances.get(&account).unwrap_or_default()
+        }
     }
 
+    const TOKEN: [u8; 32] = [0x7; 32];
+
     type Event = <MySplitter as ::ink_lang::reflect::ContractEventBase>::Type;
 
     fn assert_payee_added_event(
@@ -261,6 +313,89 @@ mod payment_splitter {
         assert_payment_released_event(&emitted_events[3], accounts.bob, 2 * 333333);
     }
 
+    fn assert_token_payment_released_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_token: AccountId,
+        expected_to: AccountId,
+        expected_amount: Balance,
+    ) {
+        if let Event::TokenPaymentReleased(TokenPaymentReleased { token, to, amount }) =
+            <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(token, expected_token);
+            assert_eq!(to, expected_to);
+            assert_eq!(amount, expected_amount);
+        } else {
+            panic!("encountered unexpected event kind: expected a TokenPaymentReleased event")
+        }
+    }
+
+    #[ink::test]
+    fn correct_release_token() {
+        let accounts = accounts();
+        let token = AccountId::from(TOKEN);
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 70), (accounts.bob, 30)]);
+        instance.fund_token(1000);
+
+        assert_eq!(instance.releasable_token(token, accounts.charlie), 700);
+        assert_eq!(instance.releasable_token(token, accounts.bob), 300);
+        assert!(instance.release_token(token, accounts.charlie).is_ok());
+        assert_eq!(instance.token_balance_of(accounts.charlie), 700);
+        assert_eq!(instance.released_token(token, accounts.charlie), 700);
+        assert_eq!(instance.total_released_token(token), 700);
+        assert_eq!(instance.releasable_token(token, accounts.charlie), 0);
+        assert_eq!(instance.releasable_token(token, accounts.bob), 300);
+
+        assert!(instance.release_token(token, accounts.bob).is_ok());
+        assert_eq!(instance.token_balance_of(accounts.bob), 300);
+        assert_eq!(instance.total_released_token(token), 1000);
+        // The native token accounting is not affected
+        assert_eq!(instance.total_released(), 0);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_token_payment_released_event(&emitted_events[2], token, accounts.charlie, 700);
+        assert_token_payment_released_event(&emitted_events[3], token, accounts.bob, 300);
+    }
+
+    #[ink::test]
+    fn release_token_leaves_dust() {
+        let accounts = accounts();
+        let token = AccountId::from(TOKEN);
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 70), (accounts.bob, 30)]);
+        instance.fund_token(1000);
+        assert!(instance.release_token(token, accounts.charlie).is_ok());
+        instance.fund_token(101);
+
+        // 1101 * 70 / 100 = 770.7 and 1101 * 30 / 100 = 330.3
+        assert_eq!(instance.releasable_token(token, accounts.charlie), 70);
+        assert_eq!(instance.releasable_token(token, accounts.bob), 330);
+        assert!(instance.release_token(token, accounts.charlie).is_ok());
+        assert!(instance.release_token(token, accounts.bob).is_ok());
+
+        assert_eq!(instance.token_balance_of(accounts.charlie), 770);
+        assert_eq!(instance.token_balance_of(accounts.bob), 330);
+        assert_eq!(instance.token_balance_of(instance.env().account_id()), 1);
+        assert_eq!(
+            instance.release_token(token, accounts.bob),
+            Err(PaymentSplitterError::AccountIsNotDuePayment)
+        );
+    }
+
+    #[ink::test]
+    fn release_token_unknown_account() {
+        let accounts = accounts();
+        let token = AccountId::from(TOKEN);
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 70), (accounts.bob, 30)]);
+        instance.fund_token(1000);
+
+        assert_eq!(instance.releasable_token(token, accounts.eve), 0);
+        assert_eq!(
+            instance.release_token(token, accounts.eve),
+            Err(PaymentSplitterError::AccountHasNoShares)
+        );
+    }
+
     fn add_funds(account: AccountId, amount: Balance) {
         let balance = ink_env::balance::<ink_env::DefaultEnvironment>();
         ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(account, balance + amount);
@@ -147,4 +147,15 @@ mod proxy {
         assert!(result.is_err());
         assert_eq!(result, Err(OwnableError::CallerIsNotOwner));
     }
+
+    #[ink::test]
+    fn change_delegate_code_and_call_fails() {
+        let hash = Hash::try_from(CODE_HASH_0).unwrap();
+        let new_hash = Hash::try_from(CODE_HASH_1).unwrap();
+        let mut my_proxy = MyProxy::new(hash);
+        change_caller(AccountId::from([0x13; 32]));
+        let result = my_proxy.change_delegate_code_and_call(new_hash, [0x1; 4], vec![]);
+        assert_eq!(result, Err(OwnableError::CallerIsNotOwner));
+        assert_eq!(my_proxy.get_delegate_code(), hash);
+    }
 }
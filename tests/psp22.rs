@@ -190,6 +190,23 @@ mod psp22_test {
         }
     }
 
+    fn assert_approval_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_owner: AccountId,
+        expected_spender: AccountId,
+        expected_value: Balance,
+    ) {
+        let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer");
+        if let Event::Approval(Approval { owner, spender, value }) = decoded_event {
+            assert_eq!(owner, expected_owner, "encountered invalid Approval.owner");
+            assert_eq!(spender, expected_spender, "encountered invalid Approval.spender");
+            assert_eq!(value, expected_value, "encountered invalid Approval.value");
+        } else {
+            panic!("encountered unexpected event kind: expected an Approval event")
+        }
+    }
+
     /// The default constructor does its job.
     #[ink::test]
     fn new_works() {
@@ -255,6 +272,98 @@ mod psp22_test {
         );
     }
 
+    #[ink::test]
+    fn transfer_to_self_keeps_balance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(psp22.transfer(accounts.alice, 60, Vec::<u8>::new()).is_ok());
+
+        assert_eq!(psp22.balance_of(accounts.alice), 100);
+        assert_eq!(psp22.total_supply(), 100);
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+        assert_transfer_event(&emitted_events[1], Some(accounts.alice), Some(accounts.alice), 60);
+
+        // the whole balance can be moved to itself, but not more
+        assert!(psp22.transfer(accounts.alice, 100, Vec::<u8>::new()).is_ok());
+        assert_eq!(psp22.balance_of(accounts.alice), 100);
+        assert_eq!(
+            psp22.transfer(accounts.alice, 101, Vec::<u8>::new()),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(psp22.balance_of(accounts.alice), 100);
+    }
+
+    #[ink::test]
+    fn transfer_to_contract_address_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        // the callee is Alice by default in the off-chain environment
+        let contract = AccountId::from([0x10; 32]);
+        ink_env::test::set_callee::<ink_env::DefaultEnvironment>(contract);
+
+        assert!(psp22.transfer(contract, 40, Vec::<u8>::new()).is_ok());
+
+        assert_eq!(psp22.balance_of(contract), 40);
+        assert_eq!(psp22.balance_of(accounts.alice), 60);
+        assert_eq!(psp22.total_supply(), 100);
+
+        // the contract can spend its own tokens
+        change_caller(contract);
+        assert!(psp22.transfer(accounts.bob, 15, Vec::<u8>::new()).is_ok());
+        assert_eq!(psp22.balance_of(contract), 25);
+        assert_eq!(psp22.balance_of(accounts.bob), 15);
+        assert_eq!(psp22.total_supply(), 100);
+    }
+
+    #[ink::test]
+    fn mint_and_burn_change_only_total_supply() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        let zero_account = AccountId::from([0; 32]);
+
+        assert!(psp22._mint_to(accounts.bob, 50).is_ok());
+        assert_eq!(psp22.balance_of(accounts.bob), 50);
+        assert_eq!(psp22.total_supply(), 150);
+
+        assert!(psp22._burn_from(accounts.alice, 30).is_ok());
+        assert_eq!(psp22.balance_of(accounts.alice), 70);
+        assert_eq!(psp22.total_supply(), 120);
+
+        // no sentinel account takes part in minting and burning
+        assert_eq!(psp22.balance_of(zero_account), 0);
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        assert_transfer_event(&emitted_events[1], None, Some(accounts.bob), 50);
+        assert_transfer_event(&emitted_events[2], Some(accounts.alice), None, 30);
+    }
+
+    #[ink::test]
+    fn mint_and_burn_for_contract_address_work() {
+        let mut psp22 = PSP22Struct::new(100);
+        // the callee is Alice by default in the off-chain environment
+        let contract = AccountId::from([0x10; 32]);
+        ink_env::test::set_callee::<ink_env::DefaultEnvironment>(contract);
+
+        assert!(psp22._mint_to(contract, 50).is_ok());
+        assert_eq!(psp22.balance_of(contract), 50);
+        assert_eq!(psp22.total_supply(), 150);
+
+        assert!(psp22._burn_from(contract, 50).is_ok());
+        assert_eq!(psp22.balance_of(contract), 0);
+        assert_eq!(psp22.total_supply(), 100);
+    }
+
+    #[ink::test]
+    fn burn_more_than_balance_should_fail() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(psp22._burn_from(accounts.bob, 1), Err(PSP22Error::InsufficientBalance));
+        assert_eq!(psp22.total_supply(), 100);
+    }
+
     #[ink::test]
     fn invalid_transfer_should_fail() {
         // Constructor works.
@@ -370,4 +479,179 @@ mod psp22_test {
             Err(PSP22Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    #[ink::test]
+    fn increase_allowance_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(psp22.increase_allowance(accounts.bob, 10), Ok(()));
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), 10);
+        assert_eq!(psp22.increase_allowance(accounts.bob, 5), Ok(()));
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), 15);
+
+        // Approval events contain the new total allowance
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        assert_approval_event(&emitted_events[1], accounts.alice, accounts.bob, 10);
+        assert_approval_event(&emitted_events[2], accounts.alice, accounts.bob, 15);
+    }
+
+    #[ink::test]
+    fn decrease_allowance_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert_eq!(psp22.approve(accounts.bob, 10), Ok(()));
+
+        assert_eq!(psp22.decrease_allowance(accounts.bob, 4), Ok(()));
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), 6);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        assert_approval_event(&emitted_events[2], accounts.alice, accounts.bob, 6);
+    }
+
+    #[ink::test]
+    fn decrease_allowance_below_zero_fails() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert_eq!(psp22.approve(accounts.bob, 10), Ok(()));
+
+        assert_eq!(
+            psp22.decrease_allowance(accounts.bob, 11),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn transfer_from_uses_increased_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert_eq!(psp22.increase_allowance(accounts.bob, 10), Ok(()));
+        assert_eq!(psp22.increase_allowance(accounts.bob, 10), Ok(()));
+        assert_eq!(psp22.decrease_allowance(accounts.bob, 5), Ok(()));
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            psp22.transfer_from(accounts.alice, accounts.eve, 15, Vec::<u8>::new()),
+            Ok(())
+        );
+
+        assert_eq!(psp22.balance_of(accounts.eve), 15);
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), 0);
+        assert_eq!(
+            psp22.transfer_from(accounts.alice, accounts.eve, 1, Vec::<u8>::new()),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+    }
+
+    #[ink::test]
+    fn transfer_from_does_not_decrease_unlimited_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(psp22.approve(accounts.bob, Balance::MAX).is_ok());
+        // Mint and approve events
+        assert_eq!(ink_env::test::recorded_events().count(), 2);
+
+        change_caller(accounts.bob);
+        for _ in 0..3 {
+            assert_eq!(
+                psp22.transfer_from(accounts.alice, accounts.eve, 10, Vec::<u8>::new()),
+                Ok(())
+            );
+            assert_eq!(psp22.allowance(accounts.alice, accounts.bob), Balance::MAX);
+        }
+
+        assert_eq!(psp22.balance_of(accounts.eve), 30);
+        assert_eq!(psp22.balance_of(accounts.alice), 70);
+        // Only transfer events are emitted, the allowance is not updated
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 5);
+        for event in &emitted_events[2..] {
+            assert_transfer_event(event, Some(accounts.alice), Some(accounts.eve), 10);
+        }
+    }
+
+    #[ink::test]
+    fn transfer_from_decreases_allowance_below_max() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(psp22.approve(accounts.bob, Balance::MAX - 1).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            psp22.transfer_from(accounts.alice, accounts.eve, 10, Vec::<u8>::new()),
+            Ok(())
+        );
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), Balance::MAX - 11);
+    }
+
+    #[ink::test]
+    fn mint_fails_if_total_supply_overflows() {
+        let mut psp22 = PSP22Struct::new(Balance::MAX - 1);
+        let accounts = accounts();
+
+        assert_eq!(psp22._mint_to(accounts.bob, 2), Err(PSP22Error::Overflow));
+
+        assert_eq!(psp22.total_supply(), Balance::MAX - 1);
+        assert_eq!(psp22.balance_of(accounts.bob), 0);
+        // only the event of the initial mint
+        assert_eq!(ink_env::test::recorded_events().count(), 1);
+    }
+
+    #[ink::test]
+    fn mint_fails_if_balance_overflows() {
+        let mut psp22 = PSP22Struct::new(10);
+        let accounts = accounts();
+        // the balance is out of sync with the total supply to check the balance separately
+        psp22.psp22.balances.insert(&accounts.bob, &Balance::MAX);
+
+        assert_eq!(psp22._mint_to(accounts.bob, 1), Err(PSP22Error::Overflow));
+
+        assert_eq!(psp22.total_supply(), 10);
+        assert_eq!(psp22.balance_of(accounts.bob), Balance::MAX);
+    }
+
+    #[ink::test]
+    fn mint_up_to_max_supply_works() {
+        let mut psp22 = PSP22Struct::new(Balance::MAX - 1);
+        let accounts = accounts();
+
+        assert_eq!(psp22._mint_to(accounts.bob, 1), Ok(()));
+
+        assert_eq!(psp22.total_supply(), Balance::MAX);
+        assert_eq!(psp22._mint_to(accounts.bob, 1), Err(PSP22Error::Overflow));
+    }
+
+    #[ink::test]
+    fn approve_from_to_sets_allowance_on_behalf_of_owner() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        // The caller is Alice, but the allowance is set for Bob's tokens
+        assert_eq!(psp22._approve_from_to(accounts.bob, accounts.charlie, 25), Ok(()));
+
+        assert_eq!(psp22.allowance(accounts.bob, accounts.charlie), 25);
+        assert_eq!(psp22.allowance(accounts.alice, accounts.charlie), 0);
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+        assert_approval_event(&emitted_events[1], accounts.bob, accounts.charlie, 25);
+    }
+
+    #[ink::test]
+    fn approve_from_to_zero_account_should_fail() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(
+            psp22._approve_from_to([0; 32].into(), accounts.charlie, 25),
+            Err(PSP22Error::ZeroSenderAddress)
+        );
+        assert_eq!(
+            psp22._approve_from_to(accounts.bob, [0; 32].into(), 25),
+            Err(PSP22Error::ZeroRecipientAddress)
+        );
+        assert_eq!(ink_env::test::recorded_events().count(), 1);
+    }
 }
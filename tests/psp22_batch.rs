@@ -0,0 +1,160 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_batch {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::extensions::batch::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    /// Event emitted when a token transfer occurs.
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+    }
+
+    type Event = <PSP22Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl psp22::Internal for PSP22Struct {
+        fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, value: Balance) {
+            self.env().emit_event(Transfer { from, to, value });
+        }
+
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22Batch for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            instance
+        }
+    }
+
+    fn assert_transfer_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_from: Option<AccountId>,
+        expected_to: Option<AccountId>,
+        expected_value: Balance,
+    ) {
+        let Event::Transfer(Transfer { from, to, value }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer");
+        assert_eq!(from, expected_from, "encountered invalid Transfer.from");
+        assert_eq!(to, expected_to, "encountered invalid Transfer.to");
+        assert_eq!(value, expected_value, "encountered invalid Transfer.value");
+    }
+
+    #[ink::test]
+    fn batch_transfer_works() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(
+            instance.batch_transfer(
+                vec![(accounts.bob, 10), (accounts.charlie, 20), (accounts.django, 30)],
+                Vec::<u8>::new()
+            ),
+            Ok(())
+        );
+
+        assert_eq!(instance.balance_of(accounts.alice), 40);
+        assert_eq!(instance.balance_of(accounts.bob), 10);
+        assert_eq!(instance.balance_of(accounts.charlie), 20);
+        assert_eq!(instance.balance_of(accounts.django), 30);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 4);
+        assert_transfer_event(&emitted_events[1], Some(accounts.alice), Some(accounts.bob), 10);
+        assert_transfer_event(&emitted_events[2], Some(accounts.alice), Some(accounts.charlie), 20);
+        assert_transfer_event(&emitted_events[3], Some(accounts.alice), Some(accounts.django), 30);
+    }
+
+    #[ink::test]
+    fn batch_transfer_fails_if_running_balance_is_not_enough() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        // every transfer is less than the starting balance, but the last one exceeds the remaining balance
+        assert_eq!(
+            instance.batch_transfer(
+                vec![(accounts.bob, 40), (accounts.charlie, 40), (accounts.django, 30)],
+                Vec::<u8>::new()
+            ),
+            Err(PSP22Error::InsufficientBalance)
+        );
+
+        // none of transfers is applied
+        assert_eq!(instance.balance_of(accounts.alice), 100);
+        assert_eq!(instance.balance_of(accounts.bob), 0);
+        assert_eq!(instance.balance_of(accounts.charlie), 0);
+        assert_eq!(instance.balance_of(accounts.django), 0);
+        assert_eq!(ink_env::test::recorded_events().count(), 1);
+    }
+
+    #[ink::test]
+    fn batch_transfer_fails_with_zero_recipient() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(
+            instance.batch_transfer(
+                vec![(accounts.bob, 10), (AccountId::from([0; 32]), 10)],
+                Vec::<u8>::new()
+            ),
+            Err(PSP22Error::ZeroRecipientAddress)
+        );
+        assert_eq!(instance.balance_of(accounts.bob), 0);
+    }
+}
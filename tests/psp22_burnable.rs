@@ -57,6 +57,8 @@ mod psp22_burnable {
         return_err_on_before: bool,
         // field for testing _after_token_transfer
         return_err_on_after: bool,
+        // account, amount and total supply seen by the last _after_burn
+        last_burn: Option<(AccountId, Balance, Balance)>,
     }
 
     type Event = <PSP22Struct as ::ink_lang::reflect::ContractEventBase>::Type;
@@ -95,6 +97,11 @@ mod psp22_burnable {
             }
             Ok(())
         }
+
+        fn _after_burn(&mut self, from: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+            self.last_burn = Some((*from, *amount, self.total_supply()));
+            Ok(())
+        }
     }
 
     impl PSP22 for PSP22Struct {}
@@ -289,4 +296,81 @@ mod psp22_burnable {
             Err(PSP22Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    #[ink::test]
+    fn burn_from_decreases_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(psp22.approve(accounts.bob, 30).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(psp22.burn_from(accounts.alice, 10).is_ok());
+
+        assert_eq!(psp22.balance_of(accounts.alice), 90);
+        assert_eq!(psp22.total_supply(), 90);
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), 20);
+    }
+
+    #[ink::test]
+    fn burn_from_fails_with_insufficient_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(psp22.approve(accounts.bob, 5).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            psp22.burn_from(accounts.alice, 10),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+        assert_eq!(
+            psp22.burn_from(accounts.charlie, 1),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+
+        assert_eq!(psp22.balance_of(accounts.alice), 100);
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), 5);
+    }
+
+    #[ink::test]
+    fn burn_from_does_not_decrease_unlimited_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(psp22.approve(accounts.bob, Balance::MAX).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(psp22.burn_from(accounts.alice, 10).is_ok());
+
+        assert_eq!(psp22.balance_of(accounts.alice), 90);
+        assert_eq!(psp22.allowance(accounts.alice, accounts.bob), Balance::MAX);
+    }
+
+    #[ink::test]
+    fn after_burn_hook_records_burn() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert_eq!(psp22.last_burn, None);
+
+        assert!(psp22.burn(accounts.alice, 10).is_ok());
+        // the hook is called after the supply is decreased
+        assert_eq!(psp22.last_burn, Some((accounts.alice, 10, 90)));
+
+        assert!(psp22.approve(accounts.bob, 5).is_ok());
+        change_caller(accounts.bob);
+        assert!(psp22.burn_from(accounts.alice, 5).is_ok());
+        assert_eq!(psp22.last_burn, Some((accounts.alice, 5, 85)));
+    }
+
+    #[ink::test]
+    fn after_burn_hook_is_not_called_on_failed_burn_or_mint() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(psp22.burn(accounts.alice, 101), Err(PSP22Error::InsufficientBalance));
+        assert!(psp22._mint_to(accounts.bob, 10).is_ok());
+
+        assert_eq!(psp22.last_burn, None);
+    }
 }
@@ -0,0 +1,131 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_circulating_supply {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::extensions::circulating_supply::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        circulating_supply: circulating_supply::Data,
+    }
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22CirculatingSupply for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn circulating_supply_is_total_supply_by_default() {
+        let instance = PSP22Struct::new(1000);
+
+        assert_eq!(instance.circulating_supply(), 1000);
+        assert!(instance.excluded_from_circulation().is_empty());
+    }
+
+    #[ink::test]
+    fn excluded_treasury_is_not_circulating() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(1000);
+        // bob is the treasury
+        assert!(instance.transfer(accounts.bob, 400, Vec::<u8>::new()).is_ok());
+
+        instance._exclude_from_circulation(accounts.bob);
+        // excluding twice doesn't subtract the balance twice
+        instance._exclude_from_circulation(accounts.bob);
+
+        assert!(instance.is_excluded_from_circulation(accounts.bob));
+        assert_eq!(instance.excluded_from_circulation(), vec![accounts.bob]);
+        assert_eq!(instance.circulating_supply(), 600);
+    }
+
+    #[ink::test]
+    fn circulating_supply_follows_transfers_to_and_from_excluded() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(1000);
+        instance._exclude_from_circulation(accounts.bob);
+        instance._exclude_from_circulation(accounts.charlie);
+
+        assert!(instance.transfer(accounts.bob, 300, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.circulating_supply(), 700);
+        assert!(instance.transfer(accounts.charlie, 200, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.circulating_supply(), 500);
+
+        // the treasury pays out
+        change_caller(accounts.bob);
+        assert!(instance.transfer(accounts.django, 100, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.circulating_supply(), 600);
+
+        // minting to and burning from the excluded account doesn't change the circulating supply
+        assert!(instance._mint_to(accounts.charlie, 1000).is_ok());
+        assert!(instance._burn_from(accounts.charlie, 500).is_ok());
+        assert_eq!(instance.circulating_supply(), 600);
+    }
+
+    #[ink::test]
+    fn include_in_circulation_works() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(1000);
+        assert!(instance.transfer(accounts.bob, 400, Vec::<u8>::new()).is_ok());
+        instance._exclude_from_circulation(accounts.bob);
+        assert_eq!(instance.circulating_supply(), 600);
+
+        instance._include_in_circulation(accounts.bob);
+
+        assert!(!instance.is_excluded_from_circulation(accounts.bob));
+        assert_eq!(instance.circulating_supply(), 1000);
+    }
+}
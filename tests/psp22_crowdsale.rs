@@ -0,0 +1,193 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_crowdsale {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::{
+            utils::crowdsale::*,
+            *,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22CrowdsaleStruct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        crowdsale: crowdsale::Data,
+    }
+
+    impl PSP22 for PSP22CrowdsaleStruct {}
+
+    impl PSP22Crowdsale for PSP22CrowdsaleStruct {}
+
+    /// The sale is a part of the token contract, so it mints the tokens directly
+    impl crowdsale::Internal for PSP22CrowdsaleStruct {
+        fn _deliver_tokens(&mut self, beneficiary: AccountId, amount: Balance) -> Result<(), PSP22CrowdsaleError> {
+            self._mint_to(beneficiary, amount)?;
+            Ok(())
+        }
+    }
+
+    impl PSP22CrowdsaleStruct {
+        #[ink(constructor)]
+        pub fn new(wallet: AccountId, rate: Balance, cap: Balance, wallet_limit: Balance) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                let token = Self::env().account_id();
+                assert!(instance._init(token, wallet, rate, cap, wallet_limit).is_ok());
+            })
+        }
+    }
+
+    const RATE: Balance = 10;
+    const CAP: Balance = 100;
+    const WALLET_LIMIT: Balance = 60;
+
+    fn setup() -> PSP22CrowdsaleStruct {
+        PSP22CrowdsaleStruct::new(accounts().charlie, RATE, CAP, WALLET_LIMIT)
+    }
+
+    /// Calls `buy_tokens` from `caller` with `value` of the native currency
+    fn buy(
+        sale: &mut PSP22CrowdsaleStruct,
+        caller: AccountId,
+        beneficiary: AccountId,
+        value: Balance,
+    ) -> Result<(), PSP22CrowdsaleError> {
+        change_caller(caller);
+        let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        let balance = ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(contract).unwrap_or(0);
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(contract, balance + value);
+        ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(value);
+        sale.buy_tokens(beneficiary)
+    }
+
+    fn balance_of_native(account: AccountId) -> Balance {
+        ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(account).unwrap_or(0)
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let sale = setup();
+
+        assert_eq!(sale.token(), ink_env::account_id::<ink_env::DefaultEnvironment>());
+        assert_eq!(sale.wallet(), accounts().charlie);
+        assert_eq!(sale.rate(), RATE);
+        assert_eq!(sale.cap(), CAP);
+        assert_eq!(sale.wallet_limit(), WALLET_LIMIT);
+        assert_eq!(sale.funds_raised(), 0);
+    }
+
+    #[ink::test]
+    fn buy_under_cap_works() {
+        let mut sale = setup();
+        let accounts = accounts();
+        let wallet_balance = balance_of_native(accounts.charlie);
+
+        assert_eq!(buy(&mut sale, accounts.alice, accounts.bob, 30), Ok(()));
+
+        assert_eq!(sale.balance_of(accounts.bob), 30 * RATE);
+        assert_eq!(sale.total_supply(), 30 * RATE);
+        assert_eq!(sale.funds_raised(), 30);
+        assert_eq!(sale.contribution_of(accounts.bob), 30);
+        assert_eq!(sale.contribution_of(accounts.alice), 0);
+        assert_eq!(balance_of_native(accounts.charlie), wallet_balance + 30);
+    }
+
+    #[ink::test]
+    fn buy_at_cap_works() {
+        let mut sale = setup();
+        let accounts = accounts();
+
+        assert_eq!(buy(&mut sale, accounts.alice, accounts.bob, 50), Ok(()));
+        assert_eq!(buy(&mut sale, accounts.alice, accounts.django, 50), Ok(()));
+
+        assert_eq!(sale.funds_raised(), CAP);
+        assert_eq!(sale.total_supply(), CAP * RATE);
+        assert_eq!(
+            buy(&mut sale, accounts.alice, accounts.eve, 1),
+            Err(PSP22CrowdsaleError::CapReached)
+        );
+        assert_eq!(sale.balance_of(accounts.eve), 0);
+    }
+
+    #[ink::test]
+    fn buy_over_cap_refunds_excess() {
+        let mut sale = setup();
+        let accounts = accounts();
+        assert_eq!(buy(&mut sale, accounts.alice, accounts.bob, 50), Ok(()));
+        assert_eq!(buy(&mut sale, accounts.alice, accounts.django, 40), Ok(()));
+        let buyer_balance = balance_of_native(accounts.eve);
+        let wallet_balance = balance_of_native(accounts.charlie);
+
+        assert_eq!(buy(&mut sale, accounts.eve, accounts.eve, 30), Ok(()));
+
+        assert_eq!(sale.funds_raised(), CAP);
+        assert_eq!(sale.contribution_of(accounts.eve), 10);
+        assert_eq!(sale.balance_of(accounts.eve), 10 * RATE);
+        assert_eq!(balance_of_native(accounts.charlie), wallet_balance + 10);
+        assert_eq!(balance_of_native(accounts.eve), buyer_balance + 20);
+    }
+
+    #[ink::test]
+    fn buy_over_wallet_limit_should_fail() {
+        let mut sale = setup();
+        let accounts = accounts();
+        assert_eq!(buy(&mut sale, accounts.alice, accounts.bob, 40), Ok(()));
+
+        assert_eq!(
+            buy(&mut sale, accounts.django, accounts.bob, WALLET_LIMIT - 40 + 1),
+            Err(PSP22CrowdsaleError::WalletLimitExceeded)
+        );
+        assert_eq!(sale.funds_raised(), 40);
+        assert_eq!(sale.balance_of(accounts.bob), 40 * RATE);
+
+        assert_eq!(buy(&mut sale, accounts.django, accounts.bob, WALLET_LIMIT - 40), Ok(()));
+        assert_eq!(sale.contribution_of(accounts.bob), WALLET_LIMIT);
+    }
+
+    #[ink::test]
+    fn buy_with_zero_value_or_beneficiary_should_fail() {
+        let mut sale = setup();
+        let accounts = accounts();
+
+        assert_eq!(
+            buy(&mut sale, accounts.alice, accounts.bob, 0),
+            Err(PSP22CrowdsaleError::ZeroPurchase)
+        );
+        assert_eq!(
+            buy(&mut sale, accounts.alice, [0; 32].into(), 10),
+            Err(PSP22CrowdsaleError::ZeroBeneficiaryAddress)
+        );
+        assert_eq!(sale.funds_raised(), 0);
+    }
+}
@@ -36,6 +36,7 @@ mod psp22_flashmint {
     pub struct PSP22FlashMintStruct {
         #[storage_field]
         psp22: psp22::Data,
+        fee_receiver: Option<AccountId>,
     }
 
     impl PSP22 for PSP22FlashMintStruct {}
@@ -47,6 +48,10 @@ mod psp22_flashmint {
             amount / 100
         }
 
+        fn _flash_fee_receiver(&self) -> Option<AccountId> {
+            self.fee_receiver
+        }
+
         fn _on_flashloan(
             &mut self,
             _receiver_account: AccountId,
@@ -123,4 +128,25 @@ mod psp22_flashmint {
             Err(FlashLenderError::AllowanceDoesNotAllowRefund)
         );
     }
+
+    #[ink::test]
+    fn flashloan_fee_goes_to_fee_receiver() {
+        let total_supply = 1000;
+        let mut instance = PSP22FlashMintStruct::new(total_supply);
+        let fee_receiver = accounts().charlie;
+        instance.fee_receiver = Some(fee_receiver);
+
+        let receiver = AccountId::from([0x1; 32]);
+        let token = instance.env().account_id();
+        let loan_amount = 100;
+        let fee = instance._get_fee(loan_amount);
+
+        assert!(instance.approve(token, loan_amount + fee).is_ok());
+        assert!(instance
+            .flashloan(receiver, token, loan_amount, Vec::<u8>::new())
+            .is_ok());
+        assert_eq!(instance.total_supply(), total_supply);
+        assert_eq!(instance.balance_of(accounts().alice), total_supply - fee);
+        assert_eq!(instance.balance_of(fee_receiver), fee);
+    }
 }
@@ -0,0 +1,147 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_holder_count {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::extensions::holder_count::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        holder_count: holder_count::Data,
+    }
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl psp22::Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._update_holder_count(from, to, amount);
+            Ok(())
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22HolderCount for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn mint_counts_new_holders() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert_eq!(instance.holder_count(), 1);
+
+        assert!(instance._mint_to(accounts.bob, 10).is_ok());
+        assert_eq!(instance.holder_count(), 2);
+        // minting to the existing holder doesn't change the count
+        assert!(instance._mint_to(accounts.bob, 10).is_ok());
+        assert!(instance._mint_to(accounts.alice, 10).is_ok());
+        assert_eq!(instance.holder_count(), 2);
+    }
+
+    #[ink::test]
+    fn zero_amount_mint_does_not_count_holder() {
+        let mut instance = PSP22Struct::new(100);
+
+        assert!(instance._mint_to(accounts().bob, 0).is_ok());
+        assert_eq!(instance.holder_count(), 1);
+    }
+
+    #[ink::test]
+    fn full_burn_removes_holder() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(instance._burn_from(accounts.alice, 40).is_ok());
+        assert_eq!(instance.holder_count(), 1);
+        assert!(instance._burn_from(accounts.alice, 60).is_ok());
+        assert_eq!(instance.holder_count(), 0);
+    }
+
+    #[ink::test]
+    fn transfer_updates_holder_count() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(instance.transfer(accounts.bob, 30, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.holder_count(), 2);
+
+        // alice sends the whole balance to charlie: one account is emptied, another one is filled
+        assert!(instance.transfer(accounts.charlie, 70, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.balance_of(accounts.alice), 0);
+        assert_eq!(instance.holder_count(), 2);
+    }
+
+    #[ink::test]
+    fn transfer_of_whole_balance_to_holder_removes_holder() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance._mint_to(accounts.bob, 10).is_ok());
+
+        assert!(instance.transfer(accounts.bob, 100, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.holder_count(), 1);
+    }
+
+    #[ink::test]
+    fn self_and_zero_amount_transfers_do_not_change_count() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(instance.transfer(accounts.alice, 100, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.holder_count(), 1);
+        assert!(instance.transfer(accounts.bob, 0, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.holder_count(), 1);
+    }
+}
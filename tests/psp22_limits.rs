@@ -0,0 +1,228 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::contract]
+mod psp22_limits {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::extensions::limits::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const MAX_BALANCE: Balance = 500;
+    const MAX_TX_AMOUNT: Balance = 100;
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        limits: limits::Data,
+    }
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl psp22::Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._check_transfer_limits(from, to, amount)
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl Ownable for PSP22Struct {}
+
+    impl PSP22Limits for PSP22Struct {}
+
+    impl PSP22Struct {
+        /// Charlie is the owner, so only transfers between Alice and Bob are limited
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut PSP22Struct| {
+                instance._init_with_owner(accounts().charlie);
+                instance._set_max_balance(MAX_BALANCE);
+                instance._set_max_tx_amount(MAX_TX_AMOUNT);
+                assert!(instance._mint_to(accounts().alice, 1000).is_ok());
+                assert!(instance._mint_to(accounts().charlie, 1000).is_ok());
+            })
+        }
+    }
+
+    #[ink::test]
+    fn set_limits_works() {
+        let mut instance = PSP22Struct::new();
+        assert_eq!(instance.max_balance(), MAX_BALANCE);
+        assert_eq!(instance.max_tx_amount(), MAX_TX_AMOUNT);
+
+        change_caller(accounts().charlie);
+        assert_eq!(instance.set_max_balance(1), Ok(()));
+        assert_eq!(instance.set_max_tx_amount(2), Ok(()));
+
+        assert_eq!(instance.max_balance(), 1);
+        assert_eq!(instance.max_tx_amount(), 2);
+    }
+
+    #[ink::test]
+    fn only_owner_can_set_limits() {
+        let mut instance = PSP22Struct::new();
+
+        change_caller(accounts().alice);
+        assert_eq!(
+            instance.set_max_balance(0),
+            Err(PSP22Error::from(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(
+            instance.set_max_tx_amount(0),
+            Err(PSP22Error::from(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(instance.max_balance(), MAX_BALANCE);
+        assert_eq!(instance.max_tx_amount(), MAX_TX_AMOUNT);
+    }
+
+    #[ink::test]
+    fn transfer_within_limits_works() {
+        let mut instance = PSP22Struct::new();
+
+        change_caller(accounts().alice);
+        assert_eq!(
+            instance.transfer(accounts().bob, MAX_TX_AMOUNT, Vec::<u8>::new()),
+            Ok(())
+        );
+        assert_eq!(instance.balance_of(accounts().bob), MAX_TX_AMOUNT);
+    }
+
+    #[ink::test]
+    fn transfer_exceeding_tx_cap_fails() {
+        let mut instance = PSP22Struct::new();
+
+        change_caller(accounts().alice);
+        assert_eq!(
+            instance.transfer(accounts().bob, MAX_TX_AMOUNT + 1, Vec::<u8>::new()),
+            Err(PSP22Error::MaxTxExceeded)
+        );
+        assert_eq!(instance.balance_of(accounts().bob), 0);
+    }
+
+    #[ink::test]
+    fn transfer_from_exceeding_tx_cap_fails() {
+        let mut instance = PSP22Struct::new();
+        change_caller(accounts().alice);
+        assert_eq!(instance.approve(accounts().bob, 1000), Ok(()));
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.transfer_from(accounts().alice, accounts().bob, MAX_TX_AMOUNT + 1, Vec::<u8>::new()),
+            Err(PSP22Error::MaxTxExceeded)
+        );
+        assert_eq!(instance.balance_of(accounts().bob), 0);
+        // the allowance is spent before the transfer is rejected, and the failed message doesn't revert it
+        assert_eq!(
+            instance.allowance(accounts().alice, accounts().bob),
+            1000 - (MAX_TX_AMOUNT + 1)
+        );
+    }
+
+    #[ink::test]
+    fn transfer_over_balance_cap_fails() {
+        let mut instance = PSP22Struct::new();
+        change_caller(accounts().charlie);
+        assert_eq!(
+            instance.transfer(accounts().bob, MAX_BALANCE - 50, Vec::<u8>::new()),
+            Ok(())
+        );
+
+        // 50 tokens fit the cap, 51 tokens push the balance of Bob over it
+        change_caller(accounts().alice);
+        assert_eq!(
+            instance.transfer(accounts().bob, 51, Vec::<u8>::new()),
+            Err(PSP22Error::MaxBalanceExceeded)
+        );
+        assert_eq!(instance.transfer(accounts().bob, 50, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.balance_of(accounts().bob), MAX_BALANCE);
+    }
+
+    #[ink::test]
+    fn owner_is_exempt() {
+        let mut instance = PSP22Struct::new();
+
+        // the owner sends more than the tx cap; the cap applies only to the balance of the recipient
+        change_caller(accounts().charlie);
+        assert_eq!(
+            instance.transfer(accounts().bob, MAX_TX_AMOUNT + 1, Vec::<u8>::new()),
+            Ok(())
+        );
+        assert_eq!(
+            instance.transfer(accounts().bob, MAX_BALANCE - MAX_TX_AMOUNT, Vec::<u8>::new()),
+            Err(PSP22Error::MaxBalanceExceeded)
+        );
+
+        // the owner can receive more than both caps
+        change_caller(accounts().alice);
+        assert_eq!(instance.transfer(accounts().charlie, 1000, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.balance_of(accounts().charlie), 1899);
+    }
+
+    #[ink::test]
+    fn minting_and_burning_are_exempt() {
+        let mut instance = PSP22Struct::new();
+
+        assert_eq!(instance._mint_to(accounts().bob, MAX_BALANCE * 2), Ok(()));
+        assert_eq!(instance._burn_from(accounts().bob, MAX_TX_AMOUNT * 2), Ok(()));
+        assert_eq!(instance.balance_of(accounts().bob), MAX_BALANCE * 2 - MAX_TX_AMOUNT * 2);
+    }
+
+    #[ink::test]
+    fn zero_limits_mean_no_limit() {
+        let mut instance = PSP22Struct::new();
+        instance._set_max_balance(0);
+        instance._set_max_tx_amount(0);
+
+        change_caller(accounts().alice);
+        assert_eq!(instance.transfer(accounts().bob, 1000, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.balance_of(accounts().bob), 1000);
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+mod psp22_math {
+    use openbrush::{
+        contracts::psp22::math::*,
+        traits::Balance,
+    };
+
+    #[test]
+    fn scale_to_same_decimals_works() {
+        assert_eq!(scale_to(1234, 6, 6), Ok(1234));
+        assert_eq!(scale_to(Balance::MAX, 18, 18), Ok(Balance::MAX));
+    }
+
+    #[test]
+    fn upscaling_works() {
+        assert_eq!(scale_to(15, 6, 18), Ok(15_000_000_000_000));
+        assert_eq!(scale_to(1, 0, 38), Ok(10u128.pow(38)));
+        assert_eq!(scale_to(0, 0, 38), Ok(0));
+    }
+
+    #[test]
+    fn downscaling_works() {
+        assert_eq!(scale_to(15_000_000_000_000, 18, 6), Ok(15));
+        assert_eq!(scale_to(10u128.pow(38), 38, 0), Ok(1));
+    }
+
+    #[test]
+    fn downscaling_truncates() {
+        assert_eq!(scale_to(1999, 3, 0), Ok(1));
+        assert_eq!(scale_to(999, 3, 0), Ok(0));
+        // the factor doesn't fit `Balance`, so nothing is left
+        assert_eq!(scale_to(Balance::MAX, 255, 0), Ok(0));
+    }
+
+    #[test]
+    fn upscaling_fails_on_overflow() {
+        assert_eq!(scale_to(Balance::MAX, 0, 1), Err(PSP22Error::Overflow));
+        assert_eq!(scale_to(4, 0, 38), Err(PSP22Error::Overflow));
+        // the factor doesn't fit `Balance`
+        assert_eq!(scale_to(1, 0, 39), Err(PSP22Error::Overflow));
+    }
+
+    #[test]
+    fn round_trip_keeps_amount() {
+        let amount = 123_456_789;
+        let scaled = scale_to(amount, 6, 18).unwrap();
+        assert_eq!(scale_to(scaled, 18, 6), Ok(amount));
+    }
+}
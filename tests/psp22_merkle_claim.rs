@@ -0,0 +1,227 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_merkle_claim {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use ink_prelude::vec::Vec;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::{
+            utils::merkle_claim::*,
+            *,
+        },
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct Claimed {
+        index: u32,
+        account: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22MerkleClaimStruct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        merkle_claim: merkle_claim::Data,
+    }
+
+    type Event = <PSP22MerkleClaimStruct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl PSP22 for PSP22MerkleClaimStruct {}
+
+    impl PSP22MerkleClaim for PSP22MerkleClaimStruct {}
+
+    /// The distribution is a part of the token contract, so it mints the tokens directly
+    impl merkle_claim::Internal for PSP22MerkleClaimStruct {
+        fn _emit_claimed_event(&self, index: u32, account: AccountId, amount: Balance) {
+            self.env().emit_event(Claimed { index, account, amount });
+        }
+
+        fn _deliver_tokens(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22MerkleClaimError> {
+            self._mint_to(account, amount)?;
+            Ok(())
+        }
+    }
+
+    impl PSP22MerkleClaimStruct {
+        #[ink(constructor)]
+        pub fn new(merkle_root: [u8; 32]) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                let token = Self::env().account_id();
+                instance._init(token, merkle_root);
+            })
+        }
+    }
+
+    /// The tree of four leaves:
+    ///
+    ///            root
+    ///          /      \
+    ///       n01        n23
+    ///      /   \      /   \
+    ///    l0    l1   l2     l3
+    struct Tree {
+        leaves: Vec<(u32, AccountId, Balance)>,
+        hashes: Vec<[u8; 32]>,
+        root: [u8; 32],
+    }
+
+    impl Tree {
+        fn new(contract: &PSP22MerkleClaimStruct) -> Self {
+            let accounts = accounts();
+            let leaves = vec![
+                (0, accounts.alice, 100),
+                (1, accounts.bob, 200),
+                (2, accounts.charlie, 300),
+                (3, accounts.django, 400),
+            ];
+            let hashes: Vec<[u8; 32]> = leaves
+                .iter()
+                .map(|(index, account, amount)| contract._leaf(*index, account, *amount))
+                .collect();
+            let root = hash_pair(&hash_pair(&hashes[0], &hashes[1]), &hash_pair(&hashes[2], &hashes[3]));
+            Self { leaves, hashes, root }
+        }
+
+        fn proof(&self, index: usize) -> Vec<[u8; 32]> {
+            let sibling = self.hashes[index ^ 1];
+            let pair = if index < 2 {
+                hash_pair(&self.hashes[2], &self.hashes[3])
+            } else {
+                hash_pair(&self.hashes[0], &self.hashes[1])
+            };
+            vec![sibling, pair]
+        }
+    }
+
+    fn setup() -> (PSP22MerkleClaimStruct, Tree) {
+        let tree = Tree::new(&PSP22MerkleClaimStruct::new([0; 32]));
+        (PSP22MerkleClaimStruct::new(tree.root), tree)
+    }
+
+    fn assert_claimed_event(event: &ink_env::test::EmittedEvent, index: u32, account: AccountId, amount: Balance) {
+        let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer");
+        if let Event::Claimed(Claimed {
+            index: event_index,
+            account: event_account,
+            amount: event_amount,
+        }) = decoded_event
+        {
+            assert_eq!(event_index, index, "encountered invalid Claimed.index");
+            assert_eq!(event_account, account, "encountered invalid Claimed.account");
+            assert_eq!(event_amount, amount, "encountered invalid Claimed.amount");
+        } else {
+            panic!("encountered unexpected event kind: expected a Claimed event")
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let (instance, tree) = setup();
+
+        assert_eq!(instance.token(), instance.env().account_id());
+        assert_eq!(instance.merkle_root(), tree.root);
+        assert!(!instance.is_claimed(0));
+    }
+
+    #[ink::test]
+    fn claim_works() {
+        let (mut instance, tree) = setup();
+
+        for (i, (index, account, amount)) in tree.leaves.iter().enumerate() {
+            assert_eq!(instance.claim(*index, *account, *amount, tree.proof(i)), Ok(()));
+            assert!(instance.is_claimed(*index));
+            assert_eq!(instance.balance_of(*account), *amount);
+        }
+        assert_eq!(instance.total_supply(), 1000);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        let claimed_events: Vec<_> = emitted_events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    <Event as scale::Decode>::decode(&mut &event.data[..]),
+                    Ok(Event::Claimed(_))
+                )
+            })
+            .collect();
+        assert_eq!(claimed_events.len(), 4);
+        for (event, (index, account, amount)) in claimed_events.iter().zip(tree.leaves.iter()) {
+            assert_claimed_event(event, *index, *account, *amount);
+        }
+    }
+
+    #[ink::test]
+    fn claim_fails_if_already_claimed() {
+        let (mut instance, tree) = setup();
+        let (index, account, amount) = tree.leaves[1];
+        assert_eq!(instance.claim(index, account, amount, tree.proof(1)), Ok(()));
+
+        assert_eq!(
+            instance.claim(index, account, amount, tree.proof(1)),
+            Err(PSP22MerkleClaimError::AlreadyClaimed)
+        );
+        assert_eq!(instance.balance_of(account), amount);
+    }
+
+    #[ink::test]
+    fn claim_fails_with_forged_proof() {
+        let (mut instance, tree) = setup();
+        let (index, account, amount) = tree.leaves[1];
+
+        // The amount isn't committed in the tree
+        assert_eq!(
+            instance.claim(index, account, amount * 10, tree.proof(1)),
+            Err(PSP22MerkleClaimError::InvalidProof)
+        );
+        // The proof of another leaf
+        assert_eq!(
+            instance.claim(index, account, amount, tree.proof(2)),
+            Err(PSP22MerkleClaimError::InvalidProof)
+        );
+        // The account isn't committed in the tree
+        assert_eq!(
+            instance.claim(index, accounts().eve, amount, tree.proof(1)),
+            Err(PSP22MerkleClaimError::InvalidProof)
+        );
+        // The proof made from arbitrary hashes
+        assert_eq!(
+            instance.claim(index, account, amount, vec![[1; 32], [2; 32]]),
+            Err(PSP22MerkleClaimError::InvalidProof)
+        );
+
+        assert!(!instance.is_claimed(index));
+        assert_eq!(instance.total_supply(), 0);
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::contract]
+mod psp22_metadata_mutable {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            psp22::extensions::metadata_mutable::*,
+        },
+        modifiers,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(event)]
+    pub struct MetadataUpdated {
+        name: Option<String>,
+        symbol: Option<String>,
+        decimals: u8,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22Metadata for PSP22Struct {}
+
+    impl Ownable for PSP22Struct {}
+
+    impl metadata_mutable::Internal for PSP22Struct {
+        fn _emit_metadata_updated_event(&self, name: Option<String>, symbol: Option<String>, decimals: u8) {
+            self.env().emit_event(MetadataUpdated { name, symbol, decimals })
+        }
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(name: Option<String>, symbol: Option<String>, decimal: u8) -> Self {
+            let mut instance = Self::default();
+            instance.metadata.name = name;
+            instance.metadata.symbol = symbol;
+            instance.metadata.decimals = decimal;
+            instance._init_with_owner(Self::env().caller());
+            instance
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_token_name(&mut self, name: Option<String>) -> Result<(), OwnableError> {
+            self._set_token_name(name);
+            Ok(())
+        }
+    }
+
+    type Event = <PSP22Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    fn assert_metadata_updated_event(
+        event: &ink_env::test::EmittedEvent,
+        expected_name: Option<String>,
+        expected_symbol: Option<String>,
+        expected_decimals: u8,
+    ) {
+        let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer");
+        let Event::MetadataUpdated(MetadataUpdated { name, symbol, decimals }) = decoded_event;
+
+        assert_eq!(name, expected_name);
+        assert_eq!(symbol, expected_symbol);
+        assert_eq!(decimals, expected_decimals);
+    }
+
+    fn token() -> PSP22Struct {
+        PSP22Struct::new(Some(String::from("TOKEN")), Some(String::from("TKN")), 18)
+    }
+
+    #[ink::test]
+    fn set_token_name_works() {
+        let mut token = token();
+
+        assert_eq!(token.set_token_name(Some(String::from("NEW TOKEN"))), Ok(()));
+
+        assert_eq!(token.token_name(), Some(String::from("NEW TOKEN")));
+        assert_eq!(token.token_symbol(), Some(String::from("TKN")));
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 1);
+        assert_metadata_updated_event(
+            &emitted_events[0],
+            Some(String::from("NEW TOKEN")),
+            Some(String::from("TKN")),
+            18,
+        );
+    }
+
+    #[ink::test]
+    fn set_token_symbol_and_decimals_works() {
+        let mut token = token();
+
+        token._set_token_symbol(Some(String::from("NTKN")));
+        token._set_token_decimals(6);
+
+        assert_eq!(token.token_symbol(), Some(String::from("NTKN")));
+        assert_eq!(token.token_decimals(), 6);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+        assert_metadata_updated_event(
+            &emitted_events[1],
+            Some(String::from("TOKEN")),
+            Some(String::from("NTKN")),
+            6,
+        );
+    }
+
+    #[ink::test]
+    fn set_token_name_fails_if_not_owner() {
+        let mut token = token();
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            token.set_token_name(Some(String::from("NEW TOKEN"))),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(token.token_name(), Some(String::from("TOKEN")));
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_mint_burn_events {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    /// Event emitted when a token transfer occurs.
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    /// Event emitted when tokens are minted.
+    #[ink(event)]
+    pub struct Mint {
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    /// Event emitted when tokens are burnt.
+    #[ink(event)]
+    pub struct Burn {
+        #[ink(topic)]
+        from: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+    }
+
+    type Event = <PSP22Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl psp22::Internal for PSP22Struct {
+        fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
+            self.env().emit_event(Transfer {
+                from,
+                to,
+                value: amount,
+            });
+        }
+
+        fn _emit_mint_event(&self, to: AccountId, amount: Balance) {
+            self.env().emit_event(Mint { to, amount });
+        }
+
+        fn _emit_burn_event(&self, from: AccountId, amount: Balance) {
+            self.env().emit_event(Burn { from, amount });
+        }
+
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn mint(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+            self._mint_to(account, amount)
+        }
+
+        #[ink(message)]
+        pub fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+            self._burn_from(account, amount)
+        }
+    }
+
+    fn decode_events() -> Vec<Event> {
+        ink_env::test::recorded_events()
+            .map(|event| {
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            })
+            .collect()
+    }
+
+    #[ink::test]
+    fn mint_emits_transfer_and_mint_events() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+
+        assert!(instance.mint(accounts.bob, 100).is_ok());
+
+        let events = decode_events();
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            Event::Transfer(Transfer { from, to, value }) => {
+                assert_eq!(*from, None);
+                assert_eq!(*to, Some(accounts.bob));
+                assert_eq!(*value, 100);
+            }
+            _ => panic!("expected Transfer event first"),
+        }
+        match &events[1] {
+            Event::Mint(Mint { to, amount }) => {
+                assert_eq!(*to, accounts.bob);
+                assert_eq!(*amount, 100);
+            }
+            _ => panic!("expected Mint event after Transfer"),
+        }
+    }
+
+    #[ink::test]
+    fn burn_emits_transfer_and_burn_events() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+        assert!(instance.mint(accounts.bob, 100).is_ok());
+
+        assert!(instance.burn(accounts.bob, 40).is_ok());
+
+        let events = decode_events();
+        assert_eq!(events.len(), 4);
+        match &events[2] {
+            Event::Transfer(Transfer { from, to, value }) => {
+                assert_eq!(*from, Some(accounts.bob));
+                assert_eq!(*to, None);
+                assert_eq!(*value, 40);
+            }
+            _ => panic!("expected Transfer event first"),
+        }
+        match &events[3] {
+            Event::Burn(Burn { from, amount }) => {
+                assert_eq!(*from, accounts.bob);
+                assert_eq!(*amount, 40);
+            }
+            _ => panic!("expected Burn event after Transfer"),
+        }
+    }
+
+    #[ink::test]
+    fn transfer_does_not_emit_mint_or_burn_events() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+        assert!(instance.mint(accounts.alice, 100).is_ok());
+
+        assert!(instance.transfer(accounts.bob, 10, Vec::<u8>::new()).is_ok());
+
+        let events = decode_events();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[2], Event::Transfer(_)));
+    }
+
+    #[ink::test]
+    fn failed_burn_emits_no_events() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+
+        assert_eq!(instance.burn(accounts.bob, 1), Err(PSP22Error::InsufficientBalance));
+        assert_eq!(decode_events().len(), 0);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_mint_rate_limit {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::extensions::{
+            mint_rate_limit::*,
+            mintable::*,
+        },
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        mint_rate_limit: mint_rate_limit::Data,
+    }
+
+    impl psp22::Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            // `is_none` means that it is minting
+            if from.is_none() {
+                self._consume_mint_allowance(amount)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22Mintable for PSP22Struct {}
+
+    impl PSP22MintRateLimit for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(period: Timestamp, cap_per_period: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(instance._init_mint_rate_limit(period, cap_per_period).is_ok());
+            instance
+        }
+    }
+
+    const PERIOD: Timestamp = 1200;
+    const CAP: Balance = 100;
+
+    /// Moves the block timestamp to `timestamp`. The off-chain environment moves the time
+    /// only by whole blocks, so `timestamp` must be a multiple of the block time.
+    fn set_block_timestamp(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+        assert_eq!(ink_env::block_timestamp::<ink_env::DefaultEnvironment>(), timestamp);
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let instance = PSP22Struct::new(PERIOD, CAP);
+
+        assert_eq!(instance.mint_period(), PERIOD);
+        assert_eq!(instance.mint_cap_per_period(), CAP);
+        assert_eq!(instance.mint_allowance_remaining(), CAP);
+    }
+
+    #[ink::test]
+    fn init_with_zero_period_should_fail() {
+        let mut instance = PSP22Struct::default();
+
+        // mints are not limited without the period
+        assert_eq!(instance.mint_allowance_remaining(), Balance::MAX);
+        assert!(instance.mint(accounts().alice, 1000).is_ok());
+        assert!(instance._init_mint_rate_limit(0, CAP).is_err());
+    }
+
+    #[ink::test]
+    fn mint_up_to_cap_works() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(PERIOD, CAP);
+
+        assert!(instance.mint(accounts.alice, 60).is_ok());
+        assert_eq!(instance.mint_allowance_remaining(), 40);
+        assert!(instance.mint(accounts.bob, 40).is_ok());
+
+        assert_eq!(instance.mint_allowance_remaining(), 0);
+        assert_eq!(instance.total_supply(), CAP);
+    }
+
+    #[ink::test]
+    fn mint_above_cap_should_fail() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(PERIOD, CAP);
+        assert!(instance.mint(accounts.alice, 60).is_ok());
+
+        assert_eq!(
+            instance.mint(accounts.alice, 41),
+            Err(PSP22Error::MintRateLimitExceeded)
+        );
+        assert_eq!(instance.mint_allowance_remaining(), 40);
+        assert_eq!(instance.total_supply(), 60);
+    }
+
+    #[ink::test]
+    fn mint_allowance_is_reset_in_next_window() {
+        let accounts = accounts();
+        set_block_timestamp(510);
+        let mut instance = PSP22Struct::new(PERIOD, CAP);
+        assert!(instance.mint(accounts.alice, CAP).is_ok());
+        assert!(instance.mint(accounts.alice, 1).is_err());
+
+        // still the first window
+        set_block_timestamp(510 + PERIOD - 30);
+        assert_eq!(instance.mint_allowance_remaining(), 0);
+        assert!(instance.mint(accounts.alice, 1).is_err());
+
+        set_block_timestamp(510 + PERIOD);
+        assert_eq!(instance.mint_allowance_remaining(), CAP);
+        assert!(instance.mint(accounts.alice, CAP).is_ok());
+        assert!(instance.mint(accounts.alice, 1).is_err());
+        assert_eq!(instance.total_supply(), 2 * CAP);
+    }
+
+    #[ink::test]
+    fn mints_of_previous_window_are_not_counted() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(PERIOD, CAP);
+        // minted at the end of the first window
+        set_block_timestamp(PERIOD - 30);
+        assert!(instance.mint(accounts.alice, 80).is_ok());
+
+        // the window boundary is crossed, only the new mints count against the current window
+        set_block_timestamp(PERIOD + 30);
+        assert!(instance.mint(accounts.alice, 80).is_ok());
+        assert_eq!(instance.mint_allowance_remaining(), 20);
+
+        // several windows are skipped
+        set_block_timestamp(5 * PERIOD + 30);
+        assert_eq!(instance.mint_allowance_remaining(), CAP);
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_partial_freeze {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::extensions::partial_freeze::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        partial_freeze: partial_freeze::Data,
+    }
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl psp22::Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._ensure_unfrozen_balance(from, amount)
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22PartialFreeze for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            })
+        }
+    }
+
+    #[ink::test]
+    fn freeze_works() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert_eq!(instance.frozen_balance(accounts.alice), 0);
+
+        assert!(instance._freeze(accounts.alice, 30).is_ok());
+        assert!(instance._freeze(accounts.alice, 20).is_ok());
+        assert_eq!(instance.frozen_balance(accounts.alice), 50);
+        assert_eq!(instance.frozen_balance(accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn freeze_fails_above_balance() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(
+            instance._freeze(accounts.alice, 101),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert!(instance._freeze(accounts.alice, 60).is_ok());
+        assert_eq!(
+            instance._freeze(accounts.alice, 41),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(
+            instance._freeze(accounts.alice, Balance::MAX),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(instance._freeze(accounts.bob, 1), Err(PSP22Error::InsufficientBalance));
+        assert_eq!(instance.frozen_balance(accounts.alice), 60);
+    }
+
+    #[ink::test]
+    fn transfer_of_unfrozen_balance_works() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance._freeze(accounts.alice, 50).is_ok());
+
+        assert!(instance.transfer(accounts.bob, 30, Vec::<u8>::new()).is_ok());
+        assert!(instance.transfer(accounts.bob, 20, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.balance_of(accounts.alice), 50);
+        assert_eq!(instance.balance_of(accounts.bob), 50);
+        assert_eq!(instance.frozen_balance(accounts.alice), 50);
+    }
+
+    #[ink::test]
+    fn transfer_fails_above_unfrozen_balance() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance._freeze(accounts.alice, 50).is_ok());
+
+        assert_eq!(
+            instance.transfer(accounts.bob, 51, Vec::<u8>::new()),
+            Err(PSP22Error::InsufficientUnfrozenBalance)
+        );
+        assert!(instance.transfer(accounts.bob, 50, Vec::<u8>::new()).is_ok());
+        assert_eq!(
+            instance.transfer(accounts.bob, 1, Vec::<u8>::new()),
+            Err(PSP22Error::InsufficientUnfrozenBalance)
+        );
+        assert_eq!(instance.balance_of(accounts.alice), 50);
+        assert_eq!(instance.balance_of(accounts.bob), 50);
+    }
+
+    #[ink::test]
+    fn transfer_from_fails_above_unfrozen_balance() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance.approve(accounts.bob, 100).is_ok());
+        assert!(instance._freeze(accounts.alice, 50).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            instance.transfer_from(accounts.alice, accounts.charlie, 60, Vec::<u8>::new()),
+            Err(PSP22Error::InsufficientUnfrozenBalance)
+        );
+        assert!(instance
+            .transfer_from(accounts.alice, accounts.charlie, 50, Vec::<u8>::new())
+            .is_ok());
+        assert_eq!(instance.balance_of(accounts.charlie), 50);
+    }
+
+    #[ink::test]
+    fn received_tokens_are_not_frozen() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance._freeze(accounts.alice, 100).is_ok());
+        assert!(instance._mint_to(accounts.alice, 10).is_ok());
+
+        assert!(instance.transfer(accounts.bob, 10, Vec::<u8>::new()).is_ok());
+        assert_eq!(
+            instance.transfer(accounts.bob, 1, Vec::<u8>::new()),
+            Err(PSP22Error::InsufficientUnfrozenBalance)
+        );
+    }
+
+    #[ink::test]
+    fn burn_of_frozen_balance_fails() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance._freeze(accounts.alice, 50).is_ok());
+
+        assert_eq!(
+            instance._burn_from(accounts.alice, 51),
+            Err(PSP22Error::InsufficientUnfrozenBalance)
+        );
+        assert!(instance._burn_from(accounts.alice, 50).is_ok());
+        assert_eq!(instance.total_supply(), 50);
+    }
+
+    #[ink::test]
+    fn unfreeze_works() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance._freeze(accounts.alice, 50).is_ok());
+
+        instance._unfreeze(accounts.alice, 20);
+        assert_eq!(instance.frozen_balance(accounts.alice), 30);
+        assert!(instance.transfer(accounts.bob, 70, Vec::<u8>::new()).is_ok());
+
+        // unfreezing more than the frozen balance unfreezes all of it
+        instance._unfreeze(accounts.alice, 100);
+        assert_eq!(instance.frozen_balance(accounts.alice), 0);
+        assert!(instance.transfer(accounts.bob, 30, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.balance_of(accounts.bob), 100);
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp22", feature = "pausable"))]
+#[openbrush::contract]
+mod psp22_pausable {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::{
+            pausable::*,
+            psp22::*,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        pause: pausable::Data,
+    }
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._ensure_not_paused()
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl Pausable for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            instance
+        }
+    }
+
+    fn paused_error() -> PSP22Error {
+        PSP22Error::from(PausableError::Paused)
+    }
+
+    #[ink::test]
+    fn transfer_works_when_not_paused() {
+        let mut instance = PSP22Struct::new(100);
+
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.balance_of(accounts().bob), 10);
+    }
+
+    #[ink::test]
+    fn transfer_fails_when_paused() {
+        let mut instance = PSP22Struct::new(100);
+        assert_eq!(instance._pause::<PSP22Error>(), Ok(()));
+
+        assert_eq!(
+            instance.transfer(accounts().bob, 10, Vec::<u8>::new()),
+            Err(paused_error())
+        );
+        assert_eq!(instance.balance_of(accounts().bob), 0);
+    }
+
+    #[ink::test]
+    fn transfer_from_fails_when_paused() {
+        let mut instance = PSP22Struct::new(100);
+        assert_eq!(instance.approve(accounts().bob, 10), Ok(()));
+        assert_eq!(instance._pause::<PSP22Error>(), Ok(()));
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.transfer_from(accounts().alice, accounts().bob, 10, Vec::<u8>::new()),
+            Err(paused_error())
+        );
+    }
+
+    #[ink::test]
+    fn mint_fails_when_paused() {
+        let mut instance = PSP22Struct::new(100);
+        assert_eq!(instance._pause::<PSP22Error>(), Ok(()));
+
+        assert_eq!(instance._mint_to(accounts().bob, 10), Err(paused_error()));
+        assert_eq!(instance.total_supply(), 100);
+    }
+
+    #[ink::test]
+    fn burn_fails_when_paused() {
+        let mut instance = PSP22Struct::new(100);
+        assert_eq!(instance._pause::<PSP22Error>(), Ok(()));
+
+        assert_eq!(instance._burn_from(accounts().alice, 10), Err(paused_error()));
+        assert_eq!(instance.total_supply(), 100);
+    }
+
+    #[ink::test]
+    fn transfer_works_after_unpause() {
+        let mut instance = PSP22Struct::new(100);
+        assert_eq!(instance._pause::<PSP22Error>(), Ok(()));
+        assert_eq!(instance._unpause::<PSP22Error>(), Ok(()));
+
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_permit {
+    use ink_env::hash::{
+        Blake2x256,
+        HashOutput,
+    };
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::extensions::permit::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+    use secp256k1::{
+        Message,
+        PublicKey,
+        Secp256k1,
+        SecretKey,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        permit: permit::Data,
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22Permit for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    fn owner_key() -> (SecretKey, AccountId) {
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key).serialize();
+        let mut account = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+        (secret_key, AccountId::from(account))
+    }
+
+    fn sign(secret_key: &SecretKey, message_hash: &[u8; 32]) -> [u8; 65] {
+        let message = Message::from_slice(message_hash).unwrap();
+        let (recovery_id, signature) = Secp256k1::new()
+            .sign_ecdsa_recoverable(&message, secret_key)
+            .serialize_compact();
+        let mut output = [0u8; 65];
+        output[..64].copy_from_slice(&signature);
+        output[64] = recovery_id.to_i32() as u8;
+        output
+    }
+
+    #[ink::test]
+    fn permit_works() {
+        let mut instance = PSP22Struct::new();
+        let (secret_key, owner) = owner_key();
+        let spender = accounts().bob;
+        let deadline = 100;
+        assert_eq!(instance.nonces(owner), 0);
+
+        let message_hash = instance._permit_message_hash(&owner, &spender, &10, 0, &deadline);
+        let signature = sign(&secret_key, &message_hash);
+        assert_eq!(instance.permit(owner, spender, 10, deadline, signature), Ok(()));
+
+        assert_eq!(instance.allowance(owner, spender), 10);
+        assert_eq!(instance.nonces(owner), 1);
+    }
+
+    #[ink::test]
+    fn permit_can_not_be_replayed() {
+        let mut instance = PSP22Struct::new();
+        let (secret_key, owner) = owner_key();
+        let spender = accounts().bob;
+
+        let message_hash = instance._permit_message_hash(&owner, &spender, &10, 0, &100);
+        let signature = sign(&secret_key, &message_hash);
+        assert_eq!(instance.permit(owner, spender, 10, 100, signature), Ok(()));
+
+        assert_eq!(
+            instance.permit(owner, spender, 10, 100, signature),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+    }
+
+    #[ink::test]
+    fn permit_fails_with_wrong_signer() {
+        let mut instance = PSP22Struct::new();
+        let (secret_key, _) = owner_key();
+        let owner = accounts().alice;
+        let spender = accounts().bob;
+
+        let message_hash = instance._permit_message_hash(&owner, &spender, &10, 0, &100);
+        let signature = sign(&secret_key, &message_hash);
+
+        assert_eq!(
+            instance.permit(owner, spender, 10, 100, signature),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+        assert_eq!(instance.allowance(owner, spender), 0);
+    }
+
+    #[ink::test]
+    fn permit_fails_with_another_value() {
+        let mut instance = PSP22Struct::new();
+        let (secret_key, owner) = owner_key();
+        let spender = accounts().bob;
+
+        let message_hash = instance._permit_message_hash(&owner, &spender, &10, 0, &100);
+        let signature = sign(&secret_key, &message_hash);
+
+        assert_eq!(
+            instance.permit(owner, spender, 1000, 100, signature),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+    }
+
+    #[ink::test]
+    fn permit_fails_after_deadline() {
+        let mut instance = PSP22Struct::new();
+        let (secret_key, owner) = owner_key();
+        let spender = accounts().bob;
+
+        let message_hash = instance._permit_message_hash(&owner, &spender, &10, 0, &100);
+        let signature = sign(&secret_key, &message_hash);
+        // pass the deadline, the off-chain environment moves the time only by whole blocks
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() <= 100 {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+
+        assert_eq!(
+            instance.permit(owner, spender, 10, 100, signature),
+            Err(PSP22Error::PermitExpired)
+        );
+        assert_eq!(instance.nonces(owner), 0);
+    }
+}
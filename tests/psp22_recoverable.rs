@@ -0,0 +1,147 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::contract]
+mod psp22_recoverable {
+    use ink::codegen::Env;
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::utils::recoverable::*,
+        storage::Mapping,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22RecoverableStruct {
+        #[storage_field]
+        ownable: ownable::Data,
+        /// Mocked balances of the foreign tokens held by the contract
+        foreign_balances: Mapping<(AccountId, AccountId), Balance>,
+    }
+
+    impl Ownable for PSP22RecoverableStruct {}
+
+    impl PSP22Recoverable for PSP22RecoverableStruct {}
+
+    /// We will override the transfer of the token, so it is not using cross-contract call in tests
+    impl recoverable::Internal for PSP22RecoverableStruct {
+        fn _recover_token(&mut self, token: AccountId, amount: Balance, to: AccountId) -> Result<(), PSP22Error> {
+            let this = self.env().account_id();
+            let balance = self.foreign_balance(token, this);
+            if balance < amount {
+                return Err(PSP22Error::InsufficientBalance)
+            }
+            self.foreign_balances.insert(&(token, this), &(balance - amount));
+            let to_balance = self.foreign_balance(token, to);
+            self.foreign_balances.insert(&(token, to), &(to_balance + amount));
+            Ok(())
+        }
+    }
+
+    impl PSP22RecoverableStruct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(Self::env().caller());
+            })
+        }
+
+        /// Helper function for the mocked transfer of `token` to the contract
+        #[ink(message)]
+        pub fn receive(&mut self, token: AccountId, amount: Balance) {
+            let this = Self::env().account_id();
+            let balance = self.foreign_balance(token, this);
+            self.foreign_balances.insert(&(token, this), &(balance + amount));
+        }
+
+        #[ink(message)]
+        pub fn foreign_balance(&self, token: AccountId, account: AccountId) -> Balance {
+            self.foreign_balances.get(&(token, account)).unwrap_or(0)
+        }
+    }
+
+    #[ink::test]
+    fn recover_foreign_token_works() {
+        let accounts = accounts();
+        let mut instance = PSP22RecoverableStruct::new();
+        let token = accounts.django;
+        instance.receive(token, 100);
+
+        assert_eq!(instance.recover_token(token, 60, accounts.bob), Ok(()));
+
+        let this = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        assert_eq!(instance.foreign_balance(token, this), 40);
+        assert_eq!(instance.foreign_balance(token, accounts.bob), 60);
+    }
+
+    #[ink::test]
+    fn recover_own_token_should_fail() {
+        let accounts = accounts();
+        let mut instance = PSP22RecoverableStruct::new();
+        let this = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        instance.receive(this, 100);
+
+        assert_eq!(
+            instance.recover_token(this, 100, accounts.bob),
+            Err(PSP22Error::Custom(String::from("Token can't be recovered")))
+        );
+        assert_eq!(instance.foreign_balance(this, this), 100);
+        assert_eq!(instance.foreign_balance(this, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn recover_to_zero_address_should_fail() {
+        let mut instance = PSP22RecoverableStruct::new();
+        let token = accounts().django;
+        instance.receive(token, 100);
+
+        assert_eq!(
+            instance.recover_token(token, 100, [0; 32].into()),
+            Err(PSP22Error::ZeroRecipientAddress)
+        );
+    }
+
+    #[ink::test]
+    fn only_owner_can_recover() {
+        let accounts = accounts();
+        let mut instance = PSP22RecoverableStruct::new();
+        let token = accounts.django;
+        instance.receive(token, 100);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            instance.recover_token(token, 100, accounts.bob),
+            Err(PSP22Error::Custom(String::from("O::CallerIsNotOwner")))
+        );
+        assert_eq!(instance.foreign_balance(token, accounts.bob), 0);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_restricted {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::extensions::restricted::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct AccountBlocked {
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct AccountUnblocked {
+        account: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        restricted: restricted::Data,
+    }
+
+    type Event = <PSP22Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl psp22::Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._ensure_not_blocked(from, to)
+        }
+    }
+
+    impl restricted::Internal for PSP22Struct {
+        fn _emit_account_blocked_event(&self, account: AccountId) {
+            self.env().emit_event(AccountBlocked { account });
+        }
+
+        fn _emit_account_unblocked_event(&self, account: AccountId) {
+            self.env().emit_event(AccountUnblocked { account });
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22Restricted for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            })
+        }
+    }
+
+    #[ink::test]
+    fn block_and_unblock_works() {
+        let mut instance = PSP22Struct::new(100);
+        let bob = accounts().bob;
+        assert!(!instance.is_blocked(bob));
+
+        instance._block_account(bob);
+        assert!(instance.is_blocked(bob));
+        // blocking twice emits only one event
+        instance._block_account(bob);
+
+        instance._unblock_account(bob);
+        assert!(!instance.is_blocked(bob));
+        instance._unblock_account(bob);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+        match <Event as scale::Decode>::decode(&mut &emitted_events[0].data[..]) {
+            Ok(Event::AccountBlocked(AccountBlocked { account })) => assert_eq!(account, bob),
+            _ => panic!("encountered unexpected event kind: expected an AccountBlocked event"),
+        }
+        match <Event as scale::Decode>::decode(&mut &emitted_events[1].data[..]) {
+            Ok(Event::AccountUnblocked(AccountUnblocked { account })) => assert_eq!(account, bob),
+            _ => panic!("encountered unexpected event kind: expected an AccountUnblocked event"),
+        }
+    }
+
+    #[ink::test]
+    fn transfer_fails_from_blocked_sender() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        instance._block_account(accounts.alice);
+
+        assert_eq!(
+            instance.transfer(accounts.bob, 10, Vec::<u8>::new()),
+            Err(PSP22Error::AccountBlocked(accounts.alice))
+        );
+        assert_eq!(instance.balance_of(accounts.alice), 100);
+        assert_eq!(instance.balance_of(accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn transfer_from_fails_from_blocked_owner() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(instance.approve(accounts.bob, 10).is_ok());
+        instance._block_account(accounts.alice);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            instance.transfer_from(accounts.alice, accounts.charlie, 10, Vec::<u8>::new()),
+            Err(PSP22Error::AccountBlocked(accounts.alice))
+        );
+        assert_eq!(instance.balance_of(accounts.charlie), 0);
+    }
+
+    #[ink::test]
+    fn transfer_fails_to_blocked_recipient() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        instance._block_account(accounts.bob);
+
+        assert_eq!(
+            instance.transfer(accounts.bob, 10, Vec::<u8>::new()),
+            Err(PSP22Error::AccountBlocked(accounts.bob))
+        );
+        assert_eq!(instance.balance_of(accounts.bob), 0);
+        // transfers between other accounts are not affected
+        assert!(instance.transfer(accounts.charlie, 10, Vec::<u8>::new()).is_ok());
+    }
+
+    #[ink::test]
+    fn mint_and_burn_fail_for_blocked_account() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        instance._block_account(accounts.alice);
+        instance._block_account(accounts.bob);
+
+        assert_eq!(
+            instance._mint_to(accounts.bob, 10),
+            Err(PSP22Error::AccountBlocked(accounts.bob))
+        );
+        assert_eq!(
+            instance._burn_from(accounts.alice, 10),
+            Err(PSP22Error::AccountBlocked(accounts.alice))
+        );
+        assert_eq!(instance.total_supply(), 100);
+    }
+
+    #[ink::test]
+    fn unblock_restores_transfers() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+        instance._block_account(accounts.bob);
+        assert!(instance.transfer(accounts.bob, 10, Vec::<u8>::new()).is_err());
+
+        instance._unblock_account(accounts.bob);
+
+        assert!(instance.transfer(accounts.bob, 10, Vec::<u8>::new()).is_ok());
+        change_caller(accounts.bob);
+        assert!(instance.transfer(accounts.alice, 5, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.balance_of(accounts.bob), 5);
+    }
+}
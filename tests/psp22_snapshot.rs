@@ -0,0 +1,199 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::contract]
+mod psp22_snapshot {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::extensions::snapshot::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        snapshot: snapshot::Data,
+    }
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl psp22::Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._update_snapshots(from, to);
+            Ok(())
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl Ownable for PSP22Struct {}
+
+    impl PSP22Snapshot for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(accounts().alice);
+                assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            })
+        }
+    }
+
+    #[ink::test]
+    fn snapshot_works() {
+        let mut instance = PSP22Struct::new(100);
+        assert_eq!(instance.current_snapshot_id(), 0);
+
+        assert_eq!(instance._snapshot(), 1);
+        assert_eq!(instance._snapshot(), 2);
+        assert_eq!(instance.current_snapshot_id(), 2);
+
+        assert_eq!(instance.snapshot(), Ok(3));
+        assert_eq!(instance.current_snapshot_id(), 3);
+    }
+
+    #[ink::test]
+    fn only_owner_can_snapshot() {
+        let mut instance = PSP22Struct::new(100);
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.snapshot(),
+            Err(PSP22Error::from(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(instance.current_snapshot_id(), 0);
+    }
+
+    #[ink::test]
+    fn balance_of_at_works() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        let first = instance._snapshot();
+        assert!(instance.transfer(accounts.bob, 30, Vec::<u8>::new()).is_ok());
+        let second = instance._snapshot();
+        assert!(instance.transfer(accounts.bob, 20, Vec::<u8>::new()).is_ok());
+        assert!(instance.transfer(accounts.charlie, 10, Vec::<u8>::new()).is_ok());
+
+        assert_eq!(instance.balance_of_at(accounts.alice, first), 100);
+        assert_eq!(instance.balance_of_at(accounts.bob, first), 0);
+        assert_eq!(instance.balance_of_at(accounts.alice, second), 70);
+        assert_eq!(instance.balance_of_at(accounts.bob, second), 30);
+        assert_eq!(instance.balance_of_at(accounts.charlie, second), 0);
+    }
+
+    #[ink::test]
+    fn not_created_snapshot_returns_zero() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(instance.balance_of_at(accounts.alice, 0), 0);
+        assert_eq!(instance.total_supply_at(0), 0);
+
+        let first = instance._snapshot();
+        assert_eq!(instance.balance_of_at(accounts.alice, first), 100);
+        assert_eq!(instance.balance_of_at(accounts.alice, first + 1), 0);
+        assert_eq!(instance.total_supply_at(first), 100);
+        assert_eq!(instance.total_supply_at(first + 1), 0);
+    }
+
+    #[ink::test]
+    fn balance_of_at_works_with_many_checkpoints() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        let mut snapshots = Vec::new();
+        for _ in 0..10 {
+            snapshots.push(instance._snapshot());
+            assert!(instance.transfer(accounts.bob, 1, Vec::<u8>::new()).is_ok());
+            assert!(instance._mint_to(accounts.alice, 10).is_ok());
+        }
+
+        for (i, snapshot) in snapshots.into_iter().enumerate() {
+            let i = i as Balance;
+            assert_eq!(instance.balance_of_at(accounts.alice, snapshot), 100 + i * 9);
+            assert_eq!(instance.balance_of_at(accounts.bob, snapshot), i);
+            assert_eq!(instance.total_supply_at(snapshot), 100 + i * 10);
+        }
+    }
+
+    #[ink::test]
+    fn balance_of_at_is_stable_after_snapshot_without_changes() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        let first = instance._snapshot();
+        let second = instance._snapshot();
+        assert!(instance.transfer(accounts.bob, 30, Vec::<u8>::new()).is_ok());
+        let third = instance._snapshot();
+
+        assert_eq!(instance.balance_of_at(accounts.alice, first), 100);
+        assert_eq!(instance.balance_of_at(accounts.alice, second), 100);
+        assert_eq!(instance.balance_of_at(accounts.alice, third), 70);
+    }
+
+    #[ink::test]
+    fn total_supply_at_works() {
+        let mut instance = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        let first = instance._snapshot();
+        assert!(instance._mint_to(accounts.bob, 50).is_ok());
+        let second = instance._snapshot();
+        assert!(instance._burn_from(accounts.alice, 30).is_ok());
+        // transfers don't change the total supply
+        assert!(instance.transfer(accounts.bob, 10, Vec::<u8>::new()).is_ok());
+        let third = instance._snapshot();
+
+        assert_eq!(instance.total_supply_at(first), 100);
+        assert_eq!(instance.total_supply_at(second), 150);
+        assert_eq!(instance.total_supply_at(third), 120);
+    }
+}
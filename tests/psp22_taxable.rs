@@ -0,0 +1,228 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_taxable {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::extensions::taxable::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        taxable: taxable::Data,
+    }
+
+    type Event = <PSP22Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl psp22::Internal for PSP22Struct {
+        fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, value: Balance) {
+            self.env().emit_event(Transfer { from, to, value });
+        }
+
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+
+        fn _transfer_from_to(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+            data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            self._transfer_with_fee(from, to, amount, data)
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl PSP22Taxable for PSP22Struct {}
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance, fee_bps: u16, fee_recipient: AccountId) -> Self {
+            let mut instance = Self::default();
+            assert!(instance._mint_to(accounts().alice, total_supply).is_ok());
+            assert!(instance._set_fee_bps(fee_bps).is_ok());
+            assert!(instance._set_fee_recipient(fee_recipient).is_ok());
+            instance
+        }
+    }
+
+    fn decode_transfer(event: &ink_env::test::EmittedEvent) -> (Option<AccountId>, Option<AccountId>, Balance) {
+        let Event::Transfer(Transfer { from, to, value }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer");
+        (from, to, value)
+    }
+
+    #[ink::test]
+    fn transfer_charges_fee() {
+        let accounts = accounts();
+        // 2.5% fee
+        let mut instance = PSP22Struct::new(10_000, 250, accounts.charlie);
+
+        assert!(instance.transfer(accounts.bob, 1_000, Vec::<u8>::new()).is_ok());
+
+        assert_eq!(instance.balance_of(accounts.alice), 9_000);
+        assert_eq!(instance.balance_of(accounts.bob), 975);
+        assert_eq!(instance.balance_of(accounts.charlie), 25);
+        assert_eq!(instance.total_supply(), 10_000);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        assert_eq!(
+            decode_transfer(&emitted_events[1]),
+            (Some(accounts.alice), Some(accounts.charlie), 25)
+        );
+        assert_eq!(
+            decode_transfer(&emitted_events[2]),
+            (Some(accounts.alice), Some(accounts.bob), 975)
+        );
+    }
+
+    #[ink::test]
+    fn transfer_from_charges_fee() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(10_000, 1_000, accounts.charlie);
+        assert!(instance.approve(accounts.bob, 500).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(instance
+            .transfer_from(accounts.alice, accounts.eve, 500, Vec::<u8>::new())
+            .is_ok());
+
+        assert_eq!(instance.balance_of(accounts.eve), 450);
+        assert_eq!(instance.balance_of(accounts.charlie), 50);
+        assert_eq!(instance.allowance(accounts.alice, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn fee_recipient_accrues_fees() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(10_000, 100, accounts.charlie);
+
+        assert!(instance.transfer(accounts.bob, 1_000, Vec::<u8>::new()).is_ok());
+        change_caller(accounts.bob);
+        assert!(instance.transfer(accounts.eve, 500, Vec::<u8>::new()).is_ok());
+
+        assert_eq!(instance.balance_of(accounts.charlie), 15);
+        assert_eq!(instance.balance_of(accounts.eve), 495);
+    }
+
+    #[ink::test]
+    fn mint_and_burn_are_not_charged() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(10_000, 1_000, accounts.charlie);
+
+        assert!(instance._mint_to(accounts.bob, 1_000).is_ok());
+        assert!(instance._burn_from(accounts.bob, 500).is_ok());
+
+        assert_eq!(instance.balance_of(accounts.bob), 500);
+        assert_eq!(instance.balance_of(accounts.charlie), 0);
+    }
+
+    #[ink::test]
+    fn transfers_of_fee_recipient_are_not_charged() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(10_000, 1_000, accounts.charlie);
+
+        assert!(instance.transfer(accounts.charlie, 1_000, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.balance_of(accounts.charlie), 1_000);
+
+        change_caller(accounts.charlie);
+        assert!(instance.transfer(accounts.bob, 1_000, Vec::<u8>::new()).is_ok());
+        assert_eq!(instance.balance_of(accounts.bob), 1_000);
+    }
+
+    #[ink::test]
+    fn small_transfer_without_fee_emits_one_event() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(10_000, 100, accounts.charlie);
+
+        // 1% of 99 is rounded down to zero
+        assert!(instance.transfer(accounts.bob, 99, Vec::<u8>::new()).is_ok());
+
+        assert_eq!(instance.balance_of(accounts.bob), 99);
+        assert_eq!(ink_env::test::recorded_events().count(), 2);
+    }
+
+    #[ink::test]
+    fn transfer_above_balance_fails() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100, 1_000, accounts.charlie);
+
+        assert_eq!(
+            instance.transfer(accounts.bob, 101, Vec::<u8>::new()),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(instance.balance_of(accounts.alice), 100);
+        assert_eq!(instance.balance_of(accounts.charlie), 0);
+    }
+
+    #[ink::test]
+    fn set_fee_above_maximum_fails() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100, 0, accounts.charlie);
+
+        assert_eq!(
+            instance._set_fee_bps(MAX_FEE_BPS + 1),
+            Err(PSP22Error::Custom(String::from("Fee is above the maximum")))
+        );
+        assert_eq!(instance._set_fee_bps(MAX_FEE_BPS), Ok(()));
+        assert_eq!(instance.fee_bps(), MAX_FEE_BPS);
+        assert_eq!(instance.fee_recipient(), Some(accounts.charlie));
+    }
+}
@@ -135,6 +135,34 @@ mod psp22_timelock {
         assert_eq!(timelock.release(), Err(PSP22TokenTimelockError::NoTokensToRelease));
     }
 
+    #[ink::test]
+    fn release_before_and_after_release_time() {
+        let deposited_tokens = 1000;
+        let accounts = accounts();
+        let mut timelock = PSP22TokenTimelockStruct::new(AccountId::from([0x1; 32]), accounts.alice, day());
+        timelock.deposit(deposited_tokens);
+
+        // release before the deadline fails and keeps the tokens locked
+        assert_eq!(
+            timelock.release(),
+            Err(PSP22TokenTimelockError::CurrentTimeIsBeforeReleaseTime)
+        );
+        assert_eq!(timelock.balance(), deposited_tokens);
+
+        // pass one day
+        for _ in 0..day_blocks() {
+            advance_block();
+        }
+        assert!(get_time() >= timelock.release_time());
+
+        // release after the deadline transfers the whole locked balance
+        assert!(timelock.release().is_ok());
+        assert_eq!(timelock.balance(), 0);
+
+        // nothing is left to release
+        assert_eq!(timelock.release(), Err(PSP22TokenTimelockError::NoTokensToRelease));
+    }
+
     type DefEnv = ink_env::DefaultEnvironment;
 
     fn day_blocks() -> u32 {
@@ -0,0 +1,194 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::contract]
+mod psp22_transfer_cooldown {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp22::extensions::transfer_cooldown::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const COOLDOWN: Timestamp = 1200;
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        cooldown: transfer_cooldown::Data,
+    }
+
+    impl psp22::Internal for PSP22Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _from: &AccountId,
+            _to: &AccountId,
+            _value: &Balance,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Ok(())
+        }
+    }
+
+    impl psp22::Transfer for PSP22Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._consume_transfer_cooldown(from)
+        }
+    }
+
+    impl PSP22 for PSP22Struct {}
+
+    impl Ownable for PSP22Struct {}
+
+    impl PSP22TransferCooldown for PSP22Struct {}
+
+    impl PSP22Struct {
+        /// Charlie is the owner, so only transfers of Alice and Bob are limited by the cooldown
+        #[ink(constructor)]
+        pub fn new(cooldown: Timestamp) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut PSP22Struct| {
+                instance._init_with_owner(accounts().charlie);
+                instance._set_transfer_cooldown(cooldown);
+                assert!(instance._mint_to(accounts().alice, 1000).is_ok());
+                assert!(instance._mint_to(accounts().charlie, 1000).is_ok());
+            })
+        }
+    }
+
+    /// Moves the block timestamp to `timestamp`. The off-chain environment moves the time
+    /// only by whole blocks, so `timestamp` must be a multiple of the block time.
+    fn set_time(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+        assert_eq!(ink_env::block_timestamp::<ink_env::DefaultEnvironment>(), timestamp);
+    }
+
+    #[ink::test]
+    fn minting_is_exempt() {
+        let mut instance = PSP22Struct::new(COOLDOWN);
+
+        assert_eq!(instance._mint_to(accounts().alice, 10), Ok(()));
+        assert_eq!(instance._mint_to(accounts().alice, 10), Ok(()));
+        assert_eq!(instance.balance_of(accounts().alice), 1020);
+        assert_eq!(instance.last_transfer(accounts().alice), None);
+    }
+
+    #[ink::test]
+    fn rapid_second_transfer_is_blocked() {
+        let mut instance = PSP22Struct::new(COOLDOWN);
+        set_time(120);
+
+        change_caller(accounts().alice);
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.last_transfer(accounts().alice), Some(120));
+
+        set_time(120 + COOLDOWN - 30);
+        assert_eq!(
+            instance.transfer(accounts().bob, 10, Vec::<u8>::new()),
+            Err(PSP22Error::CooldownNotElapsed)
+        );
+        assert_eq!(instance.balance_of(accounts().bob), 10);
+        // the cooldown is tracked per sender
+        change_caller(accounts().bob);
+        assert_eq!(instance.transfer(accounts().alice, 5, Vec::<u8>::new()), Ok(()));
+    }
+
+    #[ink::test]
+    fn transfer_works_after_cooldown() {
+        let mut instance = PSP22Struct::new(COOLDOWN);
+        set_time(120);
+
+        change_caller(accounts().alice);
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+        set_time(120 + COOLDOWN);
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+
+        assert_eq!(instance.balance_of(accounts().bob), 20);
+        assert_eq!(instance.last_transfer(accounts().alice), Some(120 + COOLDOWN));
+    }
+
+    #[ink::test]
+    fn transfer_from_is_limited_by_cooldown_of_owner() {
+        let mut instance = PSP22Struct::new(COOLDOWN);
+        change_caller(accounts().alice);
+        assert_eq!(instance.approve(accounts().bob, 100), Ok(()));
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.transfer_from(accounts().alice, accounts().bob, 10, Vec::<u8>::new()),
+            Err(PSP22Error::CooldownNotElapsed)
+        );
+    }
+
+    #[ink::test]
+    fn owner_is_exempt() {
+        let mut instance = PSP22Struct::new(COOLDOWN);
+
+        change_caller(accounts().charlie);
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.last_transfer(accounts().charlie), None);
+    }
+
+    #[ink::test]
+    fn zero_cooldown_disables_limit() {
+        let mut instance = PSP22Struct::new(COOLDOWN);
+
+        change_caller(accounts().charlie);
+        assert_eq!(instance.set_transfer_cooldown(0), Ok(()));
+        assert_eq!(instance.transfer_cooldown(), 0);
+
+        change_caller(accounts().alice);
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.transfer(accounts().bob, 10, Vec::<u8>::new()), Ok(()));
+    }
+
+    #[ink::test]
+    fn only_owner_can_set_cooldown() {
+        let mut instance = PSP22Struct::new(COOLDOWN);
+
+        change_caller(accounts().alice);
+        assert_eq!(
+            instance.set_transfer_cooldown(0),
+            Err(PSP22Error::from(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(instance.transfer_cooldown(), COOLDOWN);
+    }
+}
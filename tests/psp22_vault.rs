@@ -0,0 +1,269 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod psp22_vault {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp22::extensions::vault::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22VaultStruct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        vault: vault::Data,
+        assets_balance: Balance,
+        decimals_offset: u8,
+    }
+
+    impl PSP22 for PSP22VaultStruct {}
+
+    /// We will override cross-contract vault calls in tests
+    /// The cross-contract interaction will be tested in integration tests
+    impl vault::Internal for PSP22VaultStruct {
+        fn _decimals_offset(&self) -> u8 {
+            self.decimals_offset
+        }
+
+        fn _total_assets(&self) -> Balance {
+            self.assets_balance
+        }
+
+        fn _transfer_assets_in(&mut self, _from: AccountId, assets: Balance) -> Result<(), PSP22Error> {
+            self.assets_balance += assets;
+            Ok(())
+        }
+
+        fn _transfer_assets_out(&mut self, _to: AccountId, assets: Balance) -> Result<(), PSP22Error> {
+            self.assets_balance -= assets;
+            Ok(())
+        }
+    }
+
+    impl PSP22Vault for PSP22VaultStruct {}
+
+    impl PSP22VaultStruct {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId, decimals_offset: u8) -> Self {
+            let mut instance = Self::default();
+            instance._init_vault(asset);
+            instance.decimals_offset = decimals_offset;
+            instance
+        }
+
+        /// Transfers assets to the vault without minting shares
+        #[ink(message)]
+        pub fn donate(&mut self, assets: Balance) {
+            self.assets_balance += assets;
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let asset = AccountId::from([0x1; 32]);
+        let vault = PSP22VaultStruct::new(asset, 0);
+
+        assert_eq!(vault.asset(), asset);
+        assert_eq!(vault.total_assets(), 0);
+        assert_eq!(vault.total_supply(), 0);
+    }
+
+    #[ink::test]
+    fn deposit_and_redeem_round_trip() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert_eq!(vault.deposit(1000, accounts.alice), Ok(1000));
+        assert_eq!(vault.balance_of(accounts.alice), 1000);
+        assert_eq!(vault.total_assets(), 1000);
+
+        assert_eq!(vault.redeem(1000, accounts.alice, accounts.alice), Ok(1000));
+        assert_eq!(vault.balance_of(accounts.alice), 0);
+        assert_eq!(vault.total_supply(), 0);
+        assert_eq!(vault.total_assets(), 0);
+    }
+
+    #[ink::test]
+    fn mint_and_withdraw_round_trip() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert_eq!(vault.mint(100, accounts.alice), Ok(100));
+        assert_eq!(vault.balance_of(accounts.alice), 100);
+        assert_eq!(vault.total_assets(), 100);
+
+        assert_eq!(vault.withdraw(100, accounts.alice, accounts.alice), Ok(100));
+        assert_eq!(vault.balance_of(accounts.alice), 0);
+        assert_eq!(vault.total_supply(), 0);
+        assert_eq!(vault.total_assets(), 0);
+    }
+
+    #[ink::test]
+    fn round_trip_never_returns_more_than_deposited() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert!(vault.deposit(1000, accounts.bob).is_ok());
+        vault.donate(500);
+
+        let shares = vault.deposit(100, accounts.alice).unwrap();
+        assert_eq!(shares, 66);
+        assert_eq!(vault.redeem(shares, accounts.alice, accounts.alice), Ok(99));
+
+        let assets = vault.mint(100, accounts.alice).unwrap();
+        assert_eq!(assets, 151);
+        assert_eq!(vault.redeem(100, accounts.alice, accounts.alice), Ok(150));
+    }
+
+    #[ink::test]
+    fn conversions_round_in_favor_of_vault() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert!(vault.deposit(1000, accounts.alice).is_ok());
+        vault.donate(500);
+
+        // 100 * 1001 / 1501 = 66.68
+        assert_eq!(vault.convert_to_shares(100), Ok(66));
+        assert_eq!(vault.preview_deposit(100), Ok(66));
+        assert_eq!(vault.preview_withdraw(100), Ok(67));
+
+        // 100 * 1501 / 1001 = 149.95
+        assert_eq!(vault.convert_to_assets(100), Ok(149));
+        assert_eq!(vault.preview_redeem(100), Ok(149));
+        assert_eq!(vault.preview_mint(100), Ok(150));
+    }
+
+    #[ink::test]
+    fn exact_conversions_are_not_rounded() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert!(vault.deposit(999, accounts.alice).is_ok());
+
+        assert_eq!(vault.preview_deposit(100), Ok(100));
+        assert_eq!(vault.preview_withdraw(100), Ok(100));
+        assert_eq!(vault.preview_redeem(100), Ok(100));
+        assert_eq!(vault.preview_mint(100), Ok(100));
+    }
+
+    #[ink::test]
+    fn inflation_attack_is_unprofitable() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 3);
+
+        // the attacker deposits the minimal amount and inflates the share price
+        change_caller(accounts.bob);
+        let attacker_shares = vault.deposit(1, accounts.bob).unwrap();
+        vault.donate(10_000);
+
+        change_caller(accounts.alice);
+        let victim_shares = vault.deposit(10_000, accounts.alice).unwrap();
+        assert!(victim_shares > 0);
+
+        change_caller(accounts.bob);
+        let attacker_assets = vault.redeem(attacker_shares, accounts.bob, accounts.bob).unwrap();
+        assert!(attacker_assets < 10_001);
+
+        change_caller(accounts.alice);
+        let victim_assets = vault.redeem(victim_shares, accounts.alice, accounts.alice).unwrap();
+        assert!(victim_assets > 9_990);
+    }
+
+    #[ink::test]
+    fn redeem_by_spender_spends_allowance() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert!(vault.deposit(1000, accounts.alice).is_ok());
+        assert!(vault.approve(accounts.bob, 300).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(vault.redeem(200, accounts.bob, accounts.alice), Ok(200));
+        assert_eq!(vault.allowance(accounts.alice, accounts.bob), 100);
+        assert_eq!(vault.balance_of(accounts.alice), 800);
+        assert_eq!(vault.total_assets(), 800);
+    }
+
+    #[ink::test]
+    fn withdraw_fails_without_allowance() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert!(vault.deposit(1000, accounts.alice).is_ok());
+        assert!(vault.approve(accounts.bob, 100).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            vault.withdraw(200, accounts.bob, accounts.alice),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+        assert_eq!(vault.allowance(accounts.alice, accounts.bob), 100);
+        assert_eq!(vault.balance_of(accounts.alice), 1000);
+        assert_eq!(vault.total_assets(), 1000);
+    }
+
+    #[ink::test]
+    fn redeem_fails_with_insufficient_balance() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+
+        assert!(vault.deposit(100, accounts.alice).is_ok());
+
+        assert_eq!(
+            vault.redeem(101, accounts.alice, accounts.alice),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(vault.balance_of(accounts.alice), 100);
+        assert_eq!(vault.total_assets(), 100);
+    }
+
+    #[ink::test]
+    fn works_with_large_balances() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]), 0);
+        // the product of the amount and the supply exceeds `Balance::MAX`
+        let large: Balance = 1_000_000 * 10u128.pow(18);
+        let small: Balance = 1000 * 10u128.pow(12);
+
+        assert_eq!(vault.deposit(large, accounts.bob), Ok(large));
+        assert_eq!(vault.deposit(small, accounts.alice), Ok(small));
+        vault.donate(large);
+
+        assert_eq!(vault.preview_redeem(small), Ok(1_999_999_999_000_000));
+        assert_eq!(vault.preview_mint(small), Ok(1_999_999_999_000_001));
+        assert_eq!(
+            vault.redeem(small, accounts.alice, accounts.alice),
+            Ok(1_999_999_999_000_000)
+        );
+        assert_eq!(vault.balance_of(accounts.alice), 0);
+    }
+}
@@ -124,4 +124,47 @@ mod psp22_wrapper {
         assert_eq!(wrapper.balance_of(accounts.alice), 100);
         assert_eq!(wrapper.total_supply(), 100);
     }
+
+    #[ink::test]
+    fn deposit_and_withdraw_keep_one_to_one_invariant() {
+        let accounts = accounts();
+        let mut wrapper = PSP22WrapperStruct::new(AccountId::from([0x1; 32]));
+
+        assert!(wrapper.deposit_for(accounts.alice, 100).is_ok());
+        assert!(wrapper.deposit_for(accounts.bob, 50).is_ok());
+        assert_eq!(wrapper.total_supply(), wrapper._underlying_balance());
+
+        assert!(wrapper.withdraw_to(accounts.bob, 30).is_ok());
+        assert_eq!(wrapper.balance_of(accounts.alice), 70);
+        assert_eq!(wrapper.balance_of(accounts.bob), 50);
+        assert_eq!(wrapper.total_supply(), 120);
+        assert_eq!(wrapper.total_supply(), wrapper._underlying_balance());
+    }
+
+    #[ink::test]
+    fn withdraw_to_fails_with_insufficient_balance() {
+        let accounts = accounts();
+        let mut wrapper = PSP22WrapperStruct::new(AccountId::from([0x1; 32]));
+
+        assert!(wrapper.deposit_for(accounts.alice, 100).is_ok());
+
+        assert_eq!(
+            wrapper.withdraw_to(accounts.alice, 101),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(wrapper.balance_of(accounts.alice), 100);
+        assert_eq!(wrapper._underlying_balance(), 100);
+    }
+
+    #[ink::test]
+    fn recover_without_surplus_mints_nothing() {
+        let accounts = accounts();
+        let mut wrapper = PSP22WrapperStruct::new(AccountId::from([0x1; 32]));
+
+        assert!(wrapper.deposit_for(accounts.bob, 100).is_ok());
+
+        assert_eq!(wrapper.recover(), Ok(0));
+        assert_eq!(wrapper.balance_of(accounts.alice), 0);
+        assert_eq!(wrapper.total_supply(), 100);
+    }
 }
@@ -65,6 +65,16 @@ mod psp34 {
         approved: bool,
     }
 
+    /// Event emitted when an operator is approved for all tokens of the owner.
+    #[ink(event)]
+    pub struct ApprovalForAll {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        approved: bool,
+    }
+
     #[derive(Default, SpreadAllocate, Storage)]
     #[ink(storage)]
     pub struct PSP34Struct {
@@ -85,6 +95,14 @@ mod psp34 {
             self.env().emit_event(Approval { from, to, id, approved });
         }
 
+        fn _emit_approval_for_all_event(&self, owner: AccountId, operator: AccountId, approved: bool) {
+            self.env().emit_event(ApprovalForAll {
+                owner,
+                operator,
+                approved,
+            });
+        }
+
         fn _do_safe_transfer_check(
             &mut self,
             _operator: &AccountId,
@@ -140,6 +158,12 @@ mod psp34 {
         }
     }
 
+    type Event = <PSP34Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    fn decode_event(event: &ink_env::test::EmittedEvent) -> Event {
+        <Event as scale::Decode>::decode(&mut &event.data[..]).expect("encountered invalid contract event data buffer")
+    }
+
     #[ink::test]
     fn collection_id_works() {
         assert_eq!(
@@ -409,4 +433,66 @@ mod psp34 {
             Err(PSP34Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    #[ink::test]
+    fn approve_for_all_emits_approval_for_all_event() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert!(nft.approve(accounts.bob, None, true).is_ok());
+        assert!(nft.approve(accounts.eve, Some(Id::U8(1u8)), true).is_ok());
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        match decode_event(&emitted_events[1]) {
+            Event::ApprovalForAll(ApprovalForAll {
+                owner,
+                operator,
+                approved,
+            }) => {
+                assert_eq!(owner, accounts.alice);
+                assert_eq!(operator, accounts.bob);
+                assert!(approved);
+            }
+            _ => panic!("expected ApprovalForAll event"),
+        }
+        match decode_event(&emitted_events[2]) {
+            Event::Approval(Approval { from, to, id, approved }) => {
+                assert_eq!(from, accounts.alice);
+                assert_eq!(to, accounts.eve);
+                assert_eq!(id, Some(Id::U8(1u8)));
+                assert!(approved);
+            }
+            _ => panic!("expected Approval event"),
+        }
+    }
+
+    #[ink::test]
+    fn allowance_for_id_is_true_with_approval_for_all() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert!(nft.approve(accounts.bob, None, true).is_ok());
+
+        assert!(nft.allowance(accounts.alice, accounts.bob, None));
+        assert!(nft.allowance(accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+        // the blanket approval also covers the tokens which don't exist yet
+        assert!(nft.allowance(accounts.alice, accounts.bob, Some(Id::U8(2u8))));
+    }
+
+    #[ink::test]
+    fn allowance_for_id_does_not_grant_approval_for_all() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2u8)).is_ok());
+
+        assert!(nft.approve(accounts.bob, Some(Id::U8(1u8)), true).is_ok());
+
+        assert!(nft.allowance(accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+        assert!(!nft.allowance(accounts.alice, accounts.bob, Some(Id::U8(2u8))));
+        assert!(!nft.allowance(accounts.alice, accounts.bob, None));
+    }
 }
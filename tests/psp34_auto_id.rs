@@ -0,0 +1,98 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp34")]
+#[openbrush::contract]
+mod psp34_auto_id {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp34::extensions::{
+            auto_id::*,
+            burnable::*,
+        },
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        auto_id: auto_id::Data,
+    }
+
+    impl PSP34 for PSP34Struct {}
+
+    impl PSP34AutoId for PSP34Struct {}
+
+    impl PSP34Burnable for PSP34Struct {}
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn mint_next_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(0)));
+        assert_eq!(nft.mint_next(accounts.bob), Ok(Id::U64(1)));
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(2)));
+
+        assert_eq!(nft.owner_of(Id::U64(1)), Some(accounts.bob));
+        assert_eq!(nft.balance_of(accounts.alice), 2);
+        assert_eq!(nft.total_supply(), 3);
+    }
+
+    #[ink::test]
+    fn mint_next_does_not_reuse_burned_id() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(0)));
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(1)));
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(2)));
+
+        assert!(nft.burn(accounts.alice, Id::U64(1)).is_ok());
+
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(3)));
+        assert_eq!(nft.owner_of(Id::U64(1)), None);
+    }
+
+    #[ink::test]
+    fn mint_next_skips_explicitly_minted_ids() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.bob, Id::U64(0)).is_ok());
+        assert!(nft._mint_to(accounts.bob, Id::U64(1)).is_ok());
+
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(2)));
+        // other kinds of ids don't affect the counter
+        assert!(nft._mint_to(accounts.bob, Id::U8(3)).is_ok());
+        assert_eq!(nft.mint_next(accounts.alice), Ok(Id::U64(3)));
+    }
+}
@@ -26,7 +26,10 @@ mod psp34_burnable {
     use ink_lang as ink;
     use openbrush::{
         contracts::psp34::extensions::burnable::*,
-        test_utils::accounts,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
         traits::{
             Storage,
             String,
@@ -154,4 +157,153 @@ mod psp34_burnable {
             Err(PSP34Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    #[ink::test]
+    fn approved_operator_can_burn() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2u8)).is_ok());
+        // Alice approves Bob to burn token Id 1 and Charlie to burn all tokens
+        assert!(nft.approve(accounts.bob, Some(Id::U8(1u8)), true).is_ok());
+        assert!(nft.approve(accounts.charlie, None, true).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(nft.burn(accounts.alice, Id::U8(1u8)).is_ok());
+        // The approval for the burned token is cleared
+        assert!(!nft.allowance(accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+        assert_eq!(nft.burn(accounts.alice, Id::U8(2u8)), Err(PSP34Error::NotApproved));
+
+        change_caller(accounts.charlie);
+        assert!(nft.burn(accounts.alice, Id::U8(2u8)).is_ok());
+        assert_eq!(nft.balance_of(accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn burn_clears_approvals_of_all_operators() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let id = Id::U8(1u8);
+        assert!(nft._mint_to(accounts.alice, id.clone()).is_ok());
+        assert!(nft.approve(accounts.bob, Some(id.clone()), true).is_ok());
+        assert!(nft.approve(accounts.charlie, Some(id.clone()), true).is_ok());
+
+        assert!(nft.burn(accounts.alice, id.clone()).is_ok());
+        // the approvals don't come back when the token is minted again
+        assert!(nft._mint_to(accounts.alice, id.clone()).is_ok());
+        assert!(!nft.allowance(accounts.alice, accounts.bob, Some(id.clone())));
+        assert!(!nft.allowance(accounts.alice, accounts.charlie, Some(id.clone())));
+
+        change_caller(accounts.charlie);
+        assert_eq!(nft.burn(accounts.alice, id), Err(PSP34Error::NotApproved));
+    }
+
+    #[ink::test]
+    fn burn_batch_clears_approvals() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2u8)).is_ok());
+        assert!(nft.approve(accounts.bob, Some(Id::U8(1u8)), true).is_ok());
+        assert!(nft.approve(accounts.charlie, Some(Id::U8(2u8)), true).is_ok());
+
+        assert!(nft.burn_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(2u8)]).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2u8)).is_ok());
+        assert!(!nft.allowance(accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+        assert!(!nft.allowance(accounts.alice, accounts.charlie, Some(Id::U8(2u8))));
+    }
+
+    #[ink::test]
+    fn burn_by_not_approved_should_fail() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(nft.burn(accounts.alice, Id::U8(1u8)), Err(PSP34Error::NotApproved));
+        assert_eq!(nft.owner_of(Id::U8(1u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn burn_from_not_owner_should_fail() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+
+        // The token is burned from its owner only
+        assert_eq!(nft.burn(accounts.bob, Id::U8(1u8)), Err(PSP34Error::NotApproved));
+        assert_eq!(nft.balance_of(accounts.alice), 1);
+    }
+
+    #[ink::test]
+    fn burn_batch_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let ids = vec![Id::U8(1u8), Id::U8(2u8), Id::U8(3u8)];
+        for id in ids.iter() {
+            assert!(nft._mint_to(accounts.alice, id.clone()).is_ok());
+        }
+
+        assert_eq!(nft.burn_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(3u8)]), Ok(()));
+
+        assert_eq!(nft.balance_of(accounts.alice), 1);
+        assert_eq!(nft.owner_of(Id::U8(1u8)), None);
+        assert_eq!(nft.owner_of(Id::U8(2u8)), Some(accounts.alice));
+        assert_eq!(nft.owner_of(Id::U8(3u8)), None);
+    }
+
+    #[ink::test]
+    fn approved_operator_can_burn_batch() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2u8)).is_ok());
+        assert!(nft.approve(accounts.bob, Some(Id::U8(1u8)), true).is_ok());
+
+        // Bob is approved only for token Id 1, so the whole batch fails
+        change_caller(accounts.bob);
+        assert_eq!(
+            nft.burn_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(2u8)]),
+            Err(PSP34Error::NotApproved)
+        );
+        assert_eq!(nft.balance_of(accounts.alice), 2);
+
+        assert_eq!(nft.burn_batch(accounts.alice, vec![Id::U8(1u8)]), Ok(()));
+        assert!(!nft.allowance(accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+    }
+
+    #[ink::test]
+    fn burn_batch_with_duplicate_ids_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2u8)).is_ok());
+
+        assert_eq!(
+            nft.burn_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(2u8), Id::U8(1u8)]),
+            Err(PSP34Error::TokenNotExists)
+        );
+        assert_eq!(nft.balance_of(accounts.alice), 2);
+    }
+
+    #[ink::test]
+    fn burn_batch_with_not_owned_id_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.bob, Id::U8(2u8)).is_ok());
+
+        assert_eq!(
+            nft.burn_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(2u8)]),
+            Err(PSP34Error::NotApproved)
+        );
+        assert_eq!(
+            nft.burn_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(3u8)]),
+            Err(PSP34Error::TokenNotExists)
+        );
+        // Nothing is burned
+        assert_eq!(nft.owner_of(Id::U8(1u8)), Some(accounts.alice));
+        assert_eq!(nft.owner_of(Id::U8(2u8)), Some(accounts.bob));
+    }
 }
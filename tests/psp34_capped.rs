@@ -0,0 +1,159 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp34")]
+#[openbrush::contract]
+mod psp34_capped {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp34::extensions::capped::*,
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        capped: capped::Data,
+        // burned tokens don't free up room if it is true
+        strict: bool,
+    }
+
+    impl psp34::Transfer for PSP34Struct {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _id: &Id,
+        ) -> Result<(), PSP34Error> {
+            // `is_none` means that it is minting
+            if from.is_none() {
+                if self.strict {
+                    self._ensure_cap_not_exceeded_strict()?;
+                } else {
+                    self._ensure_cap_not_exceeded()?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl PSP34 for PSP34Struct {}
+
+    impl PSP34Capped for PSP34Struct {}
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(cap: Balance, strict: bool) -> Self {
+            let mut instance = Self::default();
+            assert!(instance._init_cap(cap).is_ok());
+            instance.strict = strict;
+            instance
+        }
+    }
+
+    const CAP: Balance = 3;
+
+    #[ink::test]
+    fn init_cap_works() {
+        let nft = PSP34Struct::new(CAP, false);
+
+        assert_eq!(nft.cap(), CAP);
+    }
+
+    #[ink::test]
+    fn init_cap_below_supply_fails() {
+        let mut nft = PSP34Struct::new(0, false);
+        assert!(nft._mint_to(accounts().alice, Id::U8(1)).is_ok());
+        assert!(nft._mint_to(accounts().alice, Id::U8(2)).is_ok());
+
+        assert_eq!(
+            nft._init_cap(1),
+            Err(PSP34Error::Custom(String::from("Cap is below the current supply")))
+        );
+        assert_eq!(nft._init_cap(2), Ok(()));
+    }
+
+    #[ink::test]
+    fn mint_up_to_cap_works() {
+        let mut nft = PSP34Struct::new(CAP, false);
+        let accounts = accounts();
+
+        for i in 0..CAP as u8 {
+            assert!(nft._mint_to(accounts.alice, Id::U8(i)).is_ok());
+        }
+        assert_eq!(nft.total_supply(), CAP);
+        assert_eq!(
+            nft._mint_to(accounts.alice, Id::U8(CAP as u8)),
+            Err(PSP34Error::CollectionIsFull)
+        );
+        assert_eq!(nft.total_supply(), CAP);
+    }
+
+    #[ink::test]
+    fn burn_frees_up_room() {
+        let mut nft = PSP34Struct::new(CAP, false);
+        let accounts = accounts();
+        for i in 0..CAP as u8 {
+            assert!(nft._mint_to(accounts.alice, Id::U8(i)).is_ok());
+        }
+
+        assert!(nft._burn_from(accounts.alice, Id::U8(0)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(CAP as u8)).is_ok());
+        assert_eq!(
+            nft._mint_to(accounts.alice, Id::U8(CAP as u8 + 1)),
+            Err(PSP34Error::CollectionIsFull)
+        );
+    }
+
+    #[ink::test]
+    fn strict_cap_does_not_allow_remint_after_burn() {
+        let mut nft = PSP34Struct::new(CAP, true);
+        let accounts = accounts();
+        for i in 0..CAP as u8 {
+            assert!(nft._mint_to(accounts.alice, Id::U8(i)).is_ok());
+        }
+
+        assert!(nft._burn_from(accounts.alice, Id::U8(0)).is_ok());
+        assert_eq!(
+            nft._mint_to(accounts.alice, Id::U8(0)),
+            Err(PSP34Error::CollectionIsFull)
+        );
+        assert_eq!(nft.total_supply(), CAP - 1);
+    }
+
+    #[ink::test]
+    fn zero_cap_does_not_limit_minting() {
+        let mut nft = PSP34Struct::new(0, false);
+
+        for i in 0..10 {
+            assert!(nft._mint_to(accounts().alice, Id::U8(i)).is_ok());
+        }
+        assert_eq!(nft.total_supply(), 10);
+    }
+}
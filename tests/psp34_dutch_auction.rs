@@ -0,0 +1,239 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp34")]
+#[openbrush::contract]
+mod psp34_dutch_auction {
+    use ink::codegen::Env;
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::psp34::{
+            utils::dutch_auction::*,
+            *,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34DutchAuctionStruct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        auction: dutch_auction::Data,
+    }
+
+    impl PSP34 for PSP34DutchAuctionStruct {}
+
+    impl PSP34DutchAuction for PSP34DutchAuctionStruct {}
+
+    impl psp34::Internal for PSP34DutchAuctionStruct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _operator: &AccountId,
+            _from: &AccountId,
+            _to: &AccountId,
+            _id: &Id,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP34Error> {
+            Ok(())
+        }
+    }
+
+    /// The auction is a part of the collection, so it moves the token directly
+    impl dutch_auction::Internal for PSP34DutchAuctionStruct {
+        fn _deliver_token(&mut self, buyer: AccountId, id: Id) -> Result<(), PSP34DutchAuctionError> {
+            let this = self.env().account_id();
+            if self._owner_of(&id) != Some(this) {
+                return Err(PSP34Error::TokenNotExists.into())
+            }
+            self._burn_from(this, id.clone())?;
+            self._mint_to(buyer, id)?;
+            Ok(())
+        }
+    }
+
+    const START_PRICE: Balance = 1000;
+    const END_PRICE: Balance = 200;
+    const START_TIME: Timestamp = 120;
+    const DURATION: Timestamp = 1200;
+
+    impl PSP34DutchAuctionStruct {
+        #[ink(constructor)]
+        pub fn new(seller: AccountId) -> Self {
+            let mut instance = Self::default();
+            let this = Self::env().account_id();
+            assert!(instance
+                ._init(this, seller, START_PRICE, END_PRICE, START_TIME, DURATION)
+                .is_ok());
+            assert!(instance._mint_to(this, Id::U8(1)).is_ok());
+            instance
+        }
+    }
+
+    /// Moves the block timestamp to `timestamp`. The off-chain environment moves the time
+    /// only by whole blocks, so `timestamp` must be a multiple of the block time.
+    fn set_time(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+        assert_eq!(ink_env::block_timestamp::<ink_env::DefaultEnvironment>(), timestamp);
+    }
+
+    /// Calls `buy` from `caller` with `value` of the native currency
+    fn buy(
+        auction: &mut PSP34DutchAuctionStruct,
+        caller: AccountId,
+        id: Id,
+        value: Balance,
+    ) -> Result<(), PSP34DutchAuctionError> {
+        change_caller(caller);
+        let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(
+            contract,
+            balance_of_native(contract) + value,
+        );
+        ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(value);
+        auction.buy(id)
+    }
+
+    fn balance_of_native(account: AccountId) -> Balance {
+        ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(account).unwrap_or(0)
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+
+        assert_eq!(auction.token(), ink_env::account_id::<ink_env::DefaultEnvironment>());
+        assert_eq!(auction.seller(), accounts().charlie);
+        assert_eq!(auction.start_price(), START_PRICE);
+        assert_eq!(auction.end_price(), END_PRICE);
+        assert_eq!(auction.start_time(), START_TIME);
+        assert_eq!(auction.duration(), DURATION);
+    }
+
+    #[ink::test]
+    fn init_fails_if_end_price_is_above_start_price() {
+        let mut auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+
+        assert_eq!(
+            auction._init(accounts().alice, accounts().charlie, 100, 101, START_TIME, DURATION),
+            Err(PSP34DutchAuctionError::InvalidPriceRange)
+        );
+    }
+
+    #[ink::test]
+    fn price_decays_linearly() {
+        let auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+
+        set_time(0);
+        assert_eq!(auction.current_price(), START_PRICE);
+        set_time(START_TIME);
+        assert_eq!(auction.current_price(), START_PRICE);
+        set_time(START_TIME + DURATION / 4);
+        assert_eq!(auction.current_price(), 800);
+        set_time(START_TIME + DURATION / 2);
+        assert_eq!(auction.current_price(), 600);
+        set_time(START_TIME + DURATION);
+        assert_eq!(auction.current_price(), END_PRICE);
+    }
+
+    #[ink::test]
+    fn price_stays_at_floor_after_end() {
+        let auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+
+        set_time(START_TIME + DURATION * 10);
+        assert_eq!(auction.current_price(), END_PRICE);
+    }
+
+    #[ink::test]
+    fn buy_with_refund_works() {
+        let mut auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+        let accounts = accounts();
+        let seller_balance = balance_of_native(accounts.charlie);
+        let buyer_balance = balance_of_native(accounts.bob);
+        set_time(START_TIME + DURATION / 2);
+
+        assert_eq!(buy(&mut auction, accounts.bob, Id::U8(1), 1000), Ok(()));
+
+        assert_eq!(auction.owner_of(Id::U8(1)), Some(accounts.bob));
+        assert_eq!(balance_of_native(accounts.charlie), seller_balance + 600);
+        assert_eq!(balance_of_native(accounts.bob), buyer_balance + 400);
+    }
+
+    #[ink::test]
+    fn buy_with_exact_price_works() {
+        let mut auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+        let accounts = accounts();
+        let seller_balance = balance_of_native(accounts.charlie);
+        set_time(START_TIME + DURATION);
+
+        assert_eq!(buy(&mut auction, accounts.bob, Id::U8(1), END_PRICE), Ok(()));
+
+        assert_eq!(auction.owner_of(Id::U8(1)), Some(accounts.bob));
+        assert_eq!(balance_of_native(accounts.charlie), seller_balance + END_PRICE);
+    }
+
+    #[ink::test]
+    fn buy_fails_with_insufficient_value() {
+        let mut auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+        set_time(START_TIME + DURATION / 2);
+
+        assert_eq!(
+            buy(&mut auction, accounts().bob, Id::U8(1), 599),
+            Err(PSP34DutchAuctionError::InsufficientValue)
+        );
+        assert_eq!(
+            auction.owner_of(Id::U8(1)),
+            Some(ink_env::account_id::<ink_env::DefaultEnvironment>())
+        );
+    }
+
+    #[ink::test]
+    fn buy_fails_before_start() {
+        let mut auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+        set_time(START_TIME - 30);
+
+        assert_eq!(
+            buy(&mut auction, accounts().bob, Id::U8(1), START_PRICE),
+            Err(PSP34DutchAuctionError::AuctionNotStarted)
+        );
+    }
+
+    #[ink::test]
+    fn buy_fails_for_sold_token() {
+        let mut auction = PSP34DutchAuctionStruct::new(accounts().charlie);
+        set_time(START_TIME);
+        assert_eq!(buy(&mut auction, accounts().bob, Id::U8(1), START_PRICE), Ok(()));
+
+        assert_eq!(
+            buy(&mut auction, accounts().django, Id::U8(1), START_PRICE),
+            Err(PSP34DutchAuctionError::PSP34Error(PSP34Error::TokenNotExists))
+        );
+    }
+}
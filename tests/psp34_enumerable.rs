@@ -142,6 +142,8 @@ mod psp34_enumerable {
         // act. transfer token from alice to bob
         assert!(nft.transfer(accounts.bob, Id::U8(1u8), vec![]).is_ok());
         assert!(nft.transfer(accounts.bob, Id::U8(3u8), vec![]).is_ok());
+        // bob burns the token of alice, so he must be approved for it
+        assert!(nft.approve(accounts.bob, Some(Id::U8(2u8)), true).is_ok());
         change_caller(accounts.bob);
         assert!(nft.transfer(accounts.alice, Id::U8(1u8), vec![]).is_ok());
         assert!(nft.burn(accounts.alice, Id::U8(2u8)).is_ok());
@@ -174,4 +176,27 @@ mod psp34_enumerable {
         // token by index 1 does not exists
         assert_eq!(nft.token_by_index(0u128), Err(PSP34Error::TokenNotExists));
     }
+
+    #[ink::test]
+    fn enumerable_burn_middle_token_compacts_indices() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2u8)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(3u8)).is_ok());
+        // act. burn the middle token
+        assert!(nft.burn(accounts.alice, Id::U8(2u8)).is_ok());
+        // the last token takes the place of the burned one
+        assert_eq!(nft.token_by_index(0u128), Ok(Id::U8(1u8)));
+        assert_eq!(nft.token_by_index(1u128), Ok(Id::U8(3u8)));
+        assert_eq!(nft.token_by_index(2u128), Err(PSP34Error::TokenNotExists));
+        assert_eq!(nft.owners_token_by_index(accounts.alice, 0u128), Ok(Id::U8(1u8)));
+        assert_eq!(nft.owners_token_by_index(accounts.alice, 1u128), Ok(Id::U8(3u8)));
+        assert_eq!(
+            nft.owners_token_by_index(accounts.alice, 2u128),
+            Err(PSP34Error::TokenNotExists)
+        );
+        assert_eq!(nft.total_supply(), 2);
+        assert_eq!(nft.balance_of(accounts.alice), 2);
+    }
 }
@@ -0,0 +1,265 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp34", feature = "ownable"))]
+#[openbrush::contract]
+mod psp34_lazy_mint {
+    use ink_env::hash::{
+        Blake2x256,
+        HashOutput,
+    };
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp34::extensions::{
+            burnable::*,
+            lazy_mint::*,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+    use secp256k1::{
+        Message,
+        PublicKey,
+        Secp256k1,
+        SecretKey,
+    };
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        lazy_mint: lazy_mint::Data,
+    }
+
+    impl PSP34 for PSP34Struct {}
+
+    impl Ownable for PSP34Struct {}
+
+    impl PSP34Burnable for PSP34Struct {}
+
+    impl PSP34LazyMint for PSP34Struct {}
+
+    impl psp34::Internal for PSP34Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _operator: &AccountId,
+            _from: &AccountId,
+            _to: &AccountId,
+            _id: &Id,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP34Error> {
+            Ok(())
+        }
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(owner: AccountId) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(owner);
+            })
+        }
+    }
+
+    fn key(seed: u8) -> (SecretKey, AccountId) {
+        let secret_key = SecretKey::from_slice(&[seed; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key).serialize();
+        let mut account = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+        (secret_key, AccountId::from(account))
+    }
+
+    fn sign(secret_key: &SecretKey, message_hash: &[u8; 32]) -> [u8; 65] {
+        let message = Message::from_slice(message_hash).unwrap();
+        let (recovery_id, signature) = Secp256k1::new()
+            .sign_ecdsa_recoverable(&message, secret_key)
+            .serialize_compact();
+        let mut output = [0u8; 65];
+        output[..64].copy_from_slice(&signature);
+        output[64] = recovery_id.to_i32() as u8;
+        output
+    }
+
+    fn voucher(instance: &PSP34Struct, secret_key: &SecretKey, id: Id, price: Balance) -> Voucher {
+        let message_hash = instance._voucher_message_hash(&id, &price);
+        Voucher {
+            id,
+            price,
+            signature: sign(secret_key, &message_hash),
+        }
+    }
+
+    /// Calls `redeem` from `caller` with `value` of the native currency
+    fn redeem(
+        instance: &mut PSP34Struct,
+        caller: AccountId,
+        voucher: Voucher,
+        value: Balance,
+    ) -> Result<(), PSP34Error> {
+        change_caller(caller);
+        let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(
+            contract,
+            balance_of_native(contract) + value,
+        );
+        ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(value);
+        instance.redeem(voucher)
+    }
+
+    fn balance_of_native(account: AccountId) -> Balance {
+        ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(account).unwrap_or(0)
+    }
+
+    const PRICE: Balance = 100;
+
+    #[ink::test]
+    fn redeem_works() {
+        let (secret_key, owner) = key(0x42);
+        let mut instance = PSP34Struct::new(owner);
+        let buyer = accounts().bob;
+        let voucher = voucher(&instance, &secret_key, Id::U8(1), PRICE);
+        let owner_balance = balance_of_native(owner);
+        let buyer_balance = balance_of_native(buyer);
+
+        assert_eq!(redeem(&mut instance, buyer, voucher, PRICE + 50), Ok(()));
+
+        assert_eq!(instance.owner_of(Id::U8(1)), Some(buyer));
+        assert!(instance.is_redeemed(Id::U8(1)));
+        assert_eq!(balance_of_native(owner), owner_balance + PRICE);
+        // the value above the price is refunded
+        assert_eq!(balance_of_native(buyer), buyer_balance + 50);
+    }
+
+    #[ink::test]
+    fn free_voucher_works() {
+        let (secret_key, owner) = key(0x42);
+        let mut instance = PSP34Struct::new(owner);
+        let voucher = voucher(&instance, &secret_key, Id::U8(1), 0);
+
+        assert_eq!(redeem(&mut instance, accounts().bob, voucher, 0), Ok(()));
+        assert_eq!(instance.owner_of(Id::U8(1)), Some(accounts().bob));
+    }
+
+    #[ink::test]
+    fn redeem_fails_with_forged_voucher() {
+        let (_, owner) = key(0x42);
+        let (forger_key, _) = key(0x43);
+        let mut instance = PSP34Struct::new(owner);
+        let voucher = voucher(&instance, &forger_key, Id::U8(1), PRICE);
+
+        assert_eq!(
+            redeem(&mut instance, accounts().bob, voucher, PRICE),
+            Err(PSP34Error::VoucherInvalidSignature)
+        );
+        assert_eq!(instance.owner_of(Id::U8(1)), None);
+        assert!(!instance.is_redeemed(Id::U8(1)));
+    }
+
+    #[ink::test]
+    fn redeem_fails_with_changed_price() {
+        let (secret_key, owner) = key(0x42);
+        let mut instance = PSP34Struct::new(owner);
+        let mut voucher = voucher(&instance, &secret_key, Id::U8(1), PRICE);
+        voucher.price = 1;
+
+        assert_eq!(
+            redeem(&mut instance, accounts().bob, voucher, PRICE),
+            Err(PSP34Error::VoucherInvalidSignature)
+        );
+        assert_eq!(instance.owner_of(Id::U8(1)), None);
+    }
+
+    #[ink::test]
+    fn redeem_fails_after_ownership_transfer() {
+        let (secret_key, owner) = key(0x42);
+        let mut instance = PSP34Struct::new(owner);
+        let voucher = voucher(&instance, &secret_key, Id::U8(1), PRICE);
+        change_caller(owner);
+        assert!(instance.transfer_ownership(accounts().charlie).is_ok());
+
+        assert_eq!(
+            redeem(&mut instance, accounts().bob, voucher, PRICE),
+            Err(PSP34Error::VoucherInvalidSignature)
+        );
+    }
+
+    #[ink::test]
+    fn double_redeem_fails() {
+        let (secret_key, owner) = key(0x42);
+        let mut instance = PSP34Struct::new(owner);
+        let voucher = voucher(&instance, &secret_key, Id::U8(1), PRICE);
+        assert_eq!(redeem(&mut instance, accounts().bob, voucher.clone(), PRICE), Ok(()));
+
+        assert_eq!(
+            redeem(&mut instance, accounts().charlie, voucher.clone(), PRICE),
+            Err(PSP34Error::VoucherAlreadyRedeemed)
+        );
+        assert_eq!(instance.owner_of(Id::U8(1)), Some(accounts().bob));
+
+        // the voucher stays redeemed after the token is burnt
+        change_caller(accounts().bob);
+        assert!(instance.burn(accounts().bob, Id::U8(1)).is_ok());
+        assert_eq!(
+            redeem(&mut instance, accounts().charlie, voucher, PRICE),
+            Err(PSP34Error::VoucherAlreadyRedeemed)
+        );
+    }
+
+    #[ink::test]
+    fn redeem_fails_for_minted_id() {
+        let (secret_key, owner) = key(0x42);
+        let mut instance = PSP34Struct::new(owner);
+        assert!(instance._mint_to(accounts().alice, Id::U8(1)).is_ok());
+        let voucher = voucher(&instance, &secret_key, Id::U8(1), PRICE);
+        let owner_balance = balance_of_native(owner);
+
+        assert_eq!(
+            redeem(&mut instance, accounts().bob, voucher, PRICE),
+            Err(PSP34Error::TokenExists)
+        );
+        assert_eq!(instance.owner_of(Id::U8(1)), Some(accounts().alice));
+        assert!(!instance.is_redeemed(Id::U8(1)));
+        assert_eq!(balance_of_native(owner), owner_balance);
+    }
+
+    #[ink::test]
+    fn redeem_fails_with_insufficient_value() {
+        let (secret_key, owner) = key(0x42);
+        let mut instance = PSP34Struct::new(owner);
+        let voucher = voucher(&instance, &secret_key, Id::U8(1), PRICE);
+
+        assert_eq!(
+            redeem(&mut instance, accounts().bob, voucher, PRICE - 1),
+            Err(PSP34Error::VoucherInsufficientValue)
+        );
+        assert_eq!(instance.owner_of(Id::U8(1)), None);
+    }
+}
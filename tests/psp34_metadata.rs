@@ -25,7 +25,11 @@
 mod psp34_metadata {
     use ink_lang as ink;
     use openbrush::{
-        contracts::psp34::extensions::metadata::*,
+        contracts::psp34::extensions::{
+            burnable::*,
+            metadata::*,
+        },
+        test_utils::accounts,
         traits::{
             Storage,
             String,
@@ -41,6 +45,25 @@ mod psp34_metadata {
         metadata: Data,
     }
 
+    impl psp34::Transfer for PSP34Struct {
+        fn _after_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            id: &Id,
+        ) -> Result<(), PSP34Error> {
+            // `is_none` means that it is burning
+            if to.is_none() {
+                self._remove_attributes(id);
+            }
+            Ok(())
+        }
+    }
+
+    impl PSP34 for PSP34Struct {}
+
+    impl PSP34Burnable for PSP34Struct {}
+
     impl PSP34Metadata for PSP34Struct {}
 
     impl PSP34Struct {
@@ -52,6 +75,43 @@ mod psp34_metadata {
         }
     }
 
+    #[ink::test]
+    fn token_uri_works_with_numeric_id() {
+        let mut nft = PSP34Struct::new(Id::U8(1u8), String::from("KEY"), String::from("VAL"));
+        nft._set_base_uri(String::from("ipfs://base/"));
+
+        assert_eq!(nft.token_uri(Id::U8(0u8)), String::from("ipfs://base/0"));
+        assert_eq!(nft.token_uri(Id::U32(42u32)), String::from("ipfs://base/42"));
+        assert_eq!(
+            nft.token_uri(Id::U128(u128::MAX)),
+            String::from("ipfs://base/340282366920938463463374607431768211455")
+        );
+
+        nft._set_uri_suffix(String::from(".json"));
+        assert_eq!(nft.token_uri(Id::U64(1000u64)), String::from("ipfs://base/1000.json"));
+    }
+
+    #[ink::test]
+    fn token_uri_works_with_bytes_id() {
+        let mut nft = PSP34Struct::new(Id::U8(1u8), String::from("KEY"), String::from("VAL"));
+        nft._set_base_uri(String::from("ipfs://base/"));
+        nft._set_uri_suffix(String::from(".json"));
+
+        assert_eq!(
+            nft.token_uri(Id::Bytes(vec![0x00, 0x1f, 0xab, 0xff])),
+            String::from("ipfs://base/001fabff.json")
+        );
+        assert_eq!(nft.token_uri(Id::Bytes(vec![])), String::from("ipfs://base/.json"));
+    }
+
+    #[ink::test]
+    fn token_uri_is_empty_without_base_uri() {
+        let mut nft = PSP34Struct::new(Id::U8(1u8), String::from("KEY"), String::from("VAL"));
+        nft._set_uri_suffix(String::from(".json"));
+
+        assert_eq!(nft.token_uri(Id::U8(1u8)), String::new());
+    }
+
     #[ink::test]
     fn init_with_name_and_symbol_works() {
         let id = Id::U8(1u8);
@@ -62,4 +122,103 @@ mod psp34_metadata {
             Some(String::from("VAL"))
         );
     }
+
+    #[ink::test]
+    fn attributes_enumeration_works() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new(id.clone(), String::from("name"), String::from("Token"));
+        nft._set_attribute(id.clone(), String::from("color"), String::from("red"));
+        nft._set_attribute(id.clone(), String::from("size"), String::from("big"));
+
+        assert_eq!(nft.get_attribute_count(id.clone()), 3);
+        assert_eq!(nft.get_attribute_name(id.clone(), 0), Some(String::from("name")));
+        assert_eq!(nft.get_attribute_name(id.clone(), 1), Some(String::from("color")));
+        assert_eq!(nft.get_attribute_name(id.clone(), 2), Some(String::from("size")));
+        assert_eq!(nft.get_attribute_name(id.clone(), 3), None);
+        assert_eq!(nft.get_attribute_count(Id::U8(2u8)), 0);
+    }
+
+    #[ink::test]
+    fn attribute_keys_are_deduplicated() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new(id.clone(), String::from("KEY"), String::from("VAL"));
+        nft._set_attribute(id.clone(), String::from("KEY"), String::from("NEW_VAL"));
+
+        assert_eq!(nft.get_attribute_count(id.clone()), 1);
+        assert_eq!(nft.get_attribute_name(id.clone(), 0), Some(String::from("KEY")));
+        assert_eq!(
+            nft.get_attribute(id.clone(), String::from("KEY")),
+            Some(String::from("NEW_VAL"))
+        );
+    }
+
+    #[ink::test]
+    fn remove_attribute_works() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new(id.clone(), String::from("name"), String::from("Token"));
+        nft._set_attribute(id.clone(), String::from("color"), String::from("red"));
+        nft._set_attribute(id.clone(), String::from("size"), String::from("big"));
+
+        nft._remove_attribute(&id, &String::from("name"));
+
+        assert_eq!(nft.get_attribute(id.clone(), String::from("name")), None);
+        assert_eq!(nft.get_attribute_count(id.clone()), 2);
+        // The last key takes the place of the removed one
+        assert_eq!(nft.get_attribute_name(id.clone(), 0), Some(String::from("size")));
+        assert_eq!(nft.get_attribute_name(id.clone(), 1), Some(String::from("color")));
+    }
+
+    #[ink::test]
+    fn burn_removes_attributes() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new(id.clone(), String::from("name"), String::from("Token"));
+        nft._set_attribute(id.clone(), String::from("color"), String::from("red"));
+        assert!(nft._mint_to(accounts().alice, id.clone()).is_ok());
+
+        assert!(nft.burn(accounts().alice, id.clone()).is_ok());
+
+        assert_eq!(nft.get_attribute_count(id.clone()), 0);
+        assert_eq!(nft.get_attribute_name(id.clone(), 0), None);
+        assert_eq!(nft.get_attribute(id.clone(), String::from("name")), None);
+        assert_eq!(nft.get_attribute(id.clone(), String::from("color")), None);
+    }
+
+    #[ink::test]
+    fn collection_metadata_works() {
+        let mut nft = PSP34Struct::new(Id::U8(1u8), String::from("name"), String::from("Token"));
+        assert_eq!(nft.collection_name(), String::new());
+        assert_eq!(nft.collection_symbol(), String::new());
+
+        nft._set_collection_name(String::from("Collection"));
+        nft._set_collection_symbol(String::from("COL"));
+
+        assert_eq!(nft.collection_name(), String::from("Collection"));
+        assert_eq!(nft.collection_symbol(), String::from("COL"));
+        // the collection metadata is stored as the attributes of the collection id
+        assert_eq!(
+            nft.get_attribute(nft.collection_id(), String::from("name")),
+            Some(String::from("Collection"))
+        );
+        assert_eq!(nft.get_attribute_count(nft.collection_id()), 2);
+    }
+
+    #[ink::test]
+    fn collection_metadata_is_distinct_from_token_attributes() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new(id.clone(), String::from("name"), String::from("Token"));
+        nft._set_collection_name(String::from("Collection"));
+
+        assert_eq!(
+            nft.get_attribute(id.clone(), String::from("name")),
+            Some(String::from("Token"))
+        );
+        assert_eq!(nft.get_attribute(id.clone(), String::from("symbol")), None);
+        assert_eq!(nft.get_attribute_count(id.clone()), 1);
+
+        nft._set_attribute(id.clone(), String::from("name"), String::from("Renamed"));
+        assert_eq!(nft.collection_name(), String::from("Collection"));
+        assert!(nft._mint_to(accounts().alice, id.clone()).is_ok());
+        assert!(nft.burn(accounts().alice, id.clone()).is_ok());
+        assert_eq!(nft.collection_name(), String::from("Collection"));
+    }
 }
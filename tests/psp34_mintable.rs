@@ -23,6 +23,10 @@
 #[cfg(feature = "psp34")]
 #[openbrush::contract]
 mod psp34_mintable {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
     use ink_lang as ink;
     use openbrush::{
         contracts::psp34::{
@@ -36,6 +40,13 @@ mod psp34_mintable {
         },
     };
 
+    #[ink(event)]
+    pub struct Transfer {
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        id: Id,
+    }
+
     #[derive(Default, Storage)]
     #[ink(storage)]
     pub struct PSP34Struct {
@@ -73,6 +84,12 @@ mod psp34_mintable {
         }
     }
 
+    impl psp34::Internal for PSP34Struct {
+        fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
+            self.env().emit_event(Transfer { from, to, id });
+        }
+    }
+
     impl PSP34 for PSP34Struct {}
     impl PSP34Mintable for PSP34Struct {}
 
@@ -157,4 +174,61 @@ mod psp34_mintable {
             Err(PSP34Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    type Event = <PSP34Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    #[ink::test]
+    fn mint_batch_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let ids = vec![Id::U8(1u8), Id::U8(2u8), Id::U8(3u8)];
+
+        assert_eq!(nft.mint_batch(accounts.alice, ids.clone()), Ok(()));
+
+        assert_eq!(nft.balance_of(accounts.alice), 3);
+        assert_eq!(nft.total_supply(), 3);
+        for id in ids.iter() {
+            assert_eq!(nft.owner_of(id.clone()), Some(accounts.alice));
+        }
+        // The event is emitted for each id
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        for (event, expected_id) in emitted_events.iter().zip(ids.iter()) {
+            let Event::Transfer(Transfer { from, to, id }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer");
+            assert_eq!(from, None);
+            assert_eq!(to, Some(accounts.alice));
+            assert_eq!(&id, expected_id);
+        }
+    }
+
+    #[ink::test]
+    fn mint_batch_with_duplicate_ids_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        assert_eq!(
+            nft.mint_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(2u8), Id::U8(1u8)]),
+            Err(PSP34Error::TokenExists)
+        );
+        // Nothing is minted
+        assert_eq!(nft.balance_of(accounts.alice), 0);
+        assert_eq!(nft.owner_of(Id::U8(1u8)), None);
+        assert_eq!(ink_env::test::recorded_events().count(), 0);
+    }
+
+    #[ink::test]
+    fn mint_batch_with_existing_id_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(nft.mint(accounts.bob, Id::U8(3u8)).is_ok());
+
+        assert_eq!(
+            nft.mint_batch(accounts.alice, vec![Id::U8(1u8), Id::U8(2u8), Id::U8(3u8)]),
+            Err(PSP34Error::TokenExists)
+        );
+        assert_eq!(nft.balance_of(accounts.alice), 0);
+        assert_eq!(nft.owner_of(Id::U8(1u8)), None);
+        assert_eq!(nft.owner_of(Id::U8(3u8)), Some(accounts.bob));
+    }
 }
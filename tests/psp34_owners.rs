@@ -0,0 +1,164 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp34")]
+#[openbrush::contract]
+mod psp34_owners {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp34::extensions::owners::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        owners: owners::Data,
+    }
+
+    impl psp34::Internal for PSP34Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _operator: &AccountId,
+            _from: &AccountId,
+            _to: &AccountId,
+            _id: &Id,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP34Error> {
+            Ok(())
+        }
+    }
+
+    impl psp34::Transfer for PSP34Struct {
+        fn _after_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            _id: &Id,
+        ) -> Result<(), PSP34Error> {
+            self._update_owners(from, to);
+            Ok(())
+        }
+    }
+
+    impl PSP34 for PSP34Struct {}
+
+    impl PSP34Owners for PSP34Struct {}
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|_instance: &mut Self| {})
+        }
+    }
+
+    #[ink::test]
+    fn owner_list_is_empty_by_default() {
+        let nft = PSP34Struct::new();
+
+        assert_eq!(nft.owner_count(), 0);
+        assert_eq!(nft.owner_by_index(0), None);
+    }
+
+    #[ink::test]
+    fn mint_adds_distinct_owners() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+
+        assert!(nft._mint_to(accounts.alice, Id::U8(1)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2)).is_ok());
+        assert!(nft._mint_to(accounts.bob, Id::U8(3)).is_ok());
+
+        assert_eq!(nft.owner_count(), 2);
+        assert_eq!(nft.owner_by_index(0), Some(accounts.alice));
+        assert_eq!(nft.owner_by_index(1), Some(accounts.bob));
+        assert_eq!(nft.owner_by_index(2), None);
+    }
+
+    #[ink::test]
+    fn owner_list_compacts_when_owner_transfers_all_tokens() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2)).is_ok());
+        assert!(nft._mint_to(accounts.bob, Id::U8(3)).is_ok());
+        assert!(nft._mint_to(accounts.charlie, Id::U8(4)).is_ok());
+
+        // alice keeps being an owner until the last token is transferred
+        change_caller(accounts.alice);
+        assert!(nft.transfer(accounts.bob, Id::U8(1), Vec::new()).is_ok());
+        assert_eq!(nft.owner_count(), 3);
+        assert!(nft.transfer(accounts.bob, Id::U8(2), Vec::new()).is_ok());
+
+        // the last owner takes the index of alice
+        assert_eq!(nft.owner_count(), 2);
+        assert_eq!(nft.owner_by_index(0), Some(accounts.charlie));
+        assert_eq!(nft.owner_by_index(1), Some(accounts.bob));
+        assert_eq!(nft.owner_by_index(2), None);
+    }
+
+    #[ink::test]
+    fn transfer_to_new_owner_appends_it() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1)).is_ok());
+        assert!(nft._mint_to(accounts.alice, Id::U8(2)).is_ok());
+
+        change_caller(accounts.alice);
+        assert!(nft.transfer(accounts.bob, Id::U8(1), Vec::new()).is_ok());
+
+        assert_eq!(nft.owner_count(), 2);
+        assert_eq!(nft.owner_by_index(0), Some(accounts.alice));
+        assert_eq!(nft.owner_by_index(1), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn burn_of_last_token_removes_owner() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1)).is_ok());
+        assert!(nft._mint_to(accounts.bob, Id::U8(2)).is_ok());
+
+        assert!(nft._burn_from(accounts.bob, Id::U8(2)).is_ok());
+        assert_eq!(nft.owner_count(), 1);
+        assert_eq!(nft.owner_by_index(0), Some(accounts.alice));
+        assert_eq!(nft.owner_by_index(1), None);
+
+        assert!(nft._burn_from(accounts.alice, Id::U8(1)).is_ok());
+        assert_eq!(nft.owner_count(), 0);
+        assert_eq!(nft.owner_by_index(0), None);
+
+        // the removed owner is added again when it receives a token
+        assert!(nft._mint_to(accounts.bob, Id::U8(3)).is_ok());
+        assert_eq!(nft.owner_count(), 1);
+        assert_eq!(nft.owner_by_index(0), Some(accounts.bob));
+    }
+}
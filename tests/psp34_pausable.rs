@@ -0,0 +1,150 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp34", feature = "pausable"))]
+#[openbrush::contract]
+mod psp34_pausable {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::{
+            pausable::*,
+            psp34::*,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        pause: pausable::Data,
+    }
+
+    impl psp34::Internal for PSP34Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _operator: &AccountId,
+            _from: &AccountId,
+            _to: &AccountId,
+            _id: &Id,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP34Error> {
+            Ok(())
+        }
+    }
+
+    impl psp34::Transfer for PSP34Struct {
+        fn _before_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _id: &Id,
+        ) -> Result<(), PSP34Error> {
+            self._ensure_not_paused()
+        }
+    }
+
+    impl PSP34 for PSP34Struct {}
+
+    impl Pausable for PSP34Struct {}
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            assert!(instance._mint_to(accounts().alice, Id::U8(1)).is_ok());
+            instance
+        }
+    }
+
+    fn paused_error() -> PSP34Error {
+        PSP34Error::from(PausableError::Paused)
+    }
+
+    #[ink::test]
+    fn transfer_works_when_not_paused() {
+        let mut instance = PSP34Struct::new();
+
+        assert_eq!(instance.transfer(accounts().bob, Id::U8(1), Vec::<u8>::new()), Ok(()));
+        assert_eq!(instance.owner_of(Id::U8(1)), Some(accounts().bob));
+    }
+
+    #[ink::test]
+    fn transfer_fails_when_paused() {
+        let mut instance = PSP34Struct::new();
+        assert_eq!(instance._pause::<PSP34Error>(), Ok(()));
+
+        assert_eq!(
+            instance.transfer(accounts().bob, Id::U8(1), Vec::<u8>::new()),
+            Err(paused_error())
+        );
+        assert_eq!(instance.owner_of(Id::U8(1)), Some(accounts().alice));
+    }
+
+    #[ink::test]
+    fn approved_transfer_fails_when_paused() {
+        let mut instance = PSP34Struct::new();
+        assert_eq!(instance.approve(accounts().bob, Some(Id::U8(1)), true), Ok(()));
+        assert_eq!(instance._pause::<PSP34Error>(), Ok(()));
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.transfer(accounts().bob, Id::U8(1), Vec::<u8>::new()),
+            Err(paused_error())
+        );
+        assert_eq!(instance.owner_of(Id::U8(1)), Some(accounts().alice));
+    }
+
+    #[ink::test]
+    fn mint_fails_when_paused() {
+        let mut instance = PSP34Struct::new();
+        assert_eq!(instance._pause::<PSP34Error>(), Ok(()));
+
+        assert_eq!(instance._mint_to(accounts().bob, Id::U8(2)), Err(paused_error()));
+        assert_eq!(instance.total_supply(), 1);
+    }
+
+    #[ink::test]
+    fn burn_fails_when_paused() {
+        let mut instance = PSP34Struct::new();
+        assert_eq!(instance._pause::<PSP34Error>(), Ok(()));
+
+        assert_eq!(instance._burn_from(accounts().alice, Id::U8(1)), Err(paused_error()));
+        assert_eq!(instance.total_supply(), 1);
+    }
+
+    #[ink::test]
+    fn transfer_works_after_unpause() {
+        let mut instance = PSP34Struct::new();
+        assert_eq!(instance._pause::<PSP34Error>(), Ok(()));
+        assert_eq!(instance._unpause::<PSP34Error>(), Ok(()));
+
+        assert_eq!(instance.transfer(accounts().bob, Id::U8(1), Vec::<u8>::new()), Ok(()));
+    }
+}
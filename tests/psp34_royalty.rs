@@ -0,0 +1,147 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp34")]
+#[openbrush::contract]
+mod psp34_royalty {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp34::extensions::{
+            burnable::*,
+            royalty::*,
+        },
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+            ZERO_ADDRESS,
+        },
+    };
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        royalty: royalty::Data,
+    }
+
+    impl psp34::Transfer for PSP34Struct {
+        fn _after_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            id: &Id,
+        ) -> Result<(), PSP34Error> {
+            // `is_none` means that it is burning
+            if to.is_none() {
+                self._reset_token_royalty(id);
+            }
+            Ok(())
+        }
+    }
+
+    impl PSP34 for PSP34Struct {}
+
+    impl PSP34Burnable for PSP34Struct {}
+
+    impl PSP34Royalty for PSP34Struct {}
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|_instance: &mut Self| {})
+        }
+    }
+
+    #[ink::test]
+    fn royalty_info_without_royalty_works() {
+        let nft = PSP34Struct::new();
+
+        assert_eq!(nft.royalty_info(Id::U8(1u8), 1000), (ZERO_ADDRESS.into(), 0));
+    }
+
+    #[ink::test]
+    fn default_royalty_works() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+
+        // 2.5%
+        assert_eq!(nft._set_default_royalty(accounts.bob, 250), Ok(()));
+
+        assert_eq!(nft.royalty_info(Id::U8(1u8), 1000), (accounts.bob, 25));
+        assert_eq!(nft.royalty_info(Id::U8(2u8), 1000), (accounts.bob, 25));
+        assert_eq!(
+            nft.royalty_info(Id::U8(2u8), Balance::MAX),
+            (accounts.bob, Balance::MAX / 40)
+        );
+
+        nft._delete_default_royalty();
+        assert_eq!(nft.royalty_info(Id::U8(1u8), 1000), (ZERO_ADDRESS.into(), 0));
+    }
+
+    #[ink::test]
+    fn token_royalty_overrides_default_royalty() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+        assert_eq!(nft._set_default_royalty(accounts.bob, 250), Ok(()));
+
+        assert_eq!(nft._set_token_royalty(Id::U8(1u8), accounts.charlie, 1000), Ok(()));
+
+        assert_eq!(nft.royalty_info(Id::U8(1u8), 1000), (accounts.charlie, 100));
+        assert_eq!(nft.royalty_info(Id::U8(2u8), 1000), (accounts.bob, 25));
+
+        nft._reset_token_royalty(&Id::U8(1u8));
+        assert_eq!(nft.royalty_info(Id::U8(1u8), 1000), (accounts.bob, 25));
+    }
+
+    #[ink::test]
+    fn royalty_above_sale_price_fails() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+
+        assert_eq!(nft._set_default_royalty(accounts.bob, 10000), Ok(()));
+        assert_eq!(
+            nft._set_default_royalty(accounts.bob, 10001),
+            Err(PSP34Error::Custom(String::from("Royalty fee exceeds sale price")))
+        );
+        assert_eq!(
+            nft._set_token_royalty(Id::U8(1u8), accounts.bob, 10001),
+            Err(PSP34Error::Custom(String::from("Royalty fee exceeds sale price")))
+        );
+        assert_eq!(nft.royalty_info(Id::U8(1u8), 1000), (accounts.bob, 1000));
+    }
+
+    #[ink::test]
+    fn token_royalty_is_deleted_on_burn() {
+        let mut nft = PSP34Struct::new();
+        let accounts = accounts();
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert_eq!(nft._set_token_royalty(Id::U8(1u8), accounts.charlie, 1000), Ok(()));
+
+        assert!(nft.burn(accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert_eq!(nft.royalty_info(Id::U8(1u8), 1000), (ZERO_ADDRESS.into(), 0));
+    }
+}
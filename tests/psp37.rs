@@ -202,6 +202,30 @@ mod psp37 {
         );
     }
 
+    #[ink::test]
+    fn balance_of_batch_works() {
+        let token_id1 = Id::U128(1);
+        let token_id2 = Id::U128(2);
+        let unknown_id = Id::U128(3);
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id1.clone(), 1).is_ok());
+        assert!(nft.mint(accounts.bob, token_id2.clone(), 20).is_ok());
+
+        assert_eq!(
+            nft.balance_of_batch(vec![
+                (accounts.alice, token_id1.clone()),
+                (accounts.bob, token_id1.clone()),
+                (accounts.bob, token_id2.clone()),
+                (accounts.alice, unknown_id),
+                (accounts.alice, token_id1),
+            ]),
+            vec![1, 0, 20, 0, 1]
+        );
+        assert_eq!(nft.balance_of_batch(vec![]), Vec::<Balance>::new());
+    }
+
     #[ink::test]
     fn balance_of() {
         let token_id1 = Id::U128(1);
@@ -364,7 +388,7 @@ mod psp37 {
         assert!(nft.mint(accounts.alice, token_id.clone(), mint_amount).is_ok());
         assert_eq!(
             nft.transfer_from(accounts.alice, accounts.bob, token_id.clone(), transfer_amount, vec![]),
-            Err(PSP37Error::InsufficientBalance),
+            Err(PSP37Error::InsufficientTokenBalance(token_id, transfer_amount)),
         );
     }
 
@@ -559,7 +583,7 @@ mod psp37 {
         assert!(nft.mint(accounts.bob, token_id.clone(), mint_amount).is_ok());
         assert_eq!(
             nft.transfer(accounts.alice, token_id.clone(), transfer_amount, vec![]),
-            Err(PSP37Error::InsufficientBalance),
+            Err(PSP37Error::InsufficientTokenBalance(token_id, transfer_amount)),
         );
     }
 
@@ -574,10 +598,29 @@ mod psp37 {
         assert!(nft.mint(accounts.alice, token_id.clone(), mint_amount).is_ok());
         assert_eq!(
             nft.transfer(accounts.bob, token_id.clone(), transfer_amount, vec![]),
-            Err(PSP37Error::InsufficientBalance),
+            Err(PSP37Error::InsufficientTokenBalance(token_id, transfer_amount)),
         );
     }
 
+    #[ink::test]
+    fn transfer_from_insufficient_balance_keeps_allowance() {
+        let token_id = Id::U128(1);
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.bob, token_id.clone(), 1).is_ok());
+        change_caller(accounts.bob);
+        assert!(nft.approve(accounts.alice, Some(token_id.clone()), 2).is_ok());
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            nft.transfer_from(accounts.bob, accounts.alice, token_id.clone(), 2, vec![]),
+            Err(PSP37Error::InsufficientTokenBalance(token_id.clone(), 2)),
+        );
+        assert_eq!(nft.allowance(accounts.bob, accounts.alice, Some(token_id.clone())), 2);
+        assert_eq!(nft.balance_of(accounts.bob, Some(token_id)), 1);
+    }
+
     #[ink::test]
     fn before_received_should_fail_transfer() {
         let token_id_1 = Id::U128(1);
@@ -645,4 +688,65 @@ mod psp37 {
             panic!("encountered unexpected event kind: expected a Approval event")
         }
     }
+
+    #[ink::test]
+    fn transfer_from_consumes_per_id_allowance() {
+        let token_id = Id::U128(1);
+        let other_id = Id::U128(2);
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id.clone(), 10).is_ok());
+        assert!(nft.mint(accounts.alice, other_id.clone(), 10).is_ok());
+        assert!(nft.approve(accounts.bob, Some(token_id.clone()), 5).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(nft
+            .transfer_from(accounts.alice, accounts.bob, token_id.clone(), 3, vec![])
+            .is_ok());
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(token_id.clone())), 2);
+        // the allowance is limited to the approved id
+        assert_eq!(
+            nft.transfer_from(accounts.alice, accounts.bob, other_id.clone(), 1, vec![]),
+            Err(PSP37Error::NotAllowed)
+        );
+        // and to the approved value
+        assert_eq!(
+            nft.transfer_from(accounts.alice, accounts.bob, token_id.clone(), 3, vec![]),
+            Err(PSP37Error::NotAllowed)
+        );
+        assert!(nft
+            .transfer_from(accounts.alice, accounts.bob, token_id.clone(), 2, vec![])
+            .is_ok());
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(token_id.clone())), 0);
+        assert_eq!(nft.balance_of(accounts.bob, Some(token_id.clone())), 5);
+        assert_eq!(nft.balance_of(accounts.alice, Some(token_id)), 5);
+    }
+
+    #[ink::test]
+    fn transfer_from_with_approval_for_all_is_unlimited() {
+        let token_id = Id::U128(1);
+        let other_id = Id::U128(2);
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id.clone(), 10).is_ok());
+        assert!(nft.mint(accounts.alice, other_id.clone(), 10).is_ok());
+        assert!(nft.approve(accounts.bob, None, 1).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(nft
+            .transfer_from(accounts.alice, accounts.bob, token_id.clone(), 7, vec![])
+            .is_ok());
+        assert!(nft
+            .transfer_from(accounts.alice, accounts.bob, other_id.clone(), 10, vec![])
+            .is_ok());
+
+        // the approval for all tokens is not consumed
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, None), Balance::MAX);
+        assert_eq!(
+            nft.allowance(accounts.alice, accounts.bob, Some(token_id.clone())),
+            Balance::MAX
+        );
+        assert_eq!(nft.balance_of(accounts.bob, Some(token_id)), 7);
+        assert_eq!(nft.balance_of(accounts.bob, Some(other_id)), 10);
+    }
 }
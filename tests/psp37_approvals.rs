@@ -0,0 +1,264 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp37")]
+#[openbrush::contract]
+mod psp37_approvals {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::psp37::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        id: Option<Id>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ApprovalForAll {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        approved: bool,
+    }
+
+    #[derive(Default, SpreadAllocate, Storage)]
+    #[ink(storage)]
+    pub struct PSP37Struct {
+        #[storage_field]
+        psp37: psp37::Data,
+    }
+
+    impl Internal for PSP37Struct {
+        fn _emit_approval_event(&self, owner: AccountId, operator: AccountId, id: Option<Id>, value: Balance) {
+            self.env().emit_event(Approval {
+                owner,
+                operator,
+                id,
+                value,
+            });
+        }
+
+        fn _emit_approval_for_all_event(&self, owner: AccountId, operator: AccountId, approved: bool) {
+            self.env().emit_event(ApprovalForAll {
+                owner,
+                operator,
+                approved,
+            });
+        }
+
+        // Don't do cross call in test
+        fn _do_safe_transfer_check(
+            &mut self,
+            _operator: &AccountId,
+            _from: &AccountId,
+            _to: &AccountId,
+            _ids_amounts: &Vec<(Id, Balance)>,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP37Error> {
+            Ok(())
+        }
+    }
+
+    impl PSP37 for PSP37Struct {}
+
+    impl PSP37Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut PSP37Struct| {
+                assert!(instance
+                    ._mint_to(accounts().alice, vec![(ID, 10), (OTHER_ID, 10)])
+                    .is_ok());
+            })
+        }
+    }
+
+    type Event = <PSP37Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    const ID: Id = Id::U8(1);
+    const OTHER_ID: Id = Id::U8(2);
+
+    fn decode_events() -> Vec<Event> {
+        ink_env::test::recorded_events()
+            .map(|event| {
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            })
+            .collect()
+    }
+
+    #[ink::test]
+    fn blanket_approval_only() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+
+        assert_eq!(nft.approve(accounts.bob, None, 1), Ok(()));
+
+        assert!(nft._is_approved_for_all(&accounts.alice, &accounts.bob));
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, None), Balance::MAX);
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), Balance::MAX);
+        assert_eq!(
+            nft.allowance(accounts.alice, accounts.bob, Some(OTHER_ID)),
+            Balance::MAX
+        );
+        // the approval is only for the tokens of the caller
+        assert!(!nft._is_approved_for_all(&accounts.bob, &accounts.alice));
+        assert_eq!(nft.allowance(accounts.bob, accounts.alice, Some(ID)), 0);
+
+        assert_eq!(nft.approve(accounts.bob, None, 0), Ok(()));
+
+        assert!(!nft._is_approved_for_all(&accounts.alice, &accounts.bob));
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, None), 0);
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), 0);
+    }
+
+    #[ink::test]
+    fn per_id_allowance_only() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+
+        assert_eq!(nft.approve(accounts.bob, Some(ID), 5), Ok(()));
+
+        assert!(!nft._is_approved_for_all(&accounts.alice, &accounts.bob));
+        // the allowance for one id doesn't make `operator` approved for all tokens
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, None), 0);
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), 5);
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(OTHER_ID)), 0);
+
+        change_caller(accounts.bob);
+        assert_eq!(nft.transfer_from(accounts.alice, accounts.bob, ID, 2, vec![]), Ok(()));
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), 3);
+    }
+
+    #[ink::test]
+    fn blanket_approval_wins_over_per_id_allowance() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert_eq!(nft.approve(accounts.bob, Some(ID), 5), Ok(()));
+        assert_eq!(nft.approve(accounts.bob, None, 1), Ok(()));
+
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, None), Balance::MAX);
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), Balance::MAX);
+
+        // the transfers under the blanket approval don't consume the allowance for the id
+        change_caller(accounts.bob);
+        assert_eq!(nft.transfer_from(accounts.alice, accounts.bob, ID, 7, vec![]), Ok(()));
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), Balance::MAX);
+
+        // the allowance for the id is effective again after the blanket approval is revoked
+        change_caller(accounts.alice);
+        assert_eq!(nft.approve(accounts.bob, None, 0), Ok(()));
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, None), 0);
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), 5);
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(OTHER_ID)), 0);
+    }
+
+    #[ink::test]
+    fn per_id_allowance_changed_under_blanket_approval() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert_eq!(nft.approve(accounts.bob, None, 1), Ok(()));
+
+        // the allowance for the id is stored, but the blanket approval still wins
+        assert_eq!(nft.approve(accounts.bob, Some(ID), 3), Ok(()));
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), Balance::MAX);
+
+        assert_eq!(nft.approve(accounts.bob, None, 0), Ok(()));
+        assert_eq!(nft.allowance(accounts.alice, accounts.bob, Some(ID)), 3);
+    }
+
+    #[ink::test]
+    fn blanket_approval_emits_approval_for_all_event() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        let initial_events = decode_events().len();
+
+        assert_eq!(nft.approve(accounts.bob, None, 1), Ok(()));
+        assert_eq!(nft.approve(accounts.bob, None, 0), Ok(()));
+
+        let events = decode_events();
+        let events = &events[initial_events..];
+        assert_eq!(events.len(), 4);
+        // the `Approval` event has the resulting allowance instead of the passed value
+        match &events[0] {
+            Event::Approval(Approval {
+                owner,
+                operator,
+                id,
+                value,
+            }) => {
+                assert_eq!(
+                    (*owner, *operator, id.clone(), *value),
+                    (accounts.alice, accounts.bob, None, Balance::MAX)
+                );
+            }
+            _ => panic!("expected Approval event"),
+        }
+        match &events[1] {
+            Event::ApprovalForAll(ApprovalForAll {
+                owner,
+                operator,
+                approved,
+            }) => {
+                assert_eq!((*owner, *operator, *approved), (accounts.alice, accounts.bob, true));
+            }
+            _ => panic!("expected ApprovalForAll event"),
+        }
+        match &events[2] {
+            Event::Approval(Approval { id, value, .. }) => assert_eq!((id.clone(), *value), (None, 0)),
+            _ => panic!("expected Approval event"),
+        }
+        match &events[3] {
+            Event::ApprovalForAll(ApprovalForAll { approved, .. }) => assert!(!approved),
+            _ => panic!("expected ApprovalForAll event"),
+        }
+    }
+
+    #[ink::test]
+    fn per_id_approval_does_not_emit_approval_for_all_event() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        let initial_events = decode_events().len();
+
+        assert_eq!(nft.approve(accounts.bob, Some(ID), 5), Ok(()));
+
+        let events = decode_events();
+        assert_eq!(events.len(), initial_events + 1);
+        assert!(matches!(events[initial_events], Event::Approval(_)));
+    }
+}
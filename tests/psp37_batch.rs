@@ -73,6 +73,33 @@ mod psp37_batch {
     pub struct PSP37Struct {
         #[storage_field]
         psp37: psp37::Data,
+        // fields for testing that the hooks are called once per batch
+        before_calls: u32,
+        after_calls: u32,
+        last_hook_ids_amounts: Vec<(Id, Balance)>,
+    }
+
+    impl psp37::Transfer for PSP37Struct {
+        fn _before_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            ids_amounts: &Vec<(Id, Balance)>,
+        ) -> Result<(), PSP37Error> {
+            self.before_calls += 1;
+            self.last_hook_ids_amounts = ids_amounts.clone();
+            Ok(())
+        }
+
+        fn _after_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _ids_amounts: &Vec<(Id, Balance)>,
+        ) -> Result<(), PSP37Error> {
+            self.after_calls += 1;
+            Ok(())
+        }
     }
 
     impl psp37::Internal for PSP37Struct {
@@ -225,8 +252,102 @@ mod psp37_batch {
                     .collect(),
                 vec![]
             ),
-            Err(PSP37Error::InsufficientBalance),
+            Err(PSP37Error::InsufficientTokenBalance(Id::U128(123), token_1_amount)),
+        );
+    }
+
+    #[ink::test]
+    fn batch_transfer_with_underfunded_id_is_not_applied() {
+        let token_id_1 = Id::U128(1);
+        let token_id_2 = Id::U128(2);
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(nft
+            .mint(accounts.alice, vec![(token_id_1.clone(), 10), (token_id_2.clone(), 5)])
+            .is_ok());
+        let before_calls = nft.before_calls;
+
+        assert_eq!(
+            nft.batch_transfer(
+                accounts.bob,
+                vec![(token_id_1.clone(), 10), (token_id_2.clone(), 6)],
+                vec![]
+            ),
+            Err(PSP37Error::InsufficientTokenBalance(token_id_2.clone(), 6)),
+        );
+
+        // the first id is not moved
+        assert_eq!(nft.balance_of(accounts.alice, Some(token_id_1.clone())), 10);
+        assert_eq!(nft.balance_of(accounts.alice, Some(token_id_2)), 5);
+        assert_eq!(nft.balance_of(accounts.alice, None), 2);
+        assert_eq!(nft.balance_of(accounts.bob, Some(token_id_1)), 0);
+        assert_eq!(nft.balance_of(accounts.bob, None), 0);
+        assert_eq!(nft.before_calls, before_calls);
+    }
+
+    #[ink::test]
+    fn batch_transfer_from_with_underfunded_id_keeps_allowance() {
+        let token_id_1 = Id::U128(1);
+        let token_id_2 = Id::U128(2);
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(nft
+            .mint(accounts.bob, vec![(token_id_1.clone(), 10), (token_id_2.clone(), 5)])
+            .is_ok());
+        change_caller(accounts.bob);
+        assert!(nft.approve(accounts.alice, Some(token_id_1.clone()), 10).is_ok());
+        assert!(nft.approve(accounts.alice, Some(token_id_2.clone()), 10).is_ok());
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            nft.batch_transfer_from(
+                accounts.bob,
+                accounts.alice,
+                vec![(token_id_1.clone(), 10), (token_id_2.clone(), 6)],
+                vec![]
+            ),
+            Err(PSP37Error::InsufficientTokenBalance(token_id_2.clone(), 6)),
+        );
+
+        assert_eq!(nft.balance_of(accounts.bob, Some(token_id_1.clone())), 10);
+        assert_eq!(nft.allowance(accounts.bob, accounts.alice, Some(token_id_1)), 10);
+    }
+
+    #[ink::test]
+    fn batch_transfer_sums_repeated_ids() {
+        let token_id = Id::U128(1);
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, vec![(token_id.clone(), 10)]).is_ok());
+
+        assert_eq!(
+            nft.batch_transfer(accounts.bob, vec![(token_id.clone(), 6), (token_id.clone(), 6)], vec![]),
+            Err(PSP37Error::InsufficientTokenBalance(token_id.clone(), 12)),
+        );
+        assert_eq!(nft.balance_of(accounts.alice, Some(token_id)), 10);
+    }
+
+    #[ink::test]
+    fn batch_transfer_skips_zero_amounts() {
+        let token_id_1 = Id::U128(1);
+        let token_id_2 = Id::U128(2);
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, vec![(token_id_1.clone(), 10)]).is_ok());
+
+        // alice doesn't hold the second id, but its amount is zero
+        assert_eq!(
+            nft.batch_transfer(
+                accounts.bob,
+                vec![(token_id_1.clone(), 5), (token_id_2.clone(), 0)],
+                vec![]
+            ),
+            Ok(()),
         );
+
+        assert_eq!(nft.balance_of(accounts.bob, Some(token_id_1)), 5);
+        assert_eq!(nft.balance_of(accounts.bob, Some(token_id_2)), 0);
+        assert_eq!(nft.balance_of(accounts.bob, None), 1);
     }
 
     #[ink::test]
@@ -291,6 +412,22 @@ mod psp37_batch {
         assert_eq!(ink_env::test::recorded_events().count(), 3);
     }
 
+    #[ink::test]
+    fn batch_transfer_calls_hooks_once_per_batch() {
+        let ids_amounts = vec![(Id::U128(1), 1), (Id::U128(2), 20), (Id::U128(3), 300)];
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, ids_amounts.clone()).is_ok());
+        assert_eq!(nft.before_calls, 1);
+        assert_eq!(nft.after_calls, 1);
+
+        assert!(nft.batch_transfer(accounts.bob, ids_amounts.clone(), vec![]).is_ok());
+
+        assert_eq!(nft.before_calls, 2);
+        assert_eq!(nft.after_calls, 2);
+        assert_eq!(nft.last_hook_ids_amounts, ids_amounts);
+    }
+
     fn assert_batch_transfer_event(
         event: ink_env::test::EmittedEvent,
         expected_from: Option<AccountId>,
@@ -0,0 +1,130 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp37")]
+#[openbrush::contract]
+mod psp37_capped {
+    use ink_lang as ink;
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+    use openbrush_contracts::psp37::extensions::{
+        capped::*,
+        mintable::*,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP37Struct {
+        #[storage_field]
+        psp37: psp37::Data<capped::Balances>,
+    }
+
+    impl PSP37 for PSP37Struct {}
+
+    impl PSP37Mintable for PSP37Struct {}
+
+    impl PSP37Capped for PSP37Struct {}
+
+    impl PSP37Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    const CAP: Balance = 100;
+
+    #[ink::test]
+    fn set_cap_works() {
+        let mut nft = PSP37Struct::new();
+        let id = Id::U8(1);
+
+        assert_eq!(nft.cap(id.clone()), 0);
+        assert!(nft._set_cap(id.clone(), CAP).is_ok());
+        assert_eq!(nft.cap(id.clone()), CAP);
+        assert_eq!(nft.cap(Id::U8(2)), 0);
+    }
+
+    #[ink::test]
+    fn mint_up_to_cap_works() {
+        let mut nft = PSP37Struct::new();
+        let accounts = accounts();
+        let id = Id::U8(1);
+        assert!(nft._set_cap(id.clone(), CAP).is_ok());
+
+        assert!(nft.mint(accounts.alice, vec![(id.clone(), CAP - 1)]).is_ok());
+        assert!(nft.mint(accounts.bob, vec![(id.clone(), 1)]).is_ok());
+
+        assert_eq!(nft.total_supply(Some(id.clone())), CAP);
+        assert_eq!(
+            nft.mint(accounts.alice, vec![(id.clone(), 1)]),
+            Err(PSP37Error::CapExceeded)
+        );
+        assert_eq!(nft.total_supply(Some(id)), CAP);
+    }
+
+    #[ink::test]
+    fn mint_batch_exceeding_cap_fails() {
+        let mut nft = PSP37Struct::new();
+        let accounts = accounts();
+        let capped_id = Id::U8(1);
+        let uncapped_id = Id::U8(2);
+        assert!(nft._set_cap(capped_id.clone(), CAP).is_ok());
+
+        assert_eq!(
+            nft.mint(
+                accounts.alice,
+                vec![
+                    (uncapped_id.clone(), 1000),
+                    (capped_id.clone(), 60),
+                    (capped_id.clone(), 60)
+                ]
+            ),
+            Err(PSP37Error::CapExceeded)
+        );
+        assert_eq!(nft.total_supply(Some(capped_id)), 0);
+        assert_eq!(nft.total_supply(Some(uncapped_id.clone())), 0);
+
+        assert!(nft.mint(accounts.alice, vec![(uncapped_id.clone(), 1000)]).is_ok());
+        assert_eq!(nft.total_supply(Some(uncapped_id)), 1000);
+    }
+
+    #[ink::test]
+    fn set_cap_below_supply_fails() {
+        let mut nft = PSP37Struct::new();
+        let accounts = accounts();
+        let id = Id::U8(1);
+        assert!(nft.mint(accounts.alice, vec![(id.clone(), 10)]).is_ok());
+
+        assert_eq!(
+            nft._set_cap(id.clone(), 9),
+            Err(PSP37Error::Custom(String::from("Cap is below the current supply")))
+        );
+        assert!(nft._set_cap(id.clone(), 10).is_ok());
+        assert_eq!(nft.cap(id), 10);
+    }
+}
@@ -39,6 +39,11 @@ mod psp37_metadata {
         data: Vec<u8>,
     }
 
+    #[ink(event)]
+    pub struct Uri {
+        value: Vec<u8>,
+    }
+
     #[derive(Default, SpreadAllocate, Storage)]
     #[ink(storage)]
     pub struct PSP37Struct {
@@ -60,6 +65,10 @@ mod psp37_metadata {
                 data: _data.clone(),
             });
         }
+
+        fn _emit_uri_event(&self, _uri: &Vec<u8>) {
+            self.env().emit_event(Uri { value: _uri.clone() });
+        }
     }
 
     impl PSP37Struct {
@@ -72,8 +81,15 @@ mod psp37_metadata {
         pub fn set_attribute(&mut self, id: Id, key: Vec<u8>, data: Vec<u8>) -> Result<(), PSP37Error> {
             self._set_attribute(&id, &key, &data)
         }
+
+        #[ink(message)]
+        pub fn set_uri(&mut self, uri: Vec<u8>) {
+            self._set_uri(&uri)
+        }
     }
 
+    type Event = <PSP37Struct as ::ink_lang::reflect::ContractEventBase>::Type;
+
     #[ink::test]
     fn metadata_works() {
         let mut nft = PSP37Struct::new();
@@ -82,4 +98,49 @@ mod psp37_metadata {
 
         assert_eq!(nft.get_attribute(Id::U128(1), vec![0u8, 0u8]), Some(vec![1u8, 0u8]));
     }
+
+    #[ink::test]
+    fn uri_substitutes_id() {
+        let mut nft = PSP37Struct::new();
+        nft.set_uri(b"https://token-cdn-domain/{id}.json".to_vec());
+
+        assert_eq!(
+            nft.uri(Id::U128(0x4cce0)),
+            b"https://token-cdn-domain/000000000000000000000000000000000000000000000000000000000004cce0.json".to_vec()
+        );
+        assert_eq!(
+            nft.uri(Id::U8(255)),
+            b"https://token-cdn-domain/00000000000000000000000000000000000000000000000000000000000000ff.json".to_vec()
+        );
+        assert_eq!(
+            nft.uri(Id::Bytes(vec![0x0a, 0xbc])),
+            b"https://token-cdn-domain/0abc.json".to_vec()
+        );
+    }
+
+    #[ink::test]
+    fn uri_without_placeholder_is_returned_as_is() {
+        let mut nft = PSP37Struct::new();
+        assert_eq!(nft.uri(Id::U8(1)), Vec::<u8>::new());
+
+        nft.set_uri(b"ipfs://metadata/".to_vec());
+
+        assert_eq!(nft.uri(Id::U8(1)), b"ipfs://metadata/".to_vec());
+    }
+
+    #[ink::test]
+    fn set_uri_emits_event() {
+        let mut nft = PSP37Struct::new();
+        nft.set_uri(b"https://{id}/{id}".to_vec());
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 1);
+        let decoded_event = <Event as scale::Decode>::decode(&mut &emitted_events[0].data[..])
+            .expect("encountered invalid contract event data buffer");
+        match decoded_event {
+            Event::Uri(Uri { value }) => assert_eq!(value, b"https://{id}/{id}".to_vec()),
+            _ => panic!("unexpected event"),
+        }
+        assert_eq!(nft.uri(Id::Bytes(vec![1])), b"https://01/01".to_vec());
+    }
 }
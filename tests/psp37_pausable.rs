@@ -0,0 +1,162 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "psp37", feature = "pausable"))]
+#[openbrush::contract]
+mod psp37_pausable {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::{
+            pausable::*,
+            psp37::*,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP37Struct {
+        #[storage_field]
+        psp37: psp37::Data,
+        #[storage_field]
+        pause: pausable::Data,
+    }
+
+    impl psp37::Internal for PSP37Struct {
+        // Override this function with an empty body to omit error (cross-contract calls are not supported in off-chain environment)
+        fn _do_safe_transfer_check(
+            &mut self,
+            _operator: &AccountId,
+            _from: &AccountId,
+            _to: &AccountId,
+            _ids_amounts: &Vec<(Id, Balance)>,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP37Error> {
+            Ok(())
+        }
+    }
+
+    impl psp37::Transfer for PSP37Struct {
+        fn _before_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _ids: &Vec<(Id, Balance)>,
+        ) -> Result<(), PSP37Error> {
+            self._ensure_not_paused()
+        }
+    }
+
+    impl PSP37 for PSP37Struct {}
+
+    impl Pausable for PSP37Struct {}
+
+    impl PSP37Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            assert!(instance._mint_to(accounts().alice, vec![(Id::U8(1), 100)]).is_ok());
+            instance
+        }
+    }
+
+    fn paused_error() -> PSP37Error {
+        PSP37Error::from(PausableError::Paused)
+    }
+
+    #[ink::test]
+    fn transfer_works_when_not_paused() {
+        let mut instance = PSP37Struct::new();
+
+        assert_eq!(
+            instance.transfer(accounts().bob, Id::U8(1), 10, Vec::<u8>::new()),
+            Ok(())
+        );
+        assert_eq!(instance.balance_of(accounts().bob, Some(Id::U8(1))), 10);
+    }
+
+    #[ink::test]
+    fn transfer_fails_when_paused() {
+        let mut instance = PSP37Struct::new();
+        assert_eq!(instance._pause::<PSP37Error>(), Ok(()));
+
+        assert_eq!(
+            instance.transfer(accounts().bob, Id::U8(1), 10, Vec::<u8>::new()),
+            Err(paused_error())
+        );
+        assert_eq!(instance.balance_of(accounts().bob, Some(Id::U8(1))), 0);
+    }
+
+    #[ink::test]
+    fn transfer_from_fails_when_paused() {
+        let mut instance = PSP37Struct::new();
+        assert_eq!(instance.approve(accounts().bob, Some(Id::U8(1)), 10), Ok(()));
+        assert_eq!(instance._pause::<PSP37Error>(), Ok(()));
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.transfer_from(accounts().alice, accounts().bob, Id::U8(1), 10, Vec::<u8>::new()),
+            Err(paused_error())
+        );
+        assert_eq!(instance.balance_of(accounts().alice, Some(Id::U8(1))), 100);
+    }
+
+    #[ink::test]
+    fn mint_fails_when_paused() {
+        let mut instance = PSP37Struct::new();
+        assert_eq!(instance._pause::<PSP37Error>(), Ok(()));
+
+        assert_eq!(
+            instance._mint_to(accounts().bob, vec![(Id::U8(2), 10)]),
+            Err(paused_error())
+        );
+        assert_eq!(instance.total_supply(Some(Id::U8(2))), 0);
+    }
+
+    #[ink::test]
+    fn burn_fails_when_paused() {
+        let mut instance = PSP37Struct::new();
+        assert_eq!(instance._pause::<PSP37Error>(), Ok(()));
+
+        assert_eq!(
+            instance._burn_from(accounts().alice, vec![(Id::U8(1), 10)]),
+            Err(paused_error())
+        );
+        assert_eq!(instance.total_supply(Some(Id::U8(1))), 100);
+    }
+
+    #[ink::test]
+    fn transfer_works_after_unpause() {
+        let mut instance = PSP37Struct::new();
+        assert_eq!(instance._pause::<PSP37Error>(), Ok(()));
+        assert_eq!(instance._unpause::<PSP37Error>(), Ok(()));
+
+        assert_eq!(
+            instance.transfer(accounts().bob, Id::U8(1), 10, Vec::<u8>::new()),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "psp37")]
+#[openbrush::contract]
+mod psp37_supply {
+    use ink_lang as ink;
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+    use openbrush_contracts::psp37::extensions::{
+        burnable::*,
+        mintable::*,
+        supply::*,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP37Struct {
+        #[storage_field]
+        psp37: psp37::Data,
+    }
+
+    impl psp37::Internal for PSP37Struct {
+        fn _do_safe_transfer_check(
+            &mut self,
+            _operator: &AccountId,
+            _from: &AccountId,
+            _to: &AccountId,
+            _ids_amounts: &Vec<(Id, Balance)>,
+            _data: &Vec<u8>,
+        ) -> Result<(), PSP37Error> {
+            Ok(())
+        }
+    }
+
+    impl PSP37 for PSP37Struct {}
+
+    impl PSP37Mintable for PSP37Struct {}
+
+    impl PSP37Burnable for PSP37Struct {}
+
+    impl PSP37Supply for PSP37Struct {}
+
+    impl PSP37Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn total_token_count_is_zero_by_default() {
+        let nft = PSP37Struct::new();
+
+        assert_eq!(nft.total_token_count(), 0);
+        assert_eq!(nft.total_supply(Some(Id::U8(1))), 0);
+    }
+
+    #[ink::test]
+    fn supply_is_tracked_on_mint_and_burn() {
+        let accounts = accounts();
+        let token_1 = Id::U8(1);
+        let token_2 = Id::U8(2);
+        let mut nft = PSP37Struct::new();
+
+        assert!(nft
+            .mint(accounts.alice, vec![(token_1.clone(), 10), (token_2.clone(), 20)])
+            .is_ok());
+        assert!(nft.mint(accounts.bob, vec![(token_1.clone(), 5)]).is_ok());
+
+        assert_eq!(nft.total_supply(Some(token_1.clone())), 15);
+        assert_eq!(nft.total_supply(Some(token_2.clone())), 20);
+        assert_eq!(nft.total_token_count(), 2);
+
+        // partially burned token is still counted
+        assert!(nft.burn(accounts.alice, vec![(token_1.clone(), 10)]).is_ok());
+        assert_eq!(nft.total_supply(Some(token_1.clone())), 5);
+        assert_eq!(nft.total_token_count(), 2);
+
+        // fully burned token is not counted anymore
+        assert!(nft.burn(accounts.alice, vec![(token_2.clone(), 20)]).is_ok());
+        assert_eq!(nft.total_supply(Some(token_2.clone())), 0);
+        assert_eq!(nft.total_token_count(), 1);
+
+        // minting the burned token again counts it again
+        assert!(nft.mint(accounts.alice, vec![(token_2.clone(), 1)]).is_ok());
+        assert_eq!(nft.total_token_count(), 2);
+    }
+
+    #[ink::test]
+    fn transfer_does_not_change_supply() {
+        let accounts = accounts();
+        let token_1 = Id::U8(1);
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, vec![(token_1.clone(), 10)]).is_ok());
+
+        assert!(nft
+            .transfer(accounts.bob, token_1.clone(), 10, Vec::<u8>::new())
+            .is_ok());
+
+        assert_eq!(nft.total_supply(Some(token_1)), 10);
+        assert_eq!(nft.total_token_count(), 1);
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "pull_payment")]
+#[openbrush::contract]
+mod pull_payment {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::pull_payment::*,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct Deposited {
+        payee: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Withdrawn {
+        payee: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct PullPaymentStruct {
+        #[storage_field]
+        escrow: escrow::Data,
+    }
+
+    type Event = <PullPaymentStruct as ::ink_lang::reflect::ContractEventBase>::Type;
+
+    impl PullPayment for PullPaymentStruct {}
+
+    impl escrow::Internal for PullPaymentStruct {
+        fn _emit_deposited_event(&self, payee: AccountId, amount: Balance) {
+            self.env().emit_event(Deposited { payee, amount });
+        }
+
+        fn _emit_withdrawn_event(&self, payee: AccountId, amount: Balance) {
+            self.env().emit_event(Withdrawn { payee, amount });
+        }
+    }
+
+    impl PullPaymentStruct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|_instance: &mut Self| {})
+        }
+    }
+
+    fn set_contract_balance(value: Balance) {
+        let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(contract, value);
+    }
+
+    fn balance_of(account: AccountId) -> Balance {
+        ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(account).unwrap()
+    }
+
+    #[ink::test]
+    fn async_transfer_credits_payees() {
+        let accounts = accounts();
+        let mut instance = PullPaymentStruct::new();
+
+        assert_eq!(instance._async_transfer(accounts.bob, 100), Ok(()));
+        assert_eq!(instance._async_transfer(accounts.bob, 20), Ok(()));
+        assert_eq!(instance._async_transfer(accounts.charlie, 50), Ok(()));
+
+        assert_eq!(instance.payments(accounts.bob), 120);
+        assert_eq!(instance.payments(accounts.charlie), 50);
+        assert_eq!(instance.payments(accounts.django), 0);
+    }
+
+    #[ink::test]
+    fn payees_withdraw_independently() {
+        let accounts = accounts();
+        let mut instance = PullPaymentStruct::new();
+        set_contract_balance(150);
+        assert_eq!(instance._async_transfer(accounts.bob, 100), Ok(()));
+        assert_eq!(instance._async_transfer(accounts.charlie, 50), Ok(()));
+        let bob_balance = balance_of(accounts.bob);
+        let charlie_balance = balance_of(accounts.charlie);
+
+        change_caller(accounts.bob);
+        assert_eq!(instance.withdraw_payments(accounts.bob), Ok(()));
+        assert_eq!(balance_of(accounts.bob), bob_balance + 100);
+        assert_eq!(instance.payments(accounts.bob), 0);
+        assert_eq!(instance.payments(accounts.charlie), 50);
+
+        change_caller(accounts.charlie);
+        assert_eq!(instance.withdraw_payments(accounts.charlie), Ok(()));
+        assert_eq!(balance_of(accounts.charlie), charlie_balance + 50);
+        assert_eq!(instance.payments(accounts.charlie), 0);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 4);
+        match <Event as scale::Decode>::decode(&mut &emitted_events[2].data[..]) {
+            Ok(Event::Withdrawn(Withdrawn { payee, amount })) => {
+                assert_eq!(payee, accounts.bob);
+                assert_eq!(amount, 100);
+            }
+            _ => panic!("Expected Withdrawn event"),
+        }
+    }
+}
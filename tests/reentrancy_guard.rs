@@ -57,8 +57,41 @@ mod reentrancy_guard {
         pub fn call_flip_after_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
             self.flip()
         }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant)]
+        pub fn entered_inside_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            Ok(self._entered())
+        }
+
+        #[ink(message)]
+        pub fn entered(&self) -> bool {
+            self._entered()
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_key(DEPOSITS))]
+        pub fn flip_in_deposits(&mut self) -> Result<bool, ReentrancyGuardError> {
+            self.flip()
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_key(DEPOSITS))]
+        pub fn call_deposits_after_deposits_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            self.flip_in_deposits()
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_key(WITHDRAWALS))]
+        pub fn call_deposits_after_withdrawals_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            assert!(self._entered_key(WITHDRAWALS));
+            self.flip_in_deposits()
+        }
     }
 
+    const DEPOSITS: u8 = 1;
+    const WITHDRAWALS: u8 = 2;
+
     #[ink::test]
     fn flip_works() {
         let mut instance = MyFlipper::new();
@@ -77,4 +110,58 @@ mod reentrancy_guard {
             instance.call_flip_after_lock()
         );
     }
+
+    #[ink::test]
+    fn entered_reflects_lock_status() {
+        let mut instance = MyFlipper::new();
+
+        assert!(!instance.entered());
+        assert_eq!(Ok(true), instance.entered_inside_lock());
+        assert!(!instance.entered());
+        // The status is released after a failed reentrant call too
+        assert_eq!(
+            Err(ReentrancyGuardError::ReentrantCall),
+            instance.call_flip_after_lock()
+        );
+        assert!(!instance.entered());
+    }
+
+    #[ink::test]
+    fn reentry_with_the_same_key_fails() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(
+            Err(ReentrancyGuardError::ReentrantCall),
+            instance.call_deposits_after_deposits_lock()
+        );
+        assert!(!instance._entered_key(DEPOSITS));
+        assert_eq!(Ok(false), instance.flip_in_deposits());
+    }
+
+    #[ink::test]
+    fn reentry_with_another_key_works() {
+        let mut instance = MyFlipper::new();
+
+        // `flip_in_deposits` also calls `flip`, which is protected by the default key
+        assert_eq!(Ok(false), instance.call_deposits_after_withdrawals_lock());
+        assert_eq!(Ok(true), instance.call_deposits_after_withdrawals_lock());
+        assert!(!instance._entered_key(DEPOSITS));
+        assert!(!instance._entered_key(WITHDRAWALS));
+        assert!(!instance.entered());
+    }
+
+    #[ink::test]
+    fn acquire_and_release_work() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(Ok(()), instance._acquire(DEPOSITS));
+        assert_eq!(Err(ReentrancyGuardError::ReentrantCall), instance._acquire(DEPOSITS));
+        assert!(!instance._entered());
+        assert_eq!(Ok(()), instance._acquire(DEFAULT_KEY));
+        assert!(instance._entered());
+
+        instance._release(DEPOSITS);
+        assert!(!instance._entered_key(DEPOSITS));
+        assert_eq!(Ok(()), instance._acquire(DEPOSITS));
+    }
 }
@@ -0,0 +1,151 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(all(feature = "reentrancy_guard", feature = "ownable"))]
+#[openbrush::contract]
+mod reentrancy_guard_ownable {
+    use ink_lang as ink;
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            reentrancy_guard::*,
+        },
+        modifiers,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum VaultError {
+        OwnableError(OwnableError),
+        ReentrancyGuardError(ReentrancyGuardError),
+    }
+
+    impl From<OwnableError> for VaultError {
+        fn from(error: OwnableError) -> Self {
+            VaultError::OwnableError(error)
+        }
+    }
+
+    impl From<ReentrancyGuardError> for VaultError {
+        fn from(error: ReentrancyGuardError) -> Self {
+            VaultError::ReentrancyGuardError(error)
+        }
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Vault {
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        withdrawn: u32,
+        entered_in_body: bool,
+    }
+
+    impl Ownable for Vault {}
+
+    impl Vault {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            instance._init_with_owner(Self::env().caller());
+            instance
+        }
+
+        #[ink(message)]
+        #[modifiers(non_reentrant, only_owner)]
+        pub fn withdraw(&mut self) -> Result<(), VaultError> {
+            self.entered_in_body = self._entered();
+            self.withdrawn += 1;
+            Ok(())
+        }
+
+        /// Calls `withdraw` again from the guarded body, like a malicious callee would do
+        #[ink(message)]
+        #[modifiers(non_reentrant, only_owner)]
+        pub fn withdraw_and_reenter(&mut self) -> Result<(), VaultError> {
+            self.withdrawn += 1;
+            self.withdraw()
+        }
+    }
+
+    #[ink::test]
+    fn guarded_body_runs_under_lock() {
+        let mut instance = Vault::new();
+
+        assert_eq!(instance.withdraw(), Ok(()));
+        assert_eq!(instance.withdrawn, 1);
+        assert!(instance.entered_in_body);
+        // the lock is released after the body
+        assert!(!instance._entered());
+    }
+
+    #[ink::test]
+    fn reentrant_call_from_guarded_body_fails() {
+        let mut instance = Vault::new();
+
+        assert_eq!(
+            instance.withdraw_and_reenter(),
+            Err(VaultError::ReentrancyGuardError(ReentrancyGuardError::ReentrantCall))
+        );
+        // only the outer body was executed, the reentrant one was rejected by the guard
+        assert_eq!(instance.withdrawn, 1);
+        assert!(!instance._entered());
+        assert_eq!(instance.withdraw(), Ok(()));
+    }
+
+    #[ink::test]
+    fn lock_is_released_if_owner_check_fails() {
+        let mut instance = Vault::new();
+
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.withdraw(),
+            Err(VaultError::OwnableError(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(instance.withdrawn, 0);
+        // the guard is the outermost modifier, so it releases the lock after the failed owner check
+        assert!(!instance._entered());
+
+        change_caller(accounts().alice);
+        assert_eq!(instance.withdraw(), Ok(()));
+    }
+
+    #[ink::test]
+    fn guard_is_checked_before_owner() {
+        let mut instance = Vault::new();
+        assert_eq!(instance._acquire(DEFAULT_KEY), Ok(()));
+
+        // the guard rejects the call before the owner check is evaluated
+        change_caller(accounts().bob);
+        assert_eq!(
+            instance.withdraw(),
+            Err(VaultError::ReentrancyGuardError(ReentrancyGuardError::ReentrantCall))
+        );
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+mod signature {
+    use ink_env::hash::{
+        Blake2x256,
+        HashOutput,
+    };
+    use openbrush::{
+        contracts::signature::*,
+        traits::AccountId,
+    };
+    use secp256k1::{
+        Message,
+        PublicKey,
+        Secp256k1,
+        SecretKey,
+    };
+
+    fn signer_key() -> (SecretKey, AccountId) {
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key).serialize();
+        let mut account = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+        (secret_key, AccountId::from(account))
+    }
+
+    fn sign(secret_key: &SecretKey, message_hash: &[u8; 32]) -> [u8; 65] {
+        let message = Message::from_slice(message_hash).unwrap();
+        let (recovery_id, signature) = Secp256k1::new()
+            .sign_ecdsa_recoverable(&message, secret_key)
+            .serialize_compact();
+        let mut output = [0u8; 65];
+        output[..64].copy_from_slice(&signature);
+        output[64] = recovery_id.to_i32() as u8;
+        output
+    }
+
+    #[test]
+    fn recover_ecdsa_works() {
+        let (secret_key, signer) = signer_key();
+        let message_hash = [0x11; 32];
+        let signature = sign(&secret_key, &message_hash);
+
+        assert_eq!(recover_ecdsa(&message_hash, &signature), Ok(signer));
+        assert!(verify_ecdsa(&message_hash, &signature, &signer));
+    }
+
+    #[test]
+    fn recover_ecdsa_returns_another_account_for_another_message() {
+        let (secret_key, signer) = signer_key();
+        let signature = sign(&secret_key, &[0x11; 32]);
+
+        assert_ne!(recover_ecdsa(&[0x22; 32], &signature), Ok(signer));
+        assert!(!verify_ecdsa(&[0x22; 32], &signature, &signer));
+    }
+
+    #[test]
+    fn verify_ecdsa_fails_with_another_signer() {
+        let (secret_key, _) = signer_key();
+        let message_hash = [0x11; 32];
+        let signature = sign(&secret_key, &message_hash);
+
+        assert!(!verify_ecdsa(&message_hash, &signature, &AccountId::from([0x1; 32])));
+    }
+
+    #[test]
+    fn recover_ecdsa_fails_with_wrong_length() {
+        let (secret_key, signer) = signer_key();
+        let message_hash = [0x11; 32];
+        let signature = sign(&secret_key, &message_hash);
+
+        assert_eq!(
+            recover_ecdsa(&message_hash, &signature[..64]),
+            Err(SignatureError::InvalidSignatureLength)
+        );
+        assert_eq!(
+            recover_ecdsa(&message_hash, &[signature.as_slice(), &[0]].concat()),
+            Err(SignatureError::InvalidSignatureLength)
+        );
+        assert_eq!(
+            recover_ecdsa(&message_hash, &[]),
+            Err(SignatureError::InvalidSignatureLength)
+        );
+        assert!(!verify_ecdsa(&message_hash, &signature[..64], &signer));
+    }
+
+    #[test]
+    fn recover_ecdsa_fails_with_malformed_signature() {
+        let (secret_key, signer) = signer_key();
+        let message_hash = [0x11; 32];
+        let mut signature = sign(&secret_key, &message_hash);
+        signature[64] = 5;
+
+        assert_eq!(
+            recover_ecdsa(&message_hash, &signature),
+            Err(SignatureError::RecoveryFailed)
+        );
+        assert_eq!(
+            recover_ecdsa(&message_hash, &[0u8; 65]),
+            Err(SignatureError::RecoveryFailed)
+        );
+        assert!(!verify_ecdsa(&message_hash, &signature, &signer));
+    }
+}
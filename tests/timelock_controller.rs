@@ -29,7 +29,10 @@ mod timelock_controller {
     use openbrush::{
         contracts::{
             access_control::extensions::enumerable::*,
-            timelock_controller::*,
+            timelock_controller::{
+                Transaction,
+                *,
+            },
         },
         test_utils::{
             accounts,
@@ -86,8 +89,13 @@ mod timelock_controller {
         access_control: access_control::Data<enumerable::Members>,
         #[storage_field]
         timelock: timelock_controller::Data,
+        // selectors of the executed transactions
+        executed: Vec<[u8; 4]>,
     }
 
+    const FAILING_SELECTOR: [u8; 4] = [0xFF; 4];
+    const UPDATE_DELAY_SELECTOR: [u8; 4] = ink_lang::selector_bytes!("TimelockController::update_delay");
+
     type Event = <TimelockControllerStruct as ::ink_lang::reflect::ContractEventBase>::Type;
 
     impl AccessControl for TimelockControllerStruct {}
@@ -122,6 +130,26 @@ mod timelock_controller {
         fn _emit_call_executed_event(&self, id: OperationId, index: u8, transaction: Transaction) {
             self.env().emit_event(CallExecuted { id, index, transaction })
         }
+
+        /// Cross-contract calls are not supported in off-chain tests, so the call is only recorded
+        fn _call(&mut self, id: OperationId, i: u8, transaction: Transaction) -> Result<(), TimelockControllerError> {
+            if transaction.selector == FAILING_SELECTOR {
+                return Err(TimelockControllerError::UnderlyingTransactionReverted)
+            }
+            // the call of the timelock to itself is dispatched directly with the timelock as the caller
+            if transaction.callee == self.env().account_id() && transaction.selector == UPDATE_DELAY_SELECTOR {
+                let caller = self.env().caller();
+                let new_delay = <Timestamp as scale::Decode>::decode(&mut &transaction.input[..])
+                    .map_err(|_| TimelockControllerError::UnderlyingTransactionReverted)?;
+                change_caller(self.env().account_id());
+                let result = self.update_delay(new_delay);
+                change_caller(caller);
+                result.map_err(|_| TimelockControllerError::UnderlyingTransactionReverted)?;
+            }
+            self.executed.push(transaction.selector);
+            self._emit_call_executed_event(id, i, transaction);
+            Ok(())
+        }
     }
 
     impl TimelockControllerStruct {
@@ -242,6 +270,10 @@ mod timelock_controller {
         assert!(timelock.has_role(EXECUTOR_ROLE, accounts.eve));
         assert!(timelock.has_role(EXECUTOR_ROLE, accounts.charlie));
         assert!(!timelock.has_role(EXECUTOR_ROLE, accounts.bob));
+        assert!(timelock.has_role(CANCELLER_ROLE, accounts.bob));
+        assert!(timelock.has_role(CANCELLER_ROLE, accounts.charlie));
+        assert!(!timelock.has_role(CANCELLER_ROLE, accounts.eve));
+        assert_eq!(timelock.get_role_admin(CANCELLER_ROLE), CANCELLER_ROLE);
 
         let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
         assert_min_delay_change_event(&emitted_events[0], 0, 10);
@@ -348,6 +380,94 @@ mod timelock_controller {
         );
     }
 
+    /// Advances the blocks until the block timestamp reaches `timestamp`.
+    /// The off-chain environment moves the time only by whole blocks.
+    fn advance_time_to(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<DefaultEnvironment>();
+        }
+    }
+
+    fn transaction(selector: u8) -> Transaction {
+        Transaction {
+            selector: [selector; 4],
+            ..Default::default()
+        }
+    }
+
+    #[ink::test]
+    fn should_execute_batch() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock =
+            TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![accounts.alice]);
+        let transactions = vec![transaction(1), transaction(2)];
+        let id = timelock.hash_operation_batch(transactions.clone(), None, [0; 32]);
+        assert!(timelock
+            .schedule_batch(transactions.clone(), None, [0; 32], min_delay)
+            .is_ok());
+
+        advance_time_to(min_delay);
+        assert!(timelock.is_operation_ready(id));
+        assert!(timelock.execute_batch(transactions.clone(), None, [0; 32]).is_ok());
+
+        assert!(timelock.is_operation_done(id));
+        assert_eq!(timelock.executed, vec![[1; 4], [2; 4]]);
+        // The done operation can't be executed again
+        assert_eq!(
+            timelock.execute_batch(transactions, None, [0; 32]),
+            Err(TimelockControllerError::OperationIsNotReady)
+        );
+        assert_eq!(timelock.executed.len(), 2);
+    }
+
+    #[ink::test]
+    fn should_execute_batch_not_ready() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock =
+            TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![accounts.alice]);
+        let transactions = vec![transaction(1), transaction(2)];
+        assert!(timelock
+            .schedule_batch(transactions.clone(), None, [0; 32], min_delay)
+            .is_ok());
+
+        // the delay hasn't passed yet
+        assert_eq!(
+            timelock.execute_batch(transactions.clone(), None, [0; 32]),
+            Err(TimelockControllerError::OperationIsNotReady)
+        );
+        // Different salt means a different operation which is not scheduled
+        advance_time_to(min_delay);
+        assert_eq!(
+            timelock.execute_batch(transactions, None, [1; 32]),
+            Err(TimelockControllerError::OperationIsNotReady)
+        );
+        assert!(timelock.executed.is_empty());
+    }
+
+    #[ink::test]
+    fn should_execute_batch_fails_if_call_fails() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock =
+            TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![accounts.alice]);
+        let transactions = vec![transaction(1), transaction(0xFF), transaction(2)];
+        let id = timelock.hash_operation_batch(transactions.clone(), None, [0; 32]);
+        assert!(timelock
+            .schedule_batch(transactions.clone(), None, [0; 32], min_delay)
+            .is_ok());
+
+        advance_time_to(min_delay);
+        assert_eq!(
+            timelock.execute_batch(transactions, None, [0; 32]),
+            Err(TimelockControllerError::UnderlyingTransactionReverted)
+        );
+        assert!(!timelock.is_operation_done(id));
+        // The execution stops at the failed call
+        assert_eq!(timelock.executed, vec![[1; 4]]);
+    }
+
     #[ink::test]
     fn should_cancel() {
         let accounts = setup();
@@ -366,7 +486,7 @@ mod timelock_controller {
     }
 
     #[ink::test]
-    fn should_cancel_not_proposer() {
+    fn should_cancel_not_canceller() {
         let accounts = setup();
         let min_delay = 10;
         let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
@@ -379,7 +499,7 @@ mod timelock_controller {
         let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
         assert_call_scheduled_event(&emitted_events[1], id, 0, Transaction::default(), None, min_delay + 1);
 
-        assert!(timelock.revoke_role(PROPOSER_ROLE, accounts.alice).is_ok());
+        assert!(timelock.revoke_role(CANCELLER_ROLE, accounts.alice).is_ok());
         assert_eq!(
             Err(TimelockControllerError::AccessControlError(
                 AccessControlError::MissingRole
@@ -388,6 +508,66 @@ mod timelock_controller {
         );
     }
 
+    #[ink::test]
+    fn should_not_execute_cancelled_operation() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock =
+            TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![accounts.alice]);
+
+        let id = timelock.hash_operation(transaction(1), None, [0; 32]);
+        assert!(timelock.schedule(transaction(1), None, [0; 32], min_delay).is_ok());
+        // not ready yet
+        assert!(timelock.cancel(id).is_ok());
+
+        assert!(!timelock.is_operation(id));
+        advance_time_to(min_delay);
+        assert_eq!(
+            timelock.execute(transaction(1), None, [0; 32]),
+            Err(TimelockControllerError::OperationIsNotReady)
+        );
+        assert!(timelock.executed.is_empty());
+    }
+
+    #[ink::test]
+    fn should_cancel_ready_operation() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock =
+            TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![accounts.alice]);
+
+        let id = timelock.hash_operation(transaction(1), None, [0; 32]);
+        assert!(timelock.schedule(transaction(1), None, [0; 32], min_delay).is_ok());
+        advance_time_to(min_delay);
+        assert!(timelock.is_operation_ready(id));
+
+        assert!(timelock.cancel(id).is_ok());
+
+        assert_eq!(
+            timelock.execute(transaction(1), None, [0; 32]),
+            Err(TimelockControllerError::OperationIsNotReady)
+        );
+    }
+
+    #[ink::test]
+    fn should_not_cancel_done_operation() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock =
+            TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![accounts.alice]);
+
+        let id = timelock.hash_operation(transaction(1), None, [0; 32]);
+        assert!(timelock.schedule(transaction(1), None, [0; 32], min_delay).is_ok());
+        advance_time_to(min_delay);
+        assert!(timelock.execute(transaction(1), None, [0; 32]).is_ok());
+
+        assert_eq!(
+            Err(TimelockControllerError::OperationCannonBeCanceled),
+            timelock.cancel(id)
+        );
+        assert!(timelock.is_operation_done(id));
+    }
+
     #[ink::test]
     fn should_cancel_not_pending_operation() {
         let accounts = setup();
@@ -410,6 +590,51 @@ mod timelock_controller {
         // Caller of the method is contract itself
         change_caller(timelock.env().account_id());
         assert!(timelock.update_delay(min_delay + 2).is_ok());
+        assert_eq!(timelock.get_min_delay(), min_delay + 2);
+
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_min_delay_change_event(&emitted_events[emitted_events.len() - 1], min_delay, min_delay + 2);
+    }
+
+    fn update_delay_transaction(timelock: &TimelockControllerStruct, new_delay: Timestamp) -> Transaction {
+        Transaction {
+            callee: timelock.env().account_id(),
+            selector: UPDATE_DELAY_SELECTOR,
+            input: scale::Encode::encode(&new_delay),
+            ..Default::default()
+        }
+    }
+
+    #[ink::test]
+    fn should_update_delay_by_scheduled_self_call() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock =
+            TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![accounts.alice]);
+        let update = update_delay_transaction(&timelock, min_delay + 5);
+        let id = timelock.hash_operation(update.clone(), None, [0; 32]);
+        assert!(timelock.schedule(update.clone(), None, [0; 32], min_delay).is_ok());
+
+        // The change waits for the current delay like any other operation
+        assert_eq!(
+            timelock.execute(update.clone(), None, [0; 32]),
+            Err(TimelockControllerError::OperationIsNotReady)
+        );
+        assert_eq!(timelock.get_min_delay(), min_delay);
+
+        advance_time_to(min_delay);
+        assert!(timelock.execute(update, None, [0; 32]).is_ok());
+
+        assert!(timelock.is_operation_done(id));
+        assert_eq!(timelock.get_min_delay(), min_delay + 5);
+        let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_min_delay_change_event(&emitted_events[emitted_events.len() - 2], min_delay, min_delay + 5);
+
+        // The new delay applies to the operations scheduled after the change
+        assert_eq!(
+            timelock.schedule(transaction(1), None, [0; 32], min_delay),
+            Err(TimelockControllerError::InsufficientDelay)
+        );
     }
 
     #[ink::test]
@@ -423,5 +648,20 @@ mod timelock_controller {
             Err(TimelockControllerError::CallerMustBeTimeLock),
             timelock.update_delay(min_delay + 2)
         );
+        assert_eq!(timelock.get_min_delay(), min_delay);
+    }
+
+    #[ink::test]
+    fn should_not_update_delay_directly_by_admin() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+        change_caller(accounts.alice);
+
+        assert_eq!(
+            timelock.update_delay(0),
+            Err(TimelockControllerError::CallerMustBeTimeLock)
+        );
+        assert_eq!(timelock.get_min_delay(), min_delay);
     }
 }
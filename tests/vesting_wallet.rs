@@ -0,0 +1,197 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#![feature(min_specialization)]
+#[cfg(feature = "vesting_wallet")]
+#[openbrush::contract]
+mod vesting_wallet {
+    use ink_lang as ink;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::{
+        contracts::vesting_wallet::*,
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    const START: Timestamp = 1200;
+    const DURATION: Timestamp = 120;
+    const ALLOCATION: Balance = 1000;
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct VestingWalletStruct {
+        #[storage_field]
+        vesting: vesting_wallet::Data,
+        /// Mocked balance of the PSP22 token held by the contract
+        token_balance: Balance,
+        /// Mocked balance of the PSP22 token of the beneficiary
+        beneficiary_token_balance: Balance,
+    }
+
+    impl VestingWallet for VestingWalletStruct {}
+
+    /// We will override the token functions, so they are not using cross-contract call in tests
+    impl vesting_wallet::Internal for VestingWalletStruct {
+        fn _token_balance(&self, _token: &AccountId) -> Balance {
+            self.token_balance
+        }
+
+        fn _transfer_token(
+            &mut self,
+            _token: &AccountId,
+            _to: AccountId,
+            amount: Balance,
+        ) -> Result<(), VestingWalletError> {
+            self.token_balance -= amount;
+            self.beneficiary_token_balance += amount;
+            Ok(())
+        }
+    }
+
+    impl VestingWalletStruct {
+        #[ink(constructor)]
+        pub fn new(beneficiary: AccountId, start: Timestamp, duration: Timestamp) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                assert!(instance._init(beneficiary, start, duration).is_ok());
+            })
+        }
+    }
+
+    fn setup() -> VestingWalletStruct {
+        let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
+        let minimum_balance = ink_env::minimum_balance::<ink_env::DefaultEnvironment>();
+        ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(contract, minimum_balance + ALLOCATION);
+
+        let mut instance = VestingWalletStruct::new(accounts().bob, START, DURATION);
+        instance.token_balance = ALLOCATION;
+        instance
+    }
+
+    /// Moves the block timestamp to `timestamp`. The off-chain environment moves the time
+    /// only by whole blocks, so `timestamp` must be a multiple of the block time.
+    fn set_timestamp(timestamp: Timestamp) {
+        while ink_env::block_timestamp::<ink_env::DefaultEnvironment>() < timestamp {
+            let _ = ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+        }
+        assert_eq!(ink_env::block_timestamp::<ink_env::DefaultEnvironment>(), timestamp);
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let instance = setup();
+
+        assert_eq!(instance.beneficiary(), accounts().bob);
+        assert_eq!(instance.start(), START);
+        assert_eq!(instance.duration(), DURATION);
+        assert_eq!(instance.released(), 0);
+    }
+
+    #[ink::test]
+    fn init_with_zero_beneficiary_should_fail() {
+        let mut instance = VestingWalletStruct::default();
+
+        assert_eq!(
+            instance._init([0; 32].into(), START, DURATION),
+            Err(VestingWalletError::BeneficiaryZeroAddress)
+        );
+    }
+
+    #[ink::test]
+    fn vested_amount_is_linear() {
+        let instance = setup();
+        let token = accounts().django;
+
+        assert_eq!(instance.vested_amount(0), 0);
+        assert_eq!(instance.vested_amount(START - 1), 0);
+        assert_eq!(instance.vested_amount(START), 0);
+        assert_eq!(instance.vested_amount(START + DURATION / 2), ALLOCATION / 2);
+        assert_eq!(instance.vested_amount(START + DURATION), ALLOCATION);
+        assert_eq!(instance.vested_amount(START + DURATION * 2), ALLOCATION);
+
+        assert_eq!(instance.vested_amount_token(token, START), 0);
+        assert_eq!(
+            instance.vested_amount_token(token, START + DURATION / 2),
+            ALLOCATION / 2
+        );
+        assert_eq!(instance.vested_amount_token(token, START + DURATION), ALLOCATION);
+    }
+
+    #[ink::test]
+    fn vesting_schedule_does_not_overflow() {
+        let instance = setup();
+
+        assert_eq!(
+            instance._vesting_schedule(Balance::MAX, START + DURATION / 2),
+            Balance::MAX / 2
+        );
+        assert_eq!(instance._vesting_schedule(Balance::MAX, START + DURATION), Balance::MAX);
+    }
+
+    #[ink::test]
+    fn release_works() {
+        let mut instance = setup();
+        let beneficiary = accounts().bob;
+        let beneficiary_balance = ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(beneficiary)
+            .expect("Cannot get account balance");
+
+        set_timestamp(START);
+        assert_eq!(instance.releasable(), 0);
+        assert_eq!(instance.release(), Err(VestingWalletError::NothingToRelease));
+
+        set_timestamp(START + DURATION / 2);
+        assert_eq!(instance.releasable(), ALLOCATION / 2);
+        assert_eq!(instance.release(), Ok(()));
+        assert_eq!(instance.released(), ALLOCATION / 2);
+        assert_eq!(instance.releasable(), 0);
+        // The released amount is counted into the total allocation
+        assert_eq!(instance.vested_amount(START + DURATION), ALLOCATION);
+
+        set_timestamp(START + DURATION);
+        assert_eq!(instance.releasable(), ALLOCATION / 2);
+        assert_eq!(instance.release(), Ok(()));
+        assert_eq!(instance.released(), ALLOCATION);
+        assert_eq!(
+            ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(beneficiary),
+            Ok(beneficiary_balance + ALLOCATION)
+        );
+    }
+
+    #[ink::test]
+    fn release_token_works() {
+        let mut instance = setup();
+        let token = accounts().django;
+
+        // the vesting hasn't started yet
+        assert_eq!(instance.release_token(token), Err(VestingWalletError::NothingToRelease));
+
+        set_timestamp(START + DURATION / 2);
+        assert_eq!(instance.release_token(token), Ok(()));
+        assert_eq!(instance.released_token(token), ALLOCATION / 2);
+        assert_eq!(instance.beneficiary_token_balance, ALLOCATION / 2);
+
+        set_timestamp(START + DURATION);
+        assert_eq!(instance.releasable_token(token), ALLOCATION / 2);
+        assert_eq!(instance.release_token(token), Ok(()));
+        assert_eq!(instance.released_token(token), ALLOCATION);
+        assert_eq!(instance.beneficiary_token_balance, ALLOCATION);
+        assert_eq!(instance.releasable_token(token), 0);
+    }
+}